@@ -0,0 +1,29 @@
+#!/bin/bash
+# Regenerates the checked-in bindings from the CUDA toolkit in $CUDA_PATH
+# (default: /usr/local/cuda) and stores them as src/bindings/cuda_<version>.rs.
+# Keep the options in sync with `generate_bindings` in build.rs.
+set -exu
+
+CUDA_PATH=${CUDA_PATH:-/usr/local/cuda}
+cd "$(dirname "$0")"
+
+bindgen \
+  --allowlist-type="^CU.*" \
+  --allowlist-type="^cuuint(32|64)_t" \
+  --allowlist-type="^cudaError_enum" \
+  --allowlist-type="^cu.*Complex$" \
+  --allowlist-type="^cuda.*" \
+  --allowlist-type="^libraryPropertyType.*" \
+  --allowlist-var="^CU.*" \
+  --allowlist-function="^cu.*" \
+  --no-doc-comments \
+  --with-derive-default \
+  --with-derive-eq \
+  --with-derive-hash \
+  --with-derive-ord \
+  --use-core \
+  wrapper.h -- -I"${CUDA_PATH}/include" \
+  > tmp.rs
+
+CUDA_VERSION=$(grep "pub const CUDA_VERSION" tmp.rs | awk '{ print $6 }' | sed 's/;$//')
+mv tmp.rs src/bindings/cuda_${CUDA_VERSION}.rs
@@ -1,6 +1,7 @@
 use std::env;
 use std::path::PathBuf;
 
+#[cfg(feature = "bindgen")]
 fn find_dir(env_key: &'static str, candidates: Vec<&'static str>) -> Option<PathBuf> {
     match env::var_os(env_key) {
         Some(val) => Some(PathBuf::from(&val)),
@@ -17,28 +18,362 @@ fn find_dir(env_key: &'static str, candidates: Vec<&'static str>) -> Option<Path
     }
 }
 
-fn main() {
+/// Generates bindings from the headers of the local CUDA toolkit, with the
+/// same options `bindgen.sh` uses for the checked-in ones. The selected
+/// `cuda-*` feature must match the toolkit.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(_version: &str) -> String {
     let cuda_include = find_dir(
         "CUDA_INCLUDE_PATH",
         vec!["/opt/cuda/include", "/usr/local/cuda/include"],
     ).expect("Could not find CUDA include path");
 
-    println!("cargo:rustc-link-lib=dylib=cuda");
-
-    let bindings = bindgen::Builder::default()
+    bindgen::Builder::default()
         .clang_arg(format!("-I{}", cuda_include.to_string_lossy()))
-        .header(cuda_include.join("cuda.h").to_string_lossy())
-        .blocklist_function("strtold")
-        .blocklist_function("qecvt")
-        .blocklist_function("qfcvt")
-        .blocklist_function("qgcvt")
-        .blocklist_function("qecvt_r")
-        .blocklist_function("qfcvt_r")
+        .header("wrapper.h")
+        .allowlist_type("^CU.*")
+        .allowlist_type("^cuuint(32|64)_t")
+        .allowlist_type("^cudaError_enum")
+        .allowlist_type("^cu.*Complex$")
+        .allowlist_type("^cuda.*")
+        .allowlist_type("^libraryPropertyType.*")
+        .allowlist_var("^CU.*")
+        .allowlist_function("^cu.*")
+        .generate_comments(false)
+        .derive_default(true)
+        .derive_eq(true)
+        .derive_hash(true)
+        .derive_ord(true)
+        .use_core()
         .generate()
-        .expect("Unable to generate bindings");
+        .expect("Unable to generate bindings")
+        .to_string()
+}
+
+/// The CUDA version selected by the `cuda-*` features, e.g. `"12040"`.
+/// Features are additive, so several may be enabled, e.g. by different
+/// dependents; the newest one wins.
+fn cuda_version() -> String {
+    let mut versions: Vec<_> = env::vars()
+        .filter_map(|(key, _)| key.strip_prefix("CARGO_FEATURE_CUDA_").map(str::to_owned))
+        .collect();
+    // The versions all have five digits, so they sort as strings.
+    versions.sort();
+
+    match versions.as_slice() {
+        [] => panic!("No CUDA version selected, enable one of the `cuda-*` features"),
+        [.., version] => {
+            if versions.len() > 1 {
+                println!(
+                    "cargo:warning=Multiple CUDA versions selected ({}), using {}",
+                    versions.join(", "),
+                    version
+                );
+            }
+            version.clone()
+        }
+    }
+}
+
+/// Reads the checked-in bindings for `version`.
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings(version: &str) -> String {
+    let path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("src")
+        .join("bindings")
+        .join(format!("cuda_{}.rs", version));
+
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Couldn't read bindings from {}", path.display()))
+}
+
+fn main() {
+    if cfg!(not(any(feature = "dynamic-loading", feature = "mock"))) {
+        println!("cargo:rustc-link-lib=dylib=cuda");
+    }
+
+    // Code that differs between versions checks `cfg(cuda = "...")`
+    // rather than the features, which may name several versions.
+    // Dependents get the version as `DEP_CUDA_RS_SYS_VERSION`.
+    let version = cuda_version();
+    println!("cargo:rustc-check-cfg=cfg(cuda, values(\"11080\", \"12000\", \"12040\"))");
+    println!("cargo:rustc-cfg=cuda=\"{}\"", version);
+    println!("cargo:version={}", version);
+
+    let bindings = generate_bindings(&version);
+
+    #[cfg(feature = "per-thread-default-stream")]
+    let bindings = per_thread::rewrite(&bindings);
+
+    #[cfg(any(feature = "dynamic-loading", feature = "mock"))]
+    let bindings = dynamic::rewrite(&bindings);
 
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_path.join("bindings.rs"))
+    std::fs::write(out_path.join("bindings.rs"), bindings)
         .expect("Couldn't write bindings!");
 }
+
+/// Binds the driver functions that use the default stream to their
+/// per-thread default stream variants, like `cuda.h` does when
+/// `CUDA_API_PER_THREAD_DEFAULT_STREAM` is defined. The Rust names stay the
+/// same; only the linked symbol changes.
+#[cfg(feature = "per-thread-default-stream")]
+mod per_thread {
+    /// Functions with an implicit default stream, bound to `<name>_ptds`.
+    const PTDS: &[&str] = &[
+        "cuMemcpy",
+        "cuMemcpyPeer",
+        "cuMemcpyHtoD_v2",
+        "cuMemcpyDtoH_v2",
+        "cuMemcpyDtoD_v2",
+        "cuMemcpyDtoA_v2",
+        "cuMemcpyAtoD_v2",
+        "cuMemcpyHtoA_v2",
+        "cuMemcpyAtoH_v2",
+        "cuMemcpyAtoA_v2",
+        "cuMemcpy2D_v2",
+        "cuMemcpy2DUnaligned_v2",
+        "cuMemcpy3D_v2",
+        "cuMemcpy3DPeer",
+        "cuMemsetD8_v2",
+        "cuMemsetD16_v2",
+        "cuMemsetD32_v2",
+        "cuMemsetD2D8_v2",
+        "cuMemsetD2D16_v2",
+        "cuMemsetD2D32_v2",
+    ];
+
+    /// Functions taking a stream argument, bound to `<name>_ptsz`.
+    const PTSZ: &[&str] = &[
+        "cuMemcpyAsync",
+        "cuMemcpyPeerAsync",
+        "cuMemcpyHtoDAsync_v2",
+        "cuMemcpyDtoHAsync_v2",
+        "cuMemcpyDtoDAsync_v2",
+        "cuMemcpyHtoAAsync_v2",
+        "cuMemcpyAtoHAsync_v2",
+        "cuMemcpy2DAsync_v2",
+        "cuMemcpy3DAsync_v2",
+        "cuMemcpy3DPeerAsync",
+        "cuMemsetD8Async",
+        "cuMemsetD16Async",
+        "cuMemsetD32Async",
+        "cuMemsetD2D8Async",
+        "cuMemsetD2D16Async",
+        "cuMemsetD2D32Async",
+        "cuMemAllocAsync",
+        "cuMemAllocFromPoolAsync",
+        "cuMemFreeAsync",
+        "cuMemPrefetchAsync",
+        "cuMemMapArrayAsync",
+        "cuStreamGetPriority",
+        "cuStreamGetId",
+        "cuStreamGetFlags",
+        "cuStreamGetCtx",
+        "cuStreamWaitEvent",
+        "cuStreamAddCallback",
+        "cuStreamAttachMemAsync",
+        "cuStreamQuery",
+        "cuStreamSynchronize",
+        "cuStreamBeginCapture",
+        "cuStreamBeginCapture_v2",
+        "cuStreamEndCapture",
+        "cuStreamIsCapturing",
+        "cuStreamGetCaptureInfo",
+        "cuStreamGetCaptureInfo_v2",
+        "cuStreamUpdateCaptureDependencies",
+        "cuStreamCopyAttributes",
+        "cuStreamGetAttribute",
+        "cuStreamSetAttribute",
+        "cuStreamWaitValue32",
+        "cuStreamWaitValue64",
+        "cuStreamWriteValue32",
+        "cuStreamWriteValue64",
+        "cuStreamBatchMemOp",
+        "cuStreamWaitValue32_v2",
+        "cuStreamWaitValue64_v2",
+        "cuStreamWriteValue32_v2",
+        "cuStreamWriteValue64_v2",
+        "cuStreamBatchMemOp_v2",
+        "cuEventRecord",
+        "cuEventRecordWithFlags",
+        "cuLaunchKernel",
+        "cuLaunchKernelEx",
+        "cuLaunchHostFunc",
+        "cuLaunchCooperativeKernel",
+        "cuGraphicsMapResources",
+        "cuGraphicsUnmapResources",
+        "cuSignalExternalSemaphoresAsync",
+        "cuWaitExternalSemaphoresAsync",
+        "cuGraphUpload",
+        "cuGraphLaunch",
+    ];
+
+    pub fn rewrite(bindings: &str) -> String {
+        let mut bindings = bindings.to_owned();
+        let suffixed = PTDS.iter().map(|name| (name, "_ptds"))
+            .chain(PTSZ.iter().map(|name| (name, "_ptsz")));
+        for (name, suffix) in suffixed {
+            bindings = bindings.replace(
+                &format!("pub fn {}(", name),
+                &format!("#[link_name = \"{}{}\"] pub fn {}(", name, suffix, name),
+            );
+        }
+
+        bindings
+    }
+}
+
+/// Turns the `extern "C"` driver declarations into a table of function
+/// pointers resolved at runtime, plus free functions of the same name that
+/// dispatch through it. Everything else in the bindings is kept as is.
+#[cfg(any(feature = "dynamic-loading", feature = "mock"))]
+mod dynamic {
+    use quote::{format_ident, quote};
+    use syn::{FnArg, ForeignItem, Item, Pat, ReturnType};
+
+    fn returns_curesult(output: &ReturnType) -> bool {
+        match output {
+            ReturnType::Type(_, ty) => quote!(#ty).to_string() == "CUresult",
+            ReturnType::Default => false,
+        }
+    }
+
+    /// The symbol set with `#[link_name]`, if it differs from the name.
+    fn link_name(func: &syn::ForeignItemFn) -> Option<String> {
+        func.attrs.iter().find_map(|attr| {
+            let syn::Meta::NameValue(ref meta) = attr.meta else {
+                return None;
+            };
+            if !meta.path.is_ident("link_name") {
+                return None;
+            }
+            match meta.value {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(ref s), .. }) => Some(s.value()),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn rewrite(bindings: &str) -> String {
+        let file = syn::parse_file(bindings).expect("Unable to parse bindings");
+
+        let mut items = Vec::new();
+        let mut fields = Vec::new();
+        let mut loads = Vec::new();
+        let mut shims = Vec::new();
+        let mut error_names = Vec::new();
+
+        for item in file.items {
+            let Item::ForeignMod(mut foreign) = item else {
+                if let Item::Const(ref constant) = item {
+                    let ident = &constant.ident;
+                    if let Some(name) = ident.to_string().strip_prefix("cudaError_enum_") {
+                        let name = proc_macro2::Literal::byte_string(
+                            format!("{}\0", name).as_bytes(),
+                        );
+                        error_names.push(quote!((#ident, #name)));
+                    }
+                }
+                items.push(item);
+                continue;
+            };
+
+            let mut kept = Vec::new();
+            for foreign_item in foreign.items {
+                let func = match foreign_item {
+                    ForeignItem::Fn(func) if returns_curesult(&func.sig.output) => func,
+                    other => {
+                        kept.push(other);
+                        continue;
+                    }
+                };
+
+                let name = &func.sig.ident;
+                let symbol = proc_macro2::Literal::byte_string(
+                    format!("{}\0", link_name(&func).unwrap_or_else(|| name.to_string())).as_bytes(),
+                );
+                let mut args = Vec::new();
+                let mut params = Vec::new();
+                let mut tys = Vec::new();
+                for (i, input) in func.sig.inputs.iter().enumerate() {
+                    let FnArg::Typed(arg) = input else {
+                        unreachable!("foreign functions have no receiver");
+                    };
+                    let arg_name = match *arg.pat {
+                        Pat::Ident(ref pat) => pat.ident.clone(),
+                        _ => format_ident!("arg{}", i),
+                    };
+                    let ty = &arg.ty;
+                    params.push(quote!(#arg_name: #ty));
+                    args.push(arg_name);
+                    tys.push(ty);
+                }
+                let output = &func.sig.output;
+                let pfn = quote!(unsafe extern "C" fn(#(#tys),*) #output);
+
+                fields.push(quote!(pub #name: Option<#pfn>));
+                loads.push(quote! {
+                    #name: ::std::mem::transmute::<*const ::std::os::raw::c_void, Option<#pfn>>(
+                        lookup(#symbol)
+                    )
+                });
+                shims.push(quote! {
+                    pub unsafe fn #name(#(#params),*) -> CUresult {
+                        match crate::driver::table() {
+                            Ok(__table) => match __table.#name {
+                                Some(__f) => __f(#(#args),*),
+                                None => crate::driver::SYMBOL_NOT_FOUND,
+                            },
+                            Err(__res) => __res,
+                        }
+                    }
+                });
+            }
+
+            if !kept.is_empty() {
+                foreign.items = kept;
+                items.push(Item::ForeignMod(foreign));
+            }
+        }
+
+        let items = items.iter();
+        quote! {
+            #(#items)*
+
+            /// Driver API entry points, resolved at runtime.
+            ///
+            /// A `None` entry means the loaded driver does not export the symbol.
+            pub struct DriverTable {
+                #(#fields,)*
+            }
+
+            impl DriverTable {
+                /// Builds the table by resolving every entry point with `lookup`,
+                /// which receives a NUL-terminated symbol name and returns its
+                /// address, or null if it is not available.
+                ///
+                /// # Safety
+                ///
+                /// Every non-null address returned by `lookup` must point to a
+                /// function with the signature declared in `cuda.h`.
+                pub unsafe fn load<F>(mut lookup: F) -> Self
+                where
+                    F: FnMut(&'static [u8]) -> *const ::std::os::raw::c_void,
+                {
+                    DriverTable {
+                        #(#loads,)*
+                    }
+                }
+            }
+
+            #(#shims)*
+
+            /// NUL-terminated names of the `CUresult` values in these bindings.
+            pub(crate) const ERROR_NAMES: &[(CUresult, &[u8])] = &[
+                #(#error_names,)*
+            ];
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,9581 @@
+/* automatically generated by rust-bindgen 0.69.4 */
+
+pub const CUDA_VERSION: u32 = 12040;
+pub const CU_IPC_HANDLE_SIZE: u32 = 64;
+pub const CU_COMPUTE_ACCELERATED_TARGET_BASE: u32 = 65536;
+pub const CU_GRAPH_COND_ASSIGN_DEFAULT: u32 = 1;
+pub const CU_GRAPH_KERNEL_NODE_PORT_DEFAULT: u32 = 0;
+pub const CU_GRAPH_KERNEL_NODE_PORT_PROGRAMMATIC: u32 = 1;
+pub const CU_GRAPH_KERNEL_NODE_PORT_LAUNCH_ORDER: u32 = 2;
+pub const CU_MEMHOSTALLOC_PORTABLE: u32 = 1;
+pub const CU_MEMHOSTALLOC_DEVICEMAP: u32 = 2;
+pub const CU_MEMHOSTALLOC_WRITECOMBINED: u32 = 4;
+pub const CU_MEMHOSTREGISTER_PORTABLE: u32 = 1;
+pub const CU_MEMHOSTREGISTER_DEVICEMAP: u32 = 2;
+pub const CU_MEMHOSTREGISTER_IOMEMORY: u32 = 4;
+pub const CU_MEMHOSTREGISTER_READ_ONLY: u32 = 8;
+pub const CU_ARRAY_SPARSE_PROPERTIES_SINGLE_MIPTAIL: u32 = 1;
+pub const CU_TENSOR_MAP_NUM_QWORDS: u32 = 16;
+pub const CUDA_EXTERNAL_MEMORY_DEDICATED: u32 = 1;
+pub const CUDA_EXTERNAL_SEMAPHORE_SIGNAL_SKIP_NVSCIBUF_MEMSYNC: u32 = 1;
+pub const CUDA_EXTERNAL_SEMAPHORE_WAIT_SKIP_NVSCIBUF_MEMSYNC: u32 = 2;
+pub const CUDA_NVSCISYNC_ATTR_SIGNAL: u32 = 1;
+pub const CUDA_NVSCISYNC_ATTR_WAIT: u32 = 2;
+pub const CU_MEM_CREATE_USAGE_TILE_POOL: u32 = 1;
+pub const CUDA_COOPERATIVE_LAUNCH_MULTI_DEVICE_NO_PRE_LAUNCH_SYNC: u32 = 1;
+pub const CUDA_COOPERATIVE_LAUNCH_MULTI_DEVICE_NO_POST_LAUNCH_SYNC: u32 = 2;
+pub const CUDA_ARRAY3D_LAYERED: u32 = 1;
+pub const CUDA_ARRAY3D_2DARRAY: u32 = 1;
+pub const CUDA_ARRAY3D_SURFACE_LDST: u32 = 2;
+pub const CUDA_ARRAY3D_CUBEMAP: u32 = 4;
+pub const CUDA_ARRAY3D_TEXTURE_GATHER: u32 = 8;
+pub const CUDA_ARRAY3D_DEPTH_TEXTURE: u32 = 16;
+pub const CUDA_ARRAY3D_COLOR_ATTACHMENT: u32 = 32;
+pub const CUDA_ARRAY3D_SPARSE: u32 = 64;
+pub const CUDA_ARRAY3D_DEFERRED_MAPPING: u32 = 128;
+pub const CU_TRSA_OVERRIDE_FORMAT: u32 = 1;
+pub const CU_TRSF_READ_AS_INTEGER: u32 = 1;
+pub const CU_TRSF_NORMALIZED_COORDINATES: u32 = 2;
+pub const CU_TRSF_SRGB: u32 = 16;
+pub const CU_TRSF_DISABLE_TRILINEAR_OPTIMIZATION: u32 = 32;
+pub const CU_TRSF_SEAMLESS_CUBEMAP: u32 = 64;
+pub const CU_LAUNCH_PARAM_END_AS_INT: u32 = 0;
+pub const CU_LAUNCH_PARAM_BUFFER_POINTER_AS_INT: u32 = 1;
+pub const CU_LAUNCH_PARAM_BUFFER_SIZE_AS_INT: u32 = 2;
+pub const CU_PARAM_TR_DEFAULT: i32 = -1;
+pub type cuuint32_t = u32;
+pub type cuuint64_t = u64;
+pub type CUdeviceptr_v2 = ::core::ffi::c_ulonglong;
+pub type CUdeviceptr = CUdeviceptr_v2;
+pub type CUdevice_v1 = ::core::ffi::c_int;
+pub type CUdevice = CUdevice_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUctx_st {
+    _unused: [u8; 0],
+}
+pub type CUcontext = *mut CUctx_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUmod_st {
+    _unused: [u8; 0],
+}
+pub type CUmodule = *mut CUmod_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUfunc_st {
+    _unused: [u8; 0],
+}
+pub type CUfunction = *mut CUfunc_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUlib_st {
+    _unused: [u8; 0],
+}
+pub type CUlibrary = *mut CUlib_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUkern_st {
+    _unused: [u8; 0],
+}
+pub type CUkernel = *mut CUkern_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUarray_st {
+    _unused: [u8; 0],
+}
+pub type CUarray = *mut CUarray_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUmipmappedArray_st {
+    _unused: [u8; 0],
+}
+pub type CUmipmappedArray = *mut CUmipmappedArray_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUtexref_st {
+    _unused: [u8; 0],
+}
+pub type CUtexref = *mut CUtexref_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUsurfref_st {
+    _unused: [u8; 0],
+}
+pub type CUsurfref = *mut CUsurfref_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUevent_st {
+    _unused: [u8; 0],
+}
+pub type CUevent = *mut CUevent_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUstream_st {
+    _unused: [u8; 0],
+}
+pub type CUstream = *mut CUstream_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUgraphicsResource_st {
+    _unused: [u8; 0],
+}
+pub type CUgraphicsResource = *mut CUgraphicsResource_st;
+pub type CUtexObject_v1 = ::core::ffi::c_ulonglong;
+pub type CUtexObject = CUtexObject_v1;
+pub type CUsurfObject_v1 = ::core::ffi::c_ulonglong;
+pub type CUsurfObject = CUsurfObject_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUextMemory_st {
+    _unused: [u8; 0],
+}
+pub type CUexternalMemory = *mut CUextMemory_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUextSemaphore_st {
+    _unused: [u8; 0],
+}
+pub type CUexternalSemaphore = *mut CUextSemaphore_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUgraph_st {
+    _unused: [u8; 0],
+}
+pub type CUgraph = *mut CUgraph_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUgraphNode_st {
+    _unused: [u8; 0],
+}
+pub type CUgraphNode = *mut CUgraphNode_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUgraphExec_st {
+    _unused: [u8; 0],
+}
+pub type CUgraphExec = *mut CUgraphExec_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUmemPoolHandle_st {
+    _unused: [u8; 0],
+}
+pub type CUmemoryPool = *mut CUmemPoolHandle_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUuserObject_st {
+    _unused: [u8; 0],
+}
+pub type CUuserObject = *mut CUuserObject_st;
+pub type CUgraphConditionalHandle = cuuint64_t;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUgraphDeviceUpdatableNode_st {
+    _unused: [u8; 0],
+}
+pub type CUgraphDeviceNode = *mut CUgraphDeviceUpdatableNode_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUasyncCallbackEntry_st {
+    _unused: [u8; 0],
+}
+pub type CUasyncCallbackHandle = *mut CUasyncCallbackEntry_st;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUuuid_st {
+    pub bytes: [::core::ffi::c_char; 16usize],
+}
+#[test]
+fn bindgen_test_layout_CUuuid_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUuuid_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUuuid_st > (), 16usize, concat!("Size of: ",
+        stringify!(CUuuid_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUuuid_st > (), 1usize, concat!("Alignment of ",
+        stringify!(CUuuid_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).bytes) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUuuid_st), "::", stringify!(bytes))
+    );
+}
+pub type CUuuid = CUuuid_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUmemFabricHandle_st {
+    pub data: [::core::ffi::c_uchar; 64usize],
+}
+#[test]
+fn bindgen_test_layout_CUmemFabricHandle_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUmemFabricHandle_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUmemFabricHandle_st > (), 64usize, concat!("Size of: ",
+        stringify!(CUmemFabricHandle_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUmemFabricHandle_st > (), 1usize,
+        concat!("Alignment of ", stringify!(CUmemFabricHandle_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).data) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUmemFabricHandle_st), "::",
+        stringify!(data))
+    );
+}
+impl Default for CUmemFabricHandle_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUmemFabricHandle_v1 = CUmemFabricHandle_st;
+pub type CUmemFabricHandle = CUmemFabricHandle_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUipcEventHandle_st {
+    pub reserved: [::core::ffi::c_char; 64usize],
+}
+#[test]
+fn bindgen_test_layout_CUipcEventHandle_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUipcEventHandle_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUipcEventHandle_st > (), 64usize, concat!("Size of: ",
+        stringify!(CUipcEventHandle_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUipcEventHandle_st > (), 1usize,
+        concat!("Alignment of ", stringify!(CUipcEventHandle_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUipcEventHandle_st), "::",
+        stringify!(reserved))
+    );
+}
+impl Default for CUipcEventHandle_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUipcEventHandle_v1 = CUipcEventHandle_st;
+pub type CUipcEventHandle = CUipcEventHandle_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUipcMemHandle_st {
+    pub reserved: [::core::ffi::c_char; 64usize],
+}
+#[test]
+fn bindgen_test_layout_CUipcMemHandle_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUipcMemHandle_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUipcMemHandle_st > (), 64usize, concat!("Size of: ",
+        stringify!(CUipcMemHandle_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUipcMemHandle_st > (), 1usize, concat!("Alignment of ",
+        stringify!(CUipcMemHandle_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUipcMemHandle_st), "::",
+        stringify!(reserved))
+    );
+}
+impl Default for CUipcMemHandle_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUipcMemHandle_v1 = CUipcMemHandle_st;
+pub type CUipcMemHandle = CUipcMemHandle_v1;
+pub const CUipcMem_flags_enum_CU_IPC_MEM_LAZY_ENABLE_PEER_ACCESS: CUipcMem_flags_enum = 1;
+pub type CUipcMem_flags_enum = ::core::ffi::c_uint;
+pub use self::CUipcMem_flags_enum as CUipcMem_flags;
+pub const CUmemAttach_flags_enum_CU_MEM_ATTACH_GLOBAL: CUmemAttach_flags_enum = 1;
+pub const CUmemAttach_flags_enum_CU_MEM_ATTACH_HOST: CUmemAttach_flags_enum = 2;
+pub const CUmemAttach_flags_enum_CU_MEM_ATTACH_SINGLE: CUmemAttach_flags_enum = 4;
+pub type CUmemAttach_flags_enum = ::core::ffi::c_uint;
+pub use self::CUmemAttach_flags_enum as CUmemAttach_flags;
+pub const CUctx_flags_enum_CU_CTX_BLOCKING_SYNC: CUctx_flags_enum = 4;
+pub const CUctx_flags_enum_CU_CTX_SCHED_AUTO: CUctx_flags_enum = 0;
+pub const CUctx_flags_enum_CU_CTX_SCHED_SPIN: CUctx_flags_enum = 1;
+pub const CUctx_flags_enum_CU_CTX_SCHED_YIELD: CUctx_flags_enum = 2;
+pub const CUctx_flags_enum_CU_CTX_SCHED_BLOCKING_SYNC: CUctx_flags_enum = 4;
+pub const CUctx_flags_enum_CU_CTX_SCHED_MASK: CUctx_flags_enum = 7;
+pub const CUctx_flags_enum_CU_CTX_MAP_HOST: CUctx_flags_enum = 8;
+pub const CUctx_flags_enum_CU_CTX_LMEM_RESIZE_TO_MAX: CUctx_flags_enum = 16;
+pub const CUctx_flags_enum_CU_CTX_COREDUMP_ENABLE: CUctx_flags_enum = 32;
+pub const CUctx_flags_enum_CU_CTX_USER_COREDUMP_ENABLE: CUctx_flags_enum = 64;
+pub const CUctx_flags_enum_CU_CTX_SYNC_MEMOPS: CUctx_flags_enum = 128;
+pub const CUctx_flags_enum_CU_CTX_FLAGS_MASK: CUctx_flags_enum = 255;
+pub type CUctx_flags_enum = ::core::ffi::c_uint;
+pub use self::CUctx_flags_enum as CUctx_flags;
+pub const CUevent_sched_flags_enum_CU_EVENT_SCHED_AUTO: CUevent_sched_flags_enum = 0;
+pub const CUevent_sched_flags_enum_CU_EVENT_SCHED_SPIN: CUevent_sched_flags_enum = 1;
+pub const CUevent_sched_flags_enum_CU_EVENT_SCHED_YIELD: CUevent_sched_flags_enum = 2;
+pub const CUevent_sched_flags_enum_CU_EVENT_SCHED_BLOCKING_SYNC: CUevent_sched_flags_enum = 4;
+pub type CUevent_sched_flags_enum = ::core::ffi::c_uint;
+pub use self::CUevent_sched_flags_enum as CUevent_sched_flags;
+pub const CUstream_flags_enum_CU_STREAM_DEFAULT: CUstream_flags_enum = 0;
+pub const CUstream_flags_enum_CU_STREAM_NON_BLOCKING: CUstream_flags_enum = 1;
+pub type CUstream_flags_enum = ::core::ffi::c_uint;
+pub use self::CUstream_flags_enum as CUstream_flags;
+pub const CUevent_flags_enum_CU_EVENT_DEFAULT: CUevent_flags_enum = 0;
+pub const CUevent_flags_enum_CU_EVENT_BLOCKING_SYNC: CUevent_flags_enum = 1;
+pub const CUevent_flags_enum_CU_EVENT_DISABLE_TIMING: CUevent_flags_enum = 2;
+pub const CUevent_flags_enum_CU_EVENT_INTERPROCESS: CUevent_flags_enum = 4;
+pub type CUevent_flags_enum = ::core::ffi::c_uint;
+pub use self::CUevent_flags_enum as CUevent_flags;
+pub const CUevent_record_flags_enum_CU_EVENT_RECORD_DEFAULT: CUevent_record_flags_enum = 0;
+pub const CUevent_record_flags_enum_CU_EVENT_RECORD_EXTERNAL: CUevent_record_flags_enum = 1;
+pub type CUevent_record_flags_enum = ::core::ffi::c_uint;
+pub use self::CUevent_record_flags_enum as CUevent_record_flags;
+pub const CUevent_wait_flags_enum_CU_EVENT_WAIT_DEFAULT: CUevent_wait_flags_enum = 0;
+pub const CUevent_wait_flags_enum_CU_EVENT_WAIT_EXTERNAL: CUevent_wait_flags_enum = 1;
+pub type CUevent_wait_flags_enum = ::core::ffi::c_uint;
+pub use self::CUevent_wait_flags_enum as CUevent_wait_flags;
+pub const CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_GEQ: CUstreamWaitValue_flags_enum = 0;
+pub const CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_EQ: CUstreamWaitValue_flags_enum = 1;
+pub const CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_AND: CUstreamWaitValue_flags_enum = 2;
+pub const CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_NOR: CUstreamWaitValue_flags_enum = 3;
+pub const CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_FLUSH: CUstreamWaitValue_flags_enum = 1073741824;
+pub type CUstreamWaitValue_flags_enum = ::core::ffi::c_uint;
+pub use self::CUstreamWaitValue_flags_enum as CUstreamWaitValue_flags;
+pub const CUstreamWriteValue_flags_enum_CU_STREAM_WRITE_VALUE_DEFAULT: CUstreamWriteValue_flags_enum = 0;
+pub const CUstreamWriteValue_flags_enum_CU_STREAM_WRITE_VALUE_NO_MEMORY_BARRIER: CUstreamWriteValue_flags_enum = 1;
+pub type CUstreamWriteValue_flags_enum = ::core::ffi::c_uint;
+pub use self::CUstreamWriteValue_flags_enum as CUstreamWriteValue_flags;
+pub const CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WAIT_VALUE_32: CUstreamBatchMemOpType_enum = 1;
+pub const CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WRITE_VALUE_32: CUstreamBatchMemOpType_enum = 2;
+pub const CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WAIT_VALUE_64: CUstreamBatchMemOpType_enum = 4;
+pub const CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WRITE_VALUE_64: CUstreamBatchMemOpType_enum = 5;
+pub const CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_BARRIER: CUstreamBatchMemOpType_enum = 6;
+pub const CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_FLUSH_REMOTE_WRITES: CUstreamBatchMemOpType_enum = 3;
+pub type CUstreamBatchMemOpType_enum = ::core::ffi::c_uint;
+pub use self::CUstreamBatchMemOpType_enum as CUstreamBatchMemOpType;
+pub const CUstreamMemoryBarrier_flags_enum_CU_STREAM_MEMORY_BARRIER_TYPE_SYS: CUstreamMemoryBarrier_flags_enum = 0;
+pub const CUstreamMemoryBarrier_flags_enum_CU_STREAM_MEMORY_BARRIER_TYPE_GPU: CUstreamMemoryBarrier_flags_enum = 1;
+pub type CUstreamMemoryBarrier_flags_enum = ::core::ffi::c_uint;
+pub use self::CUstreamMemoryBarrier_flags_enum as CUstreamMemoryBarrier_flags;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUstreamBatchMemOpParams_union {
+    pub operation: CUstreamBatchMemOpType,
+    pub waitValue: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
+    pub writeValue: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
+    pub flushRemoteWrites: CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st,
+    pub memoryBarrier: CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st,
+    pub pad: [cuuint64_t; 6usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st {
+    pub operation: CUstreamBatchMemOpType,
+    pub address: CUdeviceptr,
+    pub __bindgen_anon_1: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1,
+    pub flags: ::core::ffi::c_uint,
+    pub alias: CUdeviceptr,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1 {
+    pub value: cuuint32_t,
+    pub value64: cuuint64_t,
+}
+#[test]
+fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1, >
+        (), 8usize, concat!("Size of: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1, >
+        (), 8usize, concat!("Alignment of ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).value) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1),
+        "::", stringify!(value))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).value64) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1),
+        "::", stringify!(value64))
+    );
+}
+impl Default
+for CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st > (), 40usize,
+        concat!("Size of: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st > (), 8usize,
+        concat!("Alignment of ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).operation) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st), "::",
+        stringify!(operation))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).address) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st), "::",
+        stringify!(address))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st), "::",
+        stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).alias) as usize - ptr as usize }, 32usize,
+        concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st), "::",
+        stringify!(alias))
+    );
+}
+impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st {
+    pub operation: CUstreamBatchMemOpType,
+    pub address: CUdeviceptr,
+    pub __bindgen_anon_1: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1,
+    pub flags: ::core::ffi::c_uint,
+    pub alias: CUdeviceptr,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1 {
+    pub value: cuuint32_t,
+    pub value64: cuuint64_t,
+}
+#[test]
+fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1, >
+        (), 8usize, concat!("Size of: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1, >
+        (), 8usize, concat!("Alignment of ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).value) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1),
+        "::", stringify!(value))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).value64) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1),
+        "::", stringify!(value64))
+    );
+}
+impl Default
+for CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st > (), 40usize,
+        concat!("Size of: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st > (), 8usize,
+        concat!("Alignment of ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).operation) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st),
+        "::", stringify!(operation))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).address) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st),
+        "::", stringify!(address))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st),
+        "::", stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).alias) as usize - ptr as usize }, 32usize,
+        concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st),
+        "::", stringify!(alias))
+    );
+}
+impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st {
+    pub operation: CUstreamBatchMemOpType,
+    pub flags: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st, > (),
+        8usize, concat!("Size of: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st, > (),
+        4usize, concat!("Alignment of ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).operation) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st),
+        "::", stringify!(operation))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 4usize,
+        concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st),
+        "::", stringify!(flags))
+    );
+}
+impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st {
+    pub operation: CUstreamBatchMemOpType,
+    pub flags: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st > (), 8usize,
+        concat!("Size of: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st > (), 4usize,
+        concat!("Alignment of ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).operation) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st),
+        "::", stringify!(operation))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 4usize,
+        concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st),
+        "::", stringify!(flags))
+    );
+}
+impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUstreamBatchMemOpParams_union() {
+    const UNINIT: ::core::mem::MaybeUninit<CUstreamBatchMemOpParams_union> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUstreamBatchMemOpParams_union > (), 48usize,
+        concat!("Size of: ", stringify!(CUstreamBatchMemOpParams_union))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUstreamBatchMemOpParams_union > (), 8usize,
+        concat!("Alignment of ", stringify!(CUstreamBatchMemOpParams_union))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).operation) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUstreamBatchMemOpParams_union),
+        "::", stringify!(operation))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).waitValue) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUstreamBatchMemOpParams_union),
+        "::", stringify!(waitValue))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).writeValue) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUstreamBatchMemOpParams_union),
+        "::", stringify!(writeValue))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flushRemoteWrites) as usize - ptr as usize
+        }, 0usize, concat!("Offset of field: ",
+        stringify!(CUstreamBatchMemOpParams_union), "::", stringify!(flushRemoteWrites))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memoryBarrier) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUstreamBatchMemOpParams_union),
+        "::", stringify!(memoryBarrier))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).pad) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUstreamBatchMemOpParams_union), "::",
+        stringify!(pad))
+    );
+}
+impl Default for CUstreamBatchMemOpParams_union {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUstreamBatchMemOpParams_v1 = CUstreamBatchMemOpParams_union;
+pub type CUstreamBatchMemOpParams = CUstreamBatchMemOpParams_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st {
+    pub ctx: CUcontext,
+    pub count: ::core::ffi::c_uint,
+    pub paramArray: *mut CUstreamBatchMemOpParams,
+    pub flags: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st > (), 32usize,
+        concat!("Size of: ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).ctx) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st),
+        "::", stringify!(ctx))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).count) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st),
+        "::", stringify!(count))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).paramArray) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st), "::", stringify!(paramArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st),
+        "::", stringify!(flags))
+    );
+}
+impl Default for CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_BATCH_MEM_OP_NODE_PARAMS_v1 = CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st;
+pub type CUDA_BATCH_MEM_OP_NODE_PARAMS = CUDA_BATCH_MEM_OP_NODE_PARAMS_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st {
+    pub ctx: CUcontext,
+    pub count: ::core::ffi::c_uint,
+    pub paramArray: *mut CUstreamBatchMemOpParams,
+    pub flags: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st > (), 32usize,
+        concat!("Size of: ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).ctx) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st),
+        "::", stringify!(ctx))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).count) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st),
+        "::", stringify!(count))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).paramArray) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st), "::", stringify!(paramArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st),
+        "::", stringify!(flags))
+    );
+}
+impl Default for CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_BATCH_MEM_OP_NODE_PARAMS_v2 = CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st;
+pub const CUoccupancy_flags_enum_CU_OCCUPANCY_DEFAULT: CUoccupancy_flags_enum = 0;
+pub const CUoccupancy_flags_enum_CU_OCCUPANCY_DISABLE_CACHING_OVERRIDE: CUoccupancy_flags_enum = 1;
+pub type CUoccupancy_flags_enum = ::core::ffi::c_uint;
+pub use self::CUoccupancy_flags_enum as CUoccupancy_flags;
+pub const CUstreamUpdateCaptureDependencies_flags_enum_CU_STREAM_ADD_CAPTURE_DEPENDENCIES: CUstreamUpdateCaptureDependencies_flags_enum = 0;
+pub const CUstreamUpdateCaptureDependencies_flags_enum_CU_STREAM_SET_CAPTURE_DEPENDENCIES: CUstreamUpdateCaptureDependencies_flags_enum = 1;
+pub type CUstreamUpdateCaptureDependencies_flags_enum = ::core::ffi::c_uint;
+pub use self::CUstreamUpdateCaptureDependencies_flags_enum as CUstreamUpdateCaptureDependencies_flags;
+pub const CUasyncNotificationType_enum_CU_ASYNC_NOTIFICATION_TYPE_OVER_BUDGET: CUasyncNotificationType_enum = 1;
+pub type CUasyncNotificationType_enum = ::core::ffi::c_uint;
+pub use self::CUasyncNotificationType_enum as CUasyncNotificationType;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUasyncNotificationInfo_st {
+    pub type_: CUasyncNotificationType,
+    pub info: CUasyncNotificationInfo_st__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUasyncNotificationInfo_st__bindgen_ty_1 {
+    pub overBudget: CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1 {
+    pub bytesOverBudget: ::core::ffi::c_ulonglong,
+}
+#[test]
+fn bindgen_test_layout_CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1 >
+        (), 8usize, concat!("Size of: ",
+        stringify!(CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1 >
+        (), 8usize, concat!("Alignment of ",
+        stringify!(CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).bytesOverBudget) as usize - ptr as usize
+        }, 0usize, concat!("Offset of field: ",
+        stringify!(CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1), "::",
+        stringify!(bytesOverBudget))
+    );
+}
+#[test]
+fn bindgen_test_layout_CUasyncNotificationInfo_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<CUasyncNotificationInfo_st__bindgen_ty_1> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUasyncNotificationInfo_st__bindgen_ty_1 > (), 8usize,
+        concat!("Size of: ", stringify!(CUasyncNotificationInfo_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUasyncNotificationInfo_st__bindgen_ty_1 > (), 8usize,
+        concat!("Alignment of ", stringify!(CUasyncNotificationInfo_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).overBudget) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUasyncNotificationInfo_st__bindgen_ty_1), "::",
+        stringify!(overBudget))
+    );
+}
+impl Default for CUasyncNotificationInfo_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUasyncNotificationInfo_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUasyncNotificationInfo_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUasyncNotificationInfo_st > (), 16usize,
+        concat!("Size of: ", stringify!(CUasyncNotificationInfo_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUasyncNotificationInfo_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUasyncNotificationInfo_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUasyncNotificationInfo_st), "::",
+        stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).info) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUasyncNotificationInfo_st), "::",
+        stringify!(info))
+    );
+}
+impl Default for CUasyncNotificationInfo_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUasyncNotificationInfo = CUasyncNotificationInfo_st;
+pub type CUasyncCallback = ::core::option::Option<
+    unsafe extern "C" fn(
+        info: *mut CUasyncNotificationInfo,
+        userData: *mut ::core::ffi::c_void,
+        callback: CUasyncCallbackHandle,
+    ),
+>;
+pub const CUarray_format_enum_CU_AD_FORMAT_UNSIGNED_INT8: CUarray_format_enum = 1;
+pub const CUarray_format_enum_CU_AD_FORMAT_UNSIGNED_INT16: CUarray_format_enum = 2;
+pub const CUarray_format_enum_CU_AD_FORMAT_UNSIGNED_INT32: CUarray_format_enum = 3;
+pub const CUarray_format_enum_CU_AD_FORMAT_SIGNED_INT8: CUarray_format_enum = 8;
+pub const CUarray_format_enum_CU_AD_FORMAT_SIGNED_INT16: CUarray_format_enum = 9;
+pub const CUarray_format_enum_CU_AD_FORMAT_SIGNED_INT32: CUarray_format_enum = 10;
+pub const CUarray_format_enum_CU_AD_FORMAT_HALF: CUarray_format_enum = 16;
+pub const CUarray_format_enum_CU_AD_FORMAT_FLOAT: CUarray_format_enum = 32;
+pub const CUarray_format_enum_CU_AD_FORMAT_NV12: CUarray_format_enum = 176;
+pub const CUarray_format_enum_CU_AD_FORMAT_UNORM_INT8X1: CUarray_format_enum = 192;
+pub const CUarray_format_enum_CU_AD_FORMAT_UNORM_INT8X2: CUarray_format_enum = 193;
+pub const CUarray_format_enum_CU_AD_FORMAT_UNORM_INT8X4: CUarray_format_enum = 194;
+pub const CUarray_format_enum_CU_AD_FORMAT_UNORM_INT16X1: CUarray_format_enum = 195;
+pub const CUarray_format_enum_CU_AD_FORMAT_UNORM_INT16X2: CUarray_format_enum = 196;
+pub const CUarray_format_enum_CU_AD_FORMAT_UNORM_INT16X4: CUarray_format_enum = 197;
+pub const CUarray_format_enum_CU_AD_FORMAT_SNORM_INT8X1: CUarray_format_enum = 198;
+pub const CUarray_format_enum_CU_AD_FORMAT_SNORM_INT8X2: CUarray_format_enum = 199;
+pub const CUarray_format_enum_CU_AD_FORMAT_SNORM_INT8X4: CUarray_format_enum = 200;
+pub const CUarray_format_enum_CU_AD_FORMAT_SNORM_INT16X1: CUarray_format_enum = 201;
+pub const CUarray_format_enum_CU_AD_FORMAT_SNORM_INT16X2: CUarray_format_enum = 202;
+pub const CUarray_format_enum_CU_AD_FORMAT_SNORM_INT16X4: CUarray_format_enum = 203;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC1_UNORM: CUarray_format_enum = 145;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC1_UNORM_SRGB: CUarray_format_enum = 146;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC2_UNORM: CUarray_format_enum = 147;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC2_UNORM_SRGB: CUarray_format_enum = 148;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC3_UNORM: CUarray_format_enum = 149;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC3_UNORM_SRGB: CUarray_format_enum = 150;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC4_UNORM: CUarray_format_enum = 151;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC4_SNORM: CUarray_format_enum = 152;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC5_UNORM: CUarray_format_enum = 153;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC5_SNORM: CUarray_format_enum = 154;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC6H_UF16: CUarray_format_enum = 155;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC6H_SF16: CUarray_format_enum = 156;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC7_UNORM: CUarray_format_enum = 157;
+pub const CUarray_format_enum_CU_AD_FORMAT_BC7_UNORM_SRGB: CUarray_format_enum = 158;
+pub type CUarray_format_enum = ::core::ffi::c_uint;
+pub use self::CUarray_format_enum as CUarray_format;
+pub const CUaddress_mode_enum_CU_TR_ADDRESS_MODE_WRAP: CUaddress_mode_enum = 0;
+pub const CUaddress_mode_enum_CU_TR_ADDRESS_MODE_CLAMP: CUaddress_mode_enum = 1;
+pub const CUaddress_mode_enum_CU_TR_ADDRESS_MODE_MIRROR: CUaddress_mode_enum = 2;
+pub const CUaddress_mode_enum_CU_TR_ADDRESS_MODE_BORDER: CUaddress_mode_enum = 3;
+pub type CUaddress_mode_enum = ::core::ffi::c_uint;
+pub use self::CUaddress_mode_enum as CUaddress_mode;
+pub const CUfilter_mode_enum_CU_TR_FILTER_MODE_POINT: CUfilter_mode_enum = 0;
+pub const CUfilter_mode_enum_CU_TR_FILTER_MODE_LINEAR: CUfilter_mode_enum = 1;
+pub type CUfilter_mode_enum = ::core::ffi::c_uint;
+pub use self::CUfilter_mode_enum as CUfilter_mode;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_SHARED_MEMORY_PER_BLOCK: CUdevice_attribute_enum = 8;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_REGISTERS_PER_BLOCK: CUdevice_attribute_enum = 12;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_ARRAY_WIDTH: CUdevice_attribute_enum = 27;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_ARRAY_HEIGHT: CUdevice_attribute_enum = 28;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_ARRAY_NUMSLICES: CUdevice_attribute_enum = 29;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_VIRTUAL_MEMORY_MANAGEMENT_SUPPORTED: CUdevice_attribute_enum = 102;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_BLOCK: CUdevice_attribute_enum = 1;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_X: CUdevice_attribute_enum = 2;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_Y: CUdevice_attribute_enum = 3;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_Z: CUdevice_attribute_enum = 4;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_X: CUdevice_attribute_enum = 5;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_Y: CUdevice_attribute_enum = 6;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_Z: CUdevice_attribute_enum = 7;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK: CUdevice_attribute_enum = 8;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_TOTAL_CONSTANT_MEMORY: CUdevice_attribute_enum = 9;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_WARP_SIZE: CUdevice_attribute_enum = 10;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_PITCH: CUdevice_attribute_enum = 11;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_REGISTERS_PER_BLOCK: CUdevice_attribute_enum = 12;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CLOCK_RATE: CUdevice_attribute_enum = 13;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_TEXTURE_ALIGNMENT: CUdevice_attribute_enum = 14;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GPU_OVERLAP: CUdevice_attribute_enum = 15;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MULTIPROCESSOR_COUNT: CUdevice_attribute_enum = 16;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_KERNEL_EXEC_TIMEOUT: CUdevice_attribute_enum = 17;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_INTEGRATED: CUdevice_attribute_enum = 18;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_MAP_HOST_MEMORY: CUdevice_attribute_enum = 19;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COMPUTE_MODE: CUdevice_attribute_enum = 20;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE1D_WIDTH: CUdevice_attribute_enum = 21;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_WIDTH: CUdevice_attribute_enum = 22;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_HEIGHT: CUdevice_attribute_enum = 23;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE3D_WIDTH: CUdevice_attribute_enum = 24;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE3D_HEIGHT: CUdevice_attribute_enum = 25;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE3D_DEPTH: CUdevice_attribute_enum = 26;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_LAYERED_WIDTH: CUdevice_attribute_enum = 27;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_LAYERED_HEIGHT: CUdevice_attribute_enum = 28;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_LAYERED_LAYERS: CUdevice_attribute_enum = 29;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_SURFACE_ALIGNMENT: CUdevice_attribute_enum = 30;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CONCURRENT_KERNELS: CUdevice_attribute_enum = 31;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_ECC_ENABLED: CUdevice_attribute_enum = 32;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_PCI_BUS_ID: CUdevice_attribute_enum = 33;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_PCI_DEVICE_ID: CUdevice_attribute_enum = 34;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_TCC_DRIVER: CUdevice_attribute_enum = 35;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MEMORY_CLOCK_RATE: CUdevice_attribute_enum = 36;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GLOBAL_MEMORY_BUS_WIDTH: CUdevice_attribute_enum = 37;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_L2_CACHE_SIZE: CUdevice_attribute_enum = 38;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_MULTIPROCESSOR: CUdevice_attribute_enum = 39;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_ASYNC_ENGINE_COUNT: CUdevice_attribute_enum = 40;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_UNIFIED_ADDRESSING: CUdevice_attribute_enum = 41;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE1D_LAYERED_WIDTH: CUdevice_attribute_enum = 42;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE1D_LAYERED_LAYERS: CUdevice_attribute_enum = 43;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_TEX2D_GATHER: CUdevice_attribute_enum = 44;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_GATHER_WIDTH: CUdevice_attribute_enum = 45;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_GATHER_HEIGHT: CUdevice_attribute_enum = 46;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE3D_WIDTH_ALTERNATE: CUdevice_attribute_enum = 47;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE3D_HEIGHT_ALTERNATE: CUdevice_attribute_enum = 48;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE3D_DEPTH_ALTERNATE: CUdevice_attribute_enum = 49;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_PCI_DOMAIN_ID: CUdevice_attribute_enum = 50;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_TEXTURE_PITCH_ALIGNMENT: CUdevice_attribute_enum = 51;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURECUBEMAP_WIDTH: CUdevice_attribute_enum = 52;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURECUBEMAP_LAYERED_WIDTH: CUdevice_attribute_enum = 53;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURECUBEMAP_LAYERED_LAYERS: CUdevice_attribute_enum = 54;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE1D_WIDTH: CUdevice_attribute_enum = 55;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE2D_WIDTH: CUdevice_attribute_enum = 56;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE2D_HEIGHT: CUdevice_attribute_enum = 57;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE3D_WIDTH: CUdevice_attribute_enum = 58;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE3D_HEIGHT: CUdevice_attribute_enum = 59;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE3D_DEPTH: CUdevice_attribute_enum = 60;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE1D_LAYERED_WIDTH: CUdevice_attribute_enum = 61;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE1D_LAYERED_LAYERS: CUdevice_attribute_enum = 62;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE2D_LAYERED_WIDTH: CUdevice_attribute_enum = 63;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE2D_LAYERED_HEIGHT: CUdevice_attribute_enum = 64;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACE2D_LAYERED_LAYERS: CUdevice_attribute_enum = 65;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACECUBEMAP_WIDTH: CUdevice_attribute_enum = 66;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACECUBEMAP_LAYERED_WIDTH: CUdevice_attribute_enum = 67;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_SURFACECUBEMAP_LAYERED_LAYERS: CUdevice_attribute_enum = 68;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE1D_LINEAR_WIDTH: CUdevice_attribute_enum = 69;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_LINEAR_WIDTH: CUdevice_attribute_enum = 70;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_LINEAR_HEIGHT: CUdevice_attribute_enum = 71;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_LINEAR_PITCH: CUdevice_attribute_enum = 72;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_MIPMAPPED_WIDTH: CUdevice_attribute_enum = 73;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE2D_MIPMAPPED_HEIGHT: CUdevice_attribute_enum = 74;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MAJOR: CUdevice_attribute_enum = 75;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MINOR: CUdevice_attribute_enum = 76;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAXIMUM_TEXTURE1D_MIPMAPPED_WIDTH: CUdevice_attribute_enum = 77;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_STREAM_PRIORITIES_SUPPORTED: CUdevice_attribute_enum = 78;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GLOBAL_L1_CACHE_SUPPORTED: CUdevice_attribute_enum = 79;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_LOCAL_L1_CACHE_SUPPORTED: CUdevice_attribute_enum = 80;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_MULTIPROCESSOR: CUdevice_attribute_enum = 81;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_REGISTERS_PER_MULTIPROCESSOR: CUdevice_attribute_enum = 82;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MANAGED_MEMORY: CUdevice_attribute_enum = 83;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MULTI_GPU_BOARD: CUdevice_attribute_enum = 84;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MULTI_GPU_BOARD_GROUP_ID: CUdevice_attribute_enum = 85;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_HOST_NATIVE_ATOMIC_SUPPORTED: CUdevice_attribute_enum = 86;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_SINGLE_TO_DOUBLE_PRECISION_PERF_RATIO: CUdevice_attribute_enum = 87;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_PAGEABLE_MEMORY_ACCESS: CUdevice_attribute_enum = 88;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CONCURRENT_MANAGED_ACCESS: CUdevice_attribute_enum = 89;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COMPUTE_PREEMPTION_SUPPORTED: CUdevice_attribute_enum = 90;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_USE_HOST_POINTER_FOR_REGISTERED_MEM: CUdevice_attribute_enum = 91;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_USE_STREAM_MEM_OPS_V1: CUdevice_attribute_enum = 92;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_USE_64_BIT_STREAM_MEM_OPS_V1: CUdevice_attribute_enum = 93;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_USE_STREAM_WAIT_VALUE_NOR_V1: CUdevice_attribute_enum = 94;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COOPERATIVE_LAUNCH: CUdevice_attribute_enum = 95;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COOPERATIVE_MULTI_DEVICE_LAUNCH: CUdevice_attribute_enum = 96;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK_OPTIN: CUdevice_attribute_enum = 97;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_FLUSH_REMOTE_WRITES: CUdevice_attribute_enum = 98;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_HOST_REGISTER_SUPPORTED: CUdevice_attribute_enum = 99;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_PAGEABLE_MEMORY_ACCESS_USES_HOST_PAGE_TABLES: CUdevice_attribute_enum = 100;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_DIRECT_MANAGED_MEM_ACCESS_FROM_HOST: CUdevice_attribute_enum = 101;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_VIRTUAL_ADDRESS_MANAGEMENT_SUPPORTED: CUdevice_attribute_enum = 102;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_HANDLE_TYPE_POSIX_FILE_DESCRIPTOR_SUPPORTED: CUdevice_attribute_enum = 103;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_HANDLE_TYPE_WIN32_HANDLE_SUPPORTED: CUdevice_attribute_enum = 104;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_HANDLE_TYPE_WIN32_KMT_HANDLE_SUPPORTED: CUdevice_attribute_enum = 105;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCKS_PER_MULTIPROCESSOR: CUdevice_attribute_enum = 106;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GENERIC_COMPRESSION_SUPPORTED: CUdevice_attribute_enum = 107;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_PERSISTING_L2_CACHE_SIZE: CUdevice_attribute_enum = 108;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_ACCESS_POLICY_WINDOW_SIZE: CUdevice_attribute_enum = 109;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GPU_DIRECT_RDMA_WITH_CUDA_VMM_SUPPORTED: CUdevice_attribute_enum = 110;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_RESERVED_SHARED_MEMORY_PER_BLOCK: CUdevice_attribute_enum = 111;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_SPARSE_CUDA_ARRAY_SUPPORTED: CUdevice_attribute_enum = 112;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_READ_ONLY_HOST_REGISTER_SUPPORTED: CUdevice_attribute_enum = 113;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_TIMELINE_SEMAPHORE_INTEROP_SUPPORTED: CUdevice_attribute_enum = 114;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MEMORY_POOLS_SUPPORTED: CUdevice_attribute_enum = 115;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GPU_DIRECT_RDMA_SUPPORTED: CUdevice_attribute_enum = 116;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GPU_DIRECT_RDMA_FLUSH_WRITES_OPTIONS: CUdevice_attribute_enum = 117;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GPU_DIRECT_RDMA_WRITES_ORDERING: CUdevice_attribute_enum = 118;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MEMPOOL_SUPPORTED_HANDLE_TYPES: CUdevice_attribute_enum = 119;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CLUSTER_LAUNCH: CUdevice_attribute_enum = 120;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_DEFERRED_MAPPING_CUDA_ARRAY_SUPPORTED: CUdevice_attribute_enum = 121;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_USE_64_BIT_STREAM_MEM_OPS: CUdevice_attribute_enum = 122;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_USE_STREAM_WAIT_VALUE_NOR: CUdevice_attribute_enum = 123;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_DMA_BUF_SUPPORTED: CUdevice_attribute_enum = 124;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_IPC_EVENT_SUPPORTED: CUdevice_attribute_enum = 125;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MEM_SYNC_DOMAIN_COUNT: CUdevice_attribute_enum = 126;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_TENSOR_MAP_ACCESS_SUPPORTED: CUdevice_attribute_enum = 127;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_HANDLE_TYPE_FABRIC_SUPPORTED: CUdevice_attribute_enum = 128;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_UNIFIED_FUNCTION_POINTERS: CUdevice_attribute_enum = 129;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_NUMA_CONFIG: CUdevice_attribute_enum = 130;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_NUMA_ID: CUdevice_attribute_enum = 131;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MULTICAST_SUPPORTED: CUdevice_attribute_enum = 132;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MPS_ENABLED: CUdevice_attribute_enum = 133;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_HOST_NUMA_ID: CUdevice_attribute_enum = 134;
+pub const CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX: CUdevice_attribute_enum = 135;
+pub type CUdevice_attribute_enum = ::core::ffi::c_uint;
+pub use self::CUdevice_attribute_enum as CUdevice_attribute;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUdevprop_st {
+    pub maxThreadsPerBlock: ::core::ffi::c_int,
+    pub maxThreadsDim: [::core::ffi::c_int; 3usize],
+    pub maxGridSize: [::core::ffi::c_int; 3usize],
+    pub sharedMemPerBlock: ::core::ffi::c_int,
+    pub totalConstantMemory: ::core::ffi::c_int,
+    pub SIMDWidth: ::core::ffi::c_int,
+    pub memPitch: ::core::ffi::c_int,
+    pub regsPerBlock: ::core::ffi::c_int,
+    pub clockRate: ::core::ffi::c_int,
+    pub textureAlign: ::core::ffi::c_int,
+}
+#[test]
+fn bindgen_test_layout_CUdevprop_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUdevprop_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUdevprop_st > (), 56usize, concat!("Size of: ",
+        stringify!(CUdevprop_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUdevprop_st > (), 4usize, concat!("Alignment of ",
+        stringify!(CUdevprop_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).maxThreadsPerBlock) as usize - ptr as
+        usize }, 0usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(maxThreadsPerBlock))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).maxThreadsDim) as usize - ptr as usize },
+        4usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(maxThreadsDim))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).maxGridSize) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(maxGridSize))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).sharedMemPerBlock) as usize - ptr as usize
+        }, 28usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(sharedMemPerBlock))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).totalConstantMemory) as usize - ptr as
+        usize }, 32usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(totalConstantMemory))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).SIMDWidth) as usize - ptr as usize },
+        36usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(SIMDWidth))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memPitch) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(memPitch))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).regsPerBlock) as usize - ptr as usize },
+        44usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(regsPerBlock))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).clockRate) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(clockRate))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).textureAlign) as usize - ptr as usize },
+        52usize, concat!("Offset of field: ", stringify!(CUdevprop_st), "::",
+        stringify!(textureAlign))
+    );
+}
+pub type CUdevprop_v1 = CUdevprop_st;
+pub type CUdevprop = CUdevprop_v1;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_CONTEXT: CUpointer_attribute_enum = 1;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_MEMORY_TYPE: CUpointer_attribute_enum = 2;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_DEVICE_POINTER: CUpointer_attribute_enum = 3;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_HOST_POINTER: CUpointer_attribute_enum = 4;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_P2P_TOKENS: CUpointer_attribute_enum = 5;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_SYNC_MEMOPS: CUpointer_attribute_enum = 6;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_BUFFER_ID: CUpointer_attribute_enum = 7;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_IS_MANAGED: CUpointer_attribute_enum = 8;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_DEVICE_ORDINAL: CUpointer_attribute_enum = 9;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_IS_LEGACY_CUDA_IPC_CAPABLE: CUpointer_attribute_enum = 10;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_RANGE_START_ADDR: CUpointer_attribute_enum = 11;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_RANGE_SIZE: CUpointer_attribute_enum = 12;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_MAPPED: CUpointer_attribute_enum = 13;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_ALLOWED_HANDLE_TYPES: CUpointer_attribute_enum = 14;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_IS_GPU_DIRECT_RDMA_CAPABLE: CUpointer_attribute_enum = 15;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_ACCESS_FLAGS: CUpointer_attribute_enum = 16;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_MEMPOOL_HANDLE: CUpointer_attribute_enum = 17;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_MAPPING_SIZE: CUpointer_attribute_enum = 18;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_MAPPING_BASE_ADDR: CUpointer_attribute_enum = 19;
+pub const CUpointer_attribute_enum_CU_POINTER_ATTRIBUTE_MEMORY_BLOCK_ID: CUpointer_attribute_enum = 20;
+pub type CUpointer_attribute_enum = ::core::ffi::c_uint;
+pub use self::CUpointer_attribute_enum as CUpointer_attribute;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_MAX_THREADS_PER_BLOCK: CUfunction_attribute_enum = 0;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_SHARED_SIZE_BYTES: CUfunction_attribute_enum = 1;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_CONST_SIZE_BYTES: CUfunction_attribute_enum = 2;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_LOCAL_SIZE_BYTES: CUfunction_attribute_enum = 3;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_NUM_REGS: CUfunction_attribute_enum = 4;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_PTX_VERSION: CUfunction_attribute_enum = 5;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_BINARY_VERSION: CUfunction_attribute_enum = 6;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_CACHE_MODE_CA: CUfunction_attribute_enum = 7;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_MAX_DYNAMIC_SHARED_SIZE_BYTES: CUfunction_attribute_enum = 8;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_PREFERRED_SHARED_MEMORY_CARVEOUT: CUfunction_attribute_enum = 9;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_CLUSTER_SIZE_MUST_BE_SET: CUfunction_attribute_enum = 10;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_REQUIRED_CLUSTER_WIDTH: CUfunction_attribute_enum = 11;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_REQUIRED_CLUSTER_HEIGHT: CUfunction_attribute_enum = 12;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_REQUIRED_CLUSTER_DEPTH: CUfunction_attribute_enum = 13;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_NON_PORTABLE_CLUSTER_SIZE_ALLOWED: CUfunction_attribute_enum = 14;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_CLUSTER_SCHEDULING_POLICY_PREFERENCE: CUfunction_attribute_enum = 15;
+pub const CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_MAX: CUfunction_attribute_enum = 16;
+pub type CUfunction_attribute_enum = ::core::ffi::c_uint;
+pub use self::CUfunction_attribute_enum as CUfunction_attribute;
+pub const CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_NONE: CUfunc_cache_enum = 0;
+pub const CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_SHARED: CUfunc_cache_enum = 1;
+pub const CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_L1: CUfunc_cache_enum = 2;
+pub const CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_EQUAL: CUfunc_cache_enum = 3;
+pub type CUfunc_cache_enum = ::core::ffi::c_uint;
+pub use self::CUfunc_cache_enum as CUfunc_cache;
+pub const CUsharedconfig_enum_CU_SHARED_MEM_CONFIG_DEFAULT_BANK_SIZE: CUsharedconfig_enum = 0;
+pub const CUsharedconfig_enum_CU_SHARED_MEM_CONFIG_FOUR_BYTE_BANK_SIZE: CUsharedconfig_enum = 1;
+pub const CUsharedconfig_enum_CU_SHARED_MEM_CONFIG_EIGHT_BYTE_BANK_SIZE: CUsharedconfig_enum = 2;
+pub type CUsharedconfig_enum = ::core::ffi::c_uint;
+pub use self::CUsharedconfig_enum as CUsharedconfig;
+pub const CUshared_carveout_enum_CU_SHAREDMEM_CARVEOUT_DEFAULT: CUshared_carveout_enum = -1;
+pub const CUshared_carveout_enum_CU_SHAREDMEM_CARVEOUT_MAX_SHARED: CUshared_carveout_enum = 100;
+pub const CUshared_carveout_enum_CU_SHAREDMEM_CARVEOUT_MAX_L1: CUshared_carveout_enum = 0;
+pub type CUshared_carveout_enum = ::core::ffi::c_int;
+pub use self::CUshared_carveout_enum as CUshared_carveout;
+pub const CUmemorytype_enum_CU_MEMORYTYPE_HOST: CUmemorytype_enum = 1;
+pub const CUmemorytype_enum_CU_MEMORYTYPE_DEVICE: CUmemorytype_enum = 2;
+pub const CUmemorytype_enum_CU_MEMORYTYPE_ARRAY: CUmemorytype_enum = 3;
+pub const CUmemorytype_enum_CU_MEMORYTYPE_UNIFIED: CUmemorytype_enum = 4;
+pub type CUmemorytype_enum = ::core::ffi::c_uint;
+pub use self::CUmemorytype_enum as CUmemorytype;
+pub const CUcomputemode_enum_CU_COMPUTEMODE_DEFAULT: CUcomputemode_enum = 0;
+pub const CUcomputemode_enum_CU_COMPUTEMODE_PROHIBITED: CUcomputemode_enum = 2;
+pub const CUcomputemode_enum_CU_COMPUTEMODE_EXCLUSIVE_PROCESS: CUcomputemode_enum = 3;
+pub type CUcomputemode_enum = ::core::ffi::c_uint;
+pub use self::CUcomputemode_enum as CUcomputemode;
+pub const CUmem_advise_enum_CU_MEM_ADVISE_SET_READ_MOSTLY: CUmem_advise_enum = 1;
+pub const CUmem_advise_enum_CU_MEM_ADVISE_UNSET_READ_MOSTLY: CUmem_advise_enum = 2;
+pub const CUmem_advise_enum_CU_MEM_ADVISE_SET_PREFERRED_LOCATION: CUmem_advise_enum = 3;
+pub const CUmem_advise_enum_CU_MEM_ADVISE_UNSET_PREFERRED_LOCATION: CUmem_advise_enum = 4;
+pub const CUmem_advise_enum_CU_MEM_ADVISE_SET_ACCESSED_BY: CUmem_advise_enum = 5;
+pub const CUmem_advise_enum_CU_MEM_ADVISE_UNSET_ACCESSED_BY: CUmem_advise_enum = 6;
+pub type CUmem_advise_enum = ::core::ffi::c_uint;
+pub use self::CUmem_advise_enum as CUmem_advise;
+pub const CUmem_range_attribute_enum_CU_MEM_RANGE_ATTRIBUTE_READ_MOSTLY: CUmem_range_attribute_enum = 1;
+pub const CUmem_range_attribute_enum_CU_MEM_RANGE_ATTRIBUTE_PREFERRED_LOCATION: CUmem_range_attribute_enum = 2;
+pub const CUmem_range_attribute_enum_CU_MEM_RANGE_ATTRIBUTE_ACCESSED_BY: CUmem_range_attribute_enum = 3;
+pub const CUmem_range_attribute_enum_CU_MEM_RANGE_ATTRIBUTE_LAST_PREFETCH_LOCATION: CUmem_range_attribute_enum = 4;
+pub const CUmem_range_attribute_enum_CU_MEM_RANGE_ATTRIBUTE_PREFERRED_LOCATION_TYPE: CUmem_range_attribute_enum = 5;
+pub const CUmem_range_attribute_enum_CU_MEM_RANGE_ATTRIBUTE_PREFERRED_LOCATION_ID: CUmem_range_attribute_enum = 6;
+pub const CUmem_range_attribute_enum_CU_MEM_RANGE_ATTRIBUTE_LAST_PREFETCH_LOCATION_TYPE: CUmem_range_attribute_enum = 7;
+pub const CUmem_range_attribute_enum_CU_MEM_RANGE_ATTRIBUTE_LAST_PREFETCH_LOCATION_ID: CUmem_range_attribute_enum = 8;
+pub type CUmem_range_attribute_enum = ::core::ffi::c_uint;
+pub use self::CUmem_range_attribute_enum as CUmem_range_attribute;
+pub const CUjit_option_enum_CU_JIT_MAX_REGISTERS: CUjit_option_enum = 0;
+pub const CUjit_option_enum_CU_JIT_THREADS_PER_BLOCK: CUjit_option_enum = 1;
+pub const CUjit_option_enum_CU_JIT_WALL_TIME: CUjit_option_enum = 2;
+pub const CUjit_option_enum_CU_JIT_INFO_LOG_BUFFER: CUjit_option_enum = 3;
+pub const CUjit_option_enum_CU_JIT_INFO_LOG_BUFFER_SIZE_BYTES: CUjit_option_enum = 4;
+pub const CUjit_option_enum_CU_JIT_ERROR_LOG_BUFFER: CUjit_option_enum = 5;
+pub const CUjit_option_enum_CU_JIT_ERROR_LOG_BUFFER_SIZE_BYTES: CUjit_option_enum = 6;
+pub const CUjit_option_enum_CU_JIT_OPTIMIZATION_LEVEL: CUjit_option_enum = 7;
+pub const CUjit_option_enum_CU_JIT_TARGET_FROM_CUCONTEXT: CUjit_option_enum = 8;
+pub const CUjit_option_enum_CU_JIT_TARGET: CUjit_option_enum = 9;
+pub const CUjit_option_enum_CU_JIT_FALLBACK_STRATEGY: CUjit_option_enum = 10;
+pub const CUjit_option_enum_CU_JIT_GENERATE_DEBUG_INFO: CUjit_option_enum = 11;
+pub const CUjit_option_enum_CU_JIT_LOG_VERBOSE: CUjit_option_enum = 12;
+pub const CUjit_option_enum_CU_JIT_GENERATE_LINE_INFO: CUjit_option_enum = 13;
+pub const CUjit_option_enum_CU_JIT_CACHE_MODE: CUjit_option_enum = 14;
+pub const CUjit_option_enum_CU_JIT_NEW_SM3X_OPT: CUjit_option_enum = 15;
+pub const CUjit_option_enum_CU_JIT_FAST_COMPILE: CUjit_option_enum = 16;
+pub const CUjit_option_enum_CU_JIT_GLOBAL_SYMBOL_NAMES: CUjit_option_enum = 17;
+pub const CUjit_option_enum_CU_JIT_GLOBAL_SYMBOL_ADDRESSES: CUjit_option_enum = 18;
+pub const CUjit_option_enum_CU_JIT_GLOBAL_SYMBOL_COUNT: CUjit_option_enum = 19;
+pub const CUjit_option_enum_CU_JIT_LTO: CUjit_option_enum = 20;
+pub const CUjit_option_enum_CU_JIT_FTZ: CUjit_option_enum = 21;
+pub const CUjit_option_enum_CU_JIT_PREC_DIV: CUjit_option_enum = 22;
+pub const CUjit_option_enum_CU_JIT_PREC_SQRT: CUjit_option_enum = 23;
+pub const CUjit_option_enum_CU_JIT_FMA: CUjit_option_enum = 24;
+pub const CUjit_option_enum_CU_JIT_REFERENCED_KERNEL_NAMES: CUjit_option_enum = 25;
+pub const CUjit_option_enum_CU_JIT_REFERENCED_KERNEL_COUNT: CUjit_option_enum = 26;
+pub const CUjit_option_enum_CU_JIT_REFERENCED_VARIABLE_NAMES: CUjit_option_enum = 27;
+pub const CUjit_option_enum_CU_JIT_REFERENCED_VARIABLE_COUNT: CUjit_option_enum = 28;
+pub const CUjit_option_enum_CU_JIT_OPTIMIZE_UNUSED_DEVICE_VARIABLES: CUjit_option_enum = 29;
+pub const CUjit_option_enum_CU_JIT_POSITION_INDEPENDENT_CODE: CUjit_option_enum = 30;
+pub const CUjit_option_enum_CU_JIT_MIN_CTA_PER_SM: CUjit_option_enum = 31;
+pub const CUjit_option_enum_CU_JIT_MAX_THREADS_PER_BLOCK: CUjit_option_enum = 32;
+pub const CUjit_option_enum_CU_JIT_OVERRIDE_DIRECTIVE_VALUES: CUjit_option_enum = 33;
+pub const CUjit_option_enum_CU_JIT_NUM_OPTIONS: CUjit_option_enum = 34;
+pub type CUjit_option_enum = ::core::ffi::c_uint;
+pub use self::CUjit_option_enum as CUjit_option;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_30: CUjit_target_enum = 30;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_32: CUjit_target_enum = 32;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_35: CUjit_target_enum = 35;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_37: CUjit_target_enum = 37;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_50: CUjit_target_enum = 50;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_52: CUjit_target_enum = 52;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_53: CUjit_target_enum = 53;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_60: CUjit_target_enum = 60;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_61: CUjit_target_enum = 61;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_62: CUjit_target_enum = 62;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_70: CUjit_target_enum = 70;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_72: CUjit_target_enum = 72;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_75: CUjit_target_enum = 75;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_80: CUjit_target_enum = 80;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_86: CUjit_target_enum = 86;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_87: CUjit_target_enum = 87;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_89: CUjit_target_enum = 89;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_90: CUjit_target_enum = 90;
+pub const CUjit_target_enum_CU_TARGET_COMPUTE_90A: CUjit_target_enum = 65626;
+pub type CUjit_target_enum = ::core::ffi::c_uint;
+pub use self::CUjit_target_enum as CUjit_target;
+pub const CUjit_fallback_enum_CU_PREFER_PTX: CUjit_fallback_enum = 0;
+pub const CUjit_fallback_enum_CU_PREFER_BINARY: CUjit_fallback_enum = 1;
+pub type CUjit_fallback_enum = ::core::ffi::c_uint;
+pub use self::CUjit_fallback_enum as CUjit_fallback;
+pub const CUjit_cacheMode_enum_CU_JIT_CACHE_OPTION_NONE: CUjit_cacheMode_enum = 0;
+pub const CUjit_cacheMode_enum_CU_JIT_CACHE_OPTION_CG: CUjit_cacheMode_enum = 1;
+pub const CUjit_cacheMode_enum_CU_JIT_CACHE_OPTION_CA: CUjit_cacheMode_enum = 2;
+pub type CUjit_cacheMode_enum = ::core::ffi::c_uint;
+pub use self::CUjit_cacheMode_enum as CUjit_cacheMode;
+pub const CUjitInputType_enum_CU_JIT_INPUT_CUBIN: CUjitInputType_enum = 0;
+pub const CUjitInputType_enum_CU_JIT_INPUT_PTX: CUjitInputType_enum = 1;
+pub const CUjitInputType_enum_CU_JIT_INPUT_FATBINARY: CUjitInputType_enum = 2;
+pub const CUjitInputType_enum_CU_JIT_INPUT_OBJECT: CUjitInputType_enum = 3;
+pub const CUjitInputType_enum_CU_JIT_INPUT_LIBRARY: CUjitInputType_enum = 4;
+pub const CUjitInputType_enum_CU_JIT_INPUT_NVVM: CUjitInputType_enum = 5;
+pub const CUjitInputType_enum_CU_JIT_NUM_INPUT_TYPES: CUjitInputType_enum = 6;
+pub type CUjitInputType_enum = ::core::ffi::c_uint;
+pub use self::CUjitInputType_enum as CUjitInputType;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUlinkState_st {
+    _unused: [u8; 0],
+}
+pub type CUlinkState = *mut CUlinkState_st;
+pub const CUgraphicsRegisterFlags_enum_CU_GRAPHICS_REGISTER_FLAGS_NONE: CUgraphicsRegisterFlags_enum = 0;
+pub const CUgraphicsRegisterFlags_enum_CU_GRAPHICS_REGISTER_FLAGS_READ_ONLY: CUgraphicsRegisterFlags_enum = 1;
+pub const CUgraphicsRegisterFlags_enum_CU_GRAPHICS_REGISTER_FLAGS_WRITE_DISCARD: CUgraphicsRegisterFlags_enum = 2;
+pub const CUgraphicsRegisterFlags_enum_CU_GRAPHICS_REGISTER_FLAGS_SURFACE_LDST: CUgraphicsRegisterFlags_enum = 4;
+pub const CUgraphicsRegisterFlags_enum_CU_GRAPHICS_REGISTER_FLAGS_TEXTURE_GATHER: CUgraphicsRegisterFlags_enum = 8;
+pub type CUgraphicsRegisterFlags_enum = ::core::ffi::c_uint;
+pub use self::CUgraphicsRegisterFlags_enum as CUgraphicsRegisterFlags;
+pub const CUgraphicsMapResourceFlags_enum_CU_GRAPHICS_MAP_RESOURCE_FLAGS_NONE: CUgraphicsMapResourceFlags_enum = 0;
+pub const CUgraphicsMapResourceFlags_enum_CU_GRAPHICS_MAP_RESOURCE_FLAGS_READ_ONLY: CUgraphicsMapResourceFlags_enum = 1;
+pub const CUgraphicsMapResourceFlags_enum_CU_GRAPHICS_MAP_RESOURCE_FLAGS_WRITE_DISCARD: CUgraphicsMapResourceFlags_enum = 2;
+pub type CUgraphicsMapResourceFlags_enum = ::core::ffi::c_uint;
+pub use self::CUgraphicsMapResourceFlags_enum as CUgraphicsMapResourceFlags;
+pub const CUarray_cubemap_face_enum_CU_CUBEMAP_FACE_POSITIVE_X: CUarray_cubemap_face_enum = 0;
+pub const CUarray_cubemap_face_enum_CU_CUBEMAP_FACE_NEGATIVE_X: CUarray_cubemap_face_enum = 1;
+pub const CUarray_cubemap_face_enum_CU_CUBEMAP_FACE_POSITIVE_Y: CUarray_cubemap_face_enum = 2;
+pub const CUarray_cubemap_face_enum_CU_CUBEMAP_FACE_NEGATIVE_Y: CUarray_cubemap_face_enum = 3;
+pub const CUarray_cubemap_face_enum_CU_CUBEMAP_FACE_POSITIVE_Z: CUarray_cubemap_face_enum = 4;
+pub const CUarray_cubemap_face_enum_CU_CUBEMAP_FACE_NEGATIVE_Z: CUarray_cubemap_face_enum = 5;
+pub type CUarray_cubemap_face_enum = ::core::ffi::c_uint;
+pub use self::CUarray_cubemap_face_enum as CUarray_cubemap_face;
+pub const CUlimit_enum_CU_LIMIT_STACK_SIZE: CUlimit_enum = 0;
+pub const CUlimit_enum_CU_LIMIT_PRINTF_FIFO_SIZE: CUlimit_enum = 1;
+pub const CUlimit_enum_CU_LIMIT_MALLOC_HEAP_SIZE: CUlimit_enum = 2;
+pub const CUlimit_enum_CU_LIMIT_DEV_RUNTIME_SYNC_DEPTH: CUlimit_enum = 3;
+pub const CUlimit_enum_CU_LIMIT_DEV_RUNTIME_PENDING_LAUNCH_COUNT: CUlimit_enum = 4;
+pub const CUlimit_enum_CU_LIMIT_MAX_L2_FETCH_GRANULARITY: CUlimit_enum = 5;
+pub const CUlimit_enum_CU_LIMIT_PERSISTING_L2_CACHE_SIZE: CUlimit_enum = 6;
+pub const CUlimit_enum_CU_LIMIT_MAX: CUlimit_enum = 7;
+pub type CUlimit_enum = ::core::ffi::c_uint;
+pub use self::CUlimit_enum as CUlimit;
+pub const CUresourcetype_enum_CU_RESOURCE_TYPE_ARRAY: CUresourcetype_enum = 0;
+pub const CUresourcetype_enum_CU_RESOURCE_TYPE_MIPMAPPED_ARRAY: CUresourcetype_enum = 1;
+pub const CUresourcetype_enum_CU_RESOURCE_TYPE_LINEAR: CUresourcetype_enum = 2;
+pub const CUresourcetype_enum_CU_RESOURCE_TYPE_PITCH2D: CUresourcetype_enum = 3;
+pub type CUresourcetype_enum = ::core::ffi::c_uint;
+pub use self::CUresourcetype_enum as CUresourcetype;
+pub type CUhostFn = ::core::option::Option<
+    unsafe extern "C" fn(userData: *mut ::core::ffi::c_void),
+>;
+pub const CUaccessProperty_enum_CU_ACCESS_PROPERTY_NORMAL: CUaccessProperty_enum = 0;
+pub const CUaccessProperty_enum_CU_ACCESS_PROPERTY_STREAMING: CUaccessProperty_enum = 1;
+pub const CUaccessProperty_enum_CU_ACCESS_PROPERTY_PERSISTING: CUaccessProperty_enum = 2;
+pub type CUaccessProperty_enum = ::core::ffi::c_uint;
+pub use self::CUaccessProperty_enum as CUaccessProperty;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct CUaccessPolicyWindow_st {
+    pub base_ptr: *mut ::core::ffi::c_void,
+    pub num_bytes: usize,
+    pub hitRatio: f32,
+    pub hitProp: CUaccessProperty,
+    pub missProp: CUaccessProperty,
+}
+#[test]
+fn bindgen_test_layout_CUaccessPolicyWindow_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUaccessPolicyWindow_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUaccessPolicyWindow_st > (), 32usize,
+        concat!("Size of: ", stringify!(CUaccessPolicyWindow_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUaccessPolicyWindow_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUaccessPolicyWindow_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).base_ptr) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUaccessPolicyWindow_st), "::",
+        stringify!(base_ptr))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).num_bytes) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUaccessPolicyWindow_st), "::",
+        stringify!(num_bytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).hitRatio) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUaccessPolicyWindow_st), "::",
+        stringify!(hitRatio))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).hitProp) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUaccessPolicyWindow_st), "::",
+        stringify!(hitProp))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).missProp) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUaccessPolicyWindow_st), "::",
+        stringify!(missProp))
+    );
+}
+impl Default for CUaccessPolicyWindow_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUaccessPolicyWindow_v1 = CUaccessPolicyWindow_st;
+pub type CUaccessPolicyWindow = CUaccessPolicyWindow_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_KERNEL_NODE_PARAMS_st {
+    pub func: CUfunction,
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
+    pub kernelParams: *mut *mut ::core::ffi::c_void,
+    pub extra: *mut *mut ::core::ffi::c_void,
+}
+#[test]
+fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_KERNEL_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_KERNEL_NODE_PARAMS_st > (), 56usize,
+        concat!("Size of: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_KERNEL_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_KERNEL_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).func) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st), "::",
+        stringify!(func))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimX) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st),
+        "::", stringify!(gridDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimY) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st),
+        "::", stringify!(gridDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimZ) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st),
+        "::", stringify!(gridDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimX) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st),
+        "::", stringify!(blockDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimY) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st),
+        "::", stringify!(blockDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimZ) as usize - ptr as usize },
+        28usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st),
+        "::", stringify!(blockDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).sharedMemBytes) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st),
+        "::", stringify!(sharedMemBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).kernelParams) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st),
+        "::", stringify!(kernelParams))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extra) as usize - ptr as usize }, 48usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_st), "::",
+        stringify!(extra))
+    );
+}
+impl Default for CUDA_KERNEL_NODE_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_KERNEL_NODE_PARAMS_v1 = CUDA_KERNEL_NODE_PARAMS_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_KERNEL_NODE_PARAMS_v2_st {
+    pub func: CUfunction,
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
+    pub kernelParams: *mut *mut ::core::ffi::c_void,
+    pub extra: *mut *mut ::core::ffi::c_void,
+    pub kern: CUkernel,
+    pub ctx: CUcontext,
+}
+#[test]
+fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_v2_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_KERNEL_NODE_PARAMS_v2_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_KERNEL_NODE_PARAMS_v2_st > (), 72usize,
+        concat!("Size of: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_KERNEL_NODE_PARAMS_v2_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).func) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st), "::",
+        stringify!(func))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimX) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st),
+        "::", stringify!(gridDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimY) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st),
+        "::", stringify!(gridDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimZ) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st),
+        "::", stringify!(gridDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimX) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st),
+        "::", stringify!(blockDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimY) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st),
+        "::", stringify!(blockDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimZ) as usize - ptr as usize },
+        28usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st),
+        "::", stringify!(blockDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).sharedMemBytes) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st),
+        "::", stringify!(sharedMemBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).kernelParams) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st),
+        "::", stringify!(kernelParams))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extra) as usize - ptr as usize }, 48usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st), "::",
+        stringify!(extra))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).kern) as usize - ptr as usize }, 56usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st), "::",
+        stringify!(kern))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).ctx) as usize - ptr as usize }, 64usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v2_st), "::",
+        stringify!(ctx))
+    );
+}
+impl Default for CUDA_KERNEL_NODE_PARAMS_v2_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_KERNEL_NODE_PARAMS_v2 = CUDA_KERNEL_NODE_PARAMS_v2_st;
+pub type CUDA_KERNEL_NODE_PARAMS = CUDA_KERNEL_NODE_PARAMS_v2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_KERNEL_NODE_PARAMS_v3_st {
+    pub func: CUfunction,
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
+    pub kernelParams: *mut *mut ::core::ffi::c_void,
+    pub extra: *mut *mut ::core::ffi::c_void,
+    pub kern: CUkernel,
+    pub ctx: CUcontext,
+}
+#[test]
+fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_v3_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_KERNEL_NODE_PARAMS_v3_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_KERNEL_NODE_PARAMS_v3_st > (), 72usize,
+        concat!("Size of: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_KERNEL_NODE_PARAMS_v3_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).func) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st), "::",
+        stringify!(func))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimX) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st),
+        "::", stringify!(gridDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimY) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st),
+        "::", stringify!(gridDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimZ) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st),
+        "::", stringify!(gridDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimX) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st),
+        "::", stringify!(blockDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimY) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st),
+        "::", stringify!(blockDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimZ) as usize - ptr as usize },
+        28usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st),
+        "::", stringify!(blockDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).sharedMemBytes) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st),
+        "::", stringify!(sharedMemBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).kernelParams) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st),
+        "::", stringify!(kernelParams))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extra) as usize - ptr as usize }, 48usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st), "::",
+        stringify!(extra))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).kern) as usize - ptr as usize }, 56usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st), "::",
+        stringify!(kern))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).ctx) as usize - ptr as usize }, 64usize,
+        concat!("Offset of field: ", stringify!(CUDA_KERNEL_NODE_PARAMS_v3_st), "::",
+        stringify!(ctx))
+    );
+}
+impl Default for CUDA_KERNEL_NODE_PARAMS_v3_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_KERNEL_NODE_PARAMS_v3 = CUDA_KERNEL_NODE_PARAMS_v3_st;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_MEMSET_NODE_PARAMS_st {
+    pub dst: CUdeviceptr,
+    pub pitch: usize,
+    pub value: ::core::ffi::c_uint,
+    pub elementSize: ::core::ffi::c_uint,
+    pub width: usize,
+    pub height: usize,
+}
+#[test]
+fn bindgen_test_layout_CUDA_MEMSET_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_MEMSET_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_MEMSET_NODE_PARAMS_st > (), 40usize,
+        concat!("Size of: ", stringify!(CUDA_MEMSET_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_MEMSET_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_MEMSET_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dst) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_st), "::",
+        stringify!(dst))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).pitch) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_st), "::",
+        stringify!(pitch))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).value) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_st), "::",
+        stringify!(value))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).elementSize) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_st),
+        "::", stringify!(elementSize))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).width) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_st), "::",
+        stringify!(width))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).height) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_st),
+        "::", stringify!(height))
+    );
+}
+pub type CUDA_MEMSET_NODE_PARAMS_v1 = CUDA_MEMSET_NODE_PARAMS_st;
+pub type CUDA_MEMSET_NODE_PARAMS = CUDA_MEMSET_NODE_PARAMS_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_MEMSET_NODE_PARAMS_v2_st {
+    pub dst: CUdeviceptr,
+    pub pitch: usize,
+    pub value: ::core::ffi::c_uint,
+    pub elementSize: ::core::ffi::c_uint,
+    pub width: usize,
+    pub height: usize,
+    pub ctx: CUcontext,
+}
+#[test]
+fn bindgen_test_layout_CUDA_MEMSET_NODE_PARAMS_v2_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_MEMSET_NODE_PARAMS_v2_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_MEMSET_NODE_PARAMS_v2_st > (), 48usize,
+        concat!("Size of: ", stringify!(CUDA_MEMSET_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_MEMSET_NODE_PARAMS_v2_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_MEMSET_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dst) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_v2_st), "::",
+        stringify!(dst))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).pitch) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_v2_st), "::",
+        stringify!(pitch))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).value) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_v2_st), "::",
+        stringify!(value))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).elementSize) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_v2_st),
+        "::", stringify!(elementSize))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).width) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_v2_st), "::",
+        stringify!(width))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).height) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_v2_st),
+        "::", stringify!(height))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).ctx) as usize - ptr as usize }, 40usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMSET_NODE_PARAMS_v2_st), "::",
+        stringify!(ctx))
+    );
+}
+impl Default for CUDA_MEMSET_NODE_PARAMS_v2_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_MEMSET_NODE_PARAMS_v2 = CUDA_MEMSET_NODE_PARAMS_v2_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_HOST_NODE_PARAMS_st {
+    pub fn_: CUhostFn,
+    pub userData: *mut ::core::ffi::c_void,
+}
+#[test]
+fn bindgen_test_layout_CUDA_HOST_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_HOST_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_HOST_NODE_PARAMS_st > (), 16usize,
+        concat!("Size of: ", stringify!(CUDA_HOST_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_HOST_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_HOST_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).fn_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_HOST_NODE_PARAMS_st), "::",
+        stringify!(fn_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).userData) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUDA_HOST_NODE_PARAMS_st), "::",
+        stringify!(userData))
+    );
+}
+impl Default for CUDA_HOST_NODE_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_HOST_NODE_PARAMS_v1 = CUDA_HOST_NODE_PARAMS_st;
+pub type CUDA_HOST_NODE_PARAMS = CUDA_HOST_NODE_PARAMS_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_HOST_NODE_PARAMS_v2_st {
+    pub fn_: CUhostFn,
+    pub userData: *mut ::core::ffi::c_void,
+}
+#[test]
+fn bindgen_test_layout_CUDA_HOST_NODE_PARAMS_v2_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_HOST_NODE_PARAMS_v2_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_HOST_NODE_PARAMS_v2_st > (), 16usize,
+        concat!("Size of: ", stringify!(CUDA_HOST_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_HOST_NODE_PARAMS_v2_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_HOST_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).fn_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_HOST_NODE_PARAMS_v2_st), "::",
+        stringify!(fn_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).userData) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUDA_HOST_NODE_PARAMS_v2_st),
+        "::", stringify!(userData))
+    );
+}
+impl Default for CUDA_HOST_NODE_PARAMS_v2_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_HOST_NODE_PARAMS_v2 = CUDA_HOST_NODE_PARAMS_v2_st;
+pub const CUgraphConditionalNodeType_enum_CU_GRAPH_COND_TYPE_IF: CUgraphConditionalNodeType_enum = 0;
+pub const CUgraphConditionalNodeType_enum_CU_GRAPH_COND_TYPE_WHILE: CUgraphConditionalNodeType_enum = 1;
+pub type CUgraphConditionalNodeType_enum = ::core::ffi::c_uint;
+pub use self::CUgraphConditionalNodeType_enum as CUgraphConditionalNodeType;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_CONDITIONAL_NODE_PARAMS {
+    pub handle: CUgraphConditionalHandle,
+    pub type_: CUgraphConditionalNodeType,
+    pub size: ::core::ffi::c_uint,
+    pub phGraph_out: *mut CUgraph,
+    pub ctx: CUcontext,
+}
+#[test]
+fn bindgen_test_layout_CUDA_CONDITIONAL_NODE_PARAMS() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_CONDITIONAL_NODE_PARAMS> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_CONDITIONAL_NODE_PARAMS > (), 32usize,
+        concat!("Size of: ", stringify!(CUDA_CONDITIONAL_NODE_PARAMS))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_CONDITIONAL_NODE_PARAMS > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_CONDITIONAL_NODE_PARAMS))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).handle) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_CONDITIONAL_NODE_PARAMS), "::",
+        stringify!(handle))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_CONDITIONAL_NODE_PARAMS), "::",
+        stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).size) as usize - ptr as usize }, 12usize,
+        concat!("Offset of field: ", stringify!(CUDA_CONDITIONAL_NODE_PARAMS), "::",
+        stringify!(size))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).phGraph_out) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUDA_CONDITIONAL_NODE_PARAMS),
+        "::", stringify!(phGraph_out))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).ctx) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUDA_CONDITIONAL_NODE_PARAMS), "::",
+        stringify!(ctx))
+    );
+}
+impl Default for CUDA_CONDITIONAL_NODE_PARAMS {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_KERNEL: CUgraphNodeType_enum = 0;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEMCPY: CUgraphNodeType_enum = 1;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEMSET: CUgraphNodeType_enum = 2;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_HOST: CUgraphNodeType_enum = 3;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_GRAPH: CUgraphNodeType_enum = 4;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EMPTY: CUgraphNodeType_enum = 5;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_WAIT_EVENT: CUgraphNodeType_enum = 6;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EVENT_RECORD: CUgraphNodeType_enum = 7;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EXT_SEMAS_SIGNAL: CUgraphNodeType_enum = 8;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EXT_SEMAS_WAIT: CUgraphNodeType_enum = 9;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEM_ALLOC: CUgraphNodeType_enum = 10;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEM_FREE: CUgraphNodeType_enum = 11;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_BATCH_MEM_OP: CUgraphNodeType_enum = 12;
+pub const CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_CONDITIONAL: CUgraphNodeType_enum = 13;
+pub type CUgraphNodeType_enum = ::core::ffi::c_uint;
+pub use self::CUgraphNodeType_enum as CUgraphNodeType;
+pub const CUgraphDependencyType_enum_CU_GRAPH_DEPENDENCY_TYPE_DEFAULT: CUgraphDependencyType_enum = 0;
+pub const CUgraphDependencyType_enum_CU_GRAPH_DEPENDENCY_TYPE_PROGRAMMATIC: CUgraphDependencyType_enum = 1;
+pub type CUgraphDependencyType_enum = ::core::ffi::c_uint;
+pub use self::CUgraphDependencyType_enum as CUgraphDependencyType;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUgraphEdgeData_st {
+    pub from_port: ::core::ffi::c_uchar,
+    pub to_port: ::core::ffi::c_uchar,
+    pub type_: ::core::ffi::c_uchar,
+    pub reserved: [::core::ffi::c_uchar; 5usize],
+}
+#[test]
+fn bindgen_test_layout_CUgraphEdgeData_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUgraphEdgeData_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUgraphEdgeData_st > (), 8usize, concat!("Size of: ",
+        stringify!(CUgraphEdgeData_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUgraphEdgeData_st > (), 1usize,
+        concat!("Alignment of ", stringify!(CUgraphEdgeData_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).from_port) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUgraphEdgeData_st), "::",
+        stringify!(from_port))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).to_port) as usize - ptr as usize },
+        1usize, concat!("Offset of field: ", stringify!(CUgraphEdgeData_st), "::",
+        stringify!(to_port))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 2usize,
+        concat!("Offset of field: ", stringify!(CUgraphEdgeData_st), "::",
+        stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        3usize, concat!("Offset of field: ", stringify!(CUgraphEdgeData_st), "::",
+        stringify!(reserved))
+    );
+}
+pub type CUgraphEdgeData = CUgraphEdgeData_st;
+pub const CUgraphInstantiateResult_enum_CUDA_GRAPH_INSTANTIATE_SUCCESS: CUgraphInstantiateResult_enum = 0;
+pub const CUgraphInstantiateResult_enum_CUDA_GRAPH_INSTANTIATE_ERROR: CUgraphInstantiateResult_enum = 1;
+pub const CUgraphInstantiateResult_enum_CUDA_GRAPH_INSTANTIATE_INVALID_STRUCTURE: CUgraphInstantiateResult_enum = 2;
+pub const CUgraphInstantiateResult_enum_CUDA_GRAPH_INSTANTIATE_NODE_OPERATION_NOT_SUPPORTED: CUgraphInstantiateResult_enum = 3;
+pub const CUgraphInstantiateResult_enum_CUDA_GRAPH_INSTANTIATE_MULTIPLE_CTXS_NOT_SUPPORTED: CUgraphInstantiateResult_enum = 4;
+pub type CUgraphInstantiateResult_enum = ::core::ffi::c_uint;
+pub use self::CUgraphInstantiateResult_enum as CUgraphInstantiateResult;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_GRAPH_INSTANTIATE_PARAMS_st {
+    pub flags: cuuint64_t,
+    pub hUploadStream: CUstream,
+    pub hErrNode_out: CUgraphNode,
+    pub result_out: CUgraphInstantiateResult,
+}
+#[test]
+fn bindgen_test_layout_CUDA_GRAPH_INSTANTIATE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_GRAPH_INSTANTIATE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_GRAPH_INSTANTIATE_PARAMS_st > (), 32usize,
+        concat!("Size of: ", stringify!(CUDA_GRAPH_INSTANTIATE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_GRAPH_INSTANTIATE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_GRAPH_INSTANTIATE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_GRAPH_INSTANTIATE_PARAMS_st), "::",
+        stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).hUploadStream) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_GRAPH_INSTANTIATE_PARAMS_st), "::", stringify!(hUploadStream))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).hErrNode_out) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_GRAPH_INSTANTIATE_PARAMS_st), "::", stringify!(hErrNode_out))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).result_out) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ",
+        stringify!(CUDA_GRAPH_INSTANTIATE_PARAMS_st), "::", stringify!(result_out))
+    );
+}
+impl Default for CUDA_GRAPH_INSTANTIATE_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_GRAPH_INSTANTIATE_PARAMS = CUDA_GRAPH_INSTANTIATE_PARAMS_st;
+pub const CUsynchronizationPolicy_enum_CU_SYNC_POLICY_AUTO: CUsynchronizationPolicy_enum = 1;
+pub const CUsynchronizationPolicy_enum_CU_SYNC_POLICY_SPIN: CUsynchronizationPolicy_enum = 2;
+pub const CUsynchronizationPolicy_enum_CU_SYNC_POLICY_YIELD: CUsynchronizationPolicy_enum = 3;
+pub const CUsynchronizationPolicy_enum_CU_SYNC_POLICY_BLOCKING_SYNC: CUsynchronizationPolicy_enum = 4;
+pub type CUsynchronizationPolicy_enum = ::core::ffi::c_uint;
+pub use self::CUsynchronizationPolicy_enum as CUsynchronizationPolicy;
+pub const CUclusterSchedulingPolicy_enum_CU_CLUSTER_SCHEDULING_POLICY_DEFAULT: CUclusterSchedulingPolicy_enum = 0;
+pub const CUclusterSchedulingPolicy_enum_CU_CLUSTER_SCHEDULING_POLICY_SPREAD: CUclusterSchedulingPolicy_enum = 1;
+pub const CUclusterSchedulingPolicy_enum_CU_CLUSTER_SCHEDULING_POLICY_LOAD_BALANCING: CUclusterSchedulingPolicy_enum = 2;
+pub type CUclusterSchedulingPolicy_enum = ::core::ffi::c_uint;
+pub use self::CUclusterSchedulingPolicy_enum as CUclusterSchedulingPolicy;
+pub const CUlaunchMemSyncDomain_enum_CU_LAUNCH_MEM_SYNC_DOMAIN_DEFAULT: CUlaunchMemSyncDomain_enum = 0;
+pub const CUlaunchMemSyncDomain_enum_CU_LAUNCH_MEM_SYNC_DOMAIN_REMOTE: CUlaunchMemSyncDomain_enum = 1;
+pub type CUlaunchMemSyncDomain_enum = ::core::ffi::c_uint;
+pub use self::CUlaunchMemSyncDomain_enum as CUlaunchMemSyncDomain;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUlaunchMemSyncDomainMap_st {
+    pub default_: ::core::ffi::c_uchar,
+    pub remote: ::core::ffi::c_uchar,
+}
+#[test]
+fn bindgen_test_layout_CUlaunchMemSyncDomainMap_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUlaunchMemSyncDomainMap_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUlaunchMemSyncDomainMap_st > (), 2usize,
+        concat!("Size of: ", stringify!(CUlaunchMemSyncDomainMap_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUlaunchMemSyncDomainMap_st > (), 1usize,
+        concat!("Alignment of ", stringify!(CUlaunchMemSyncDomainMap_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).default_) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUlaunchMemSyncDomainMap_st),
+        "::", stringify!(default_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).remote) as usize - ptr as usize }, 1usize,
+        concat!("Offset of field: ", stringify!(CUlaunchMemSyncDomainMap_st), "::",
+        stringify!(remote))
+    );
+}
+pub type CUlaunchMemSyncDomainMap = CUlaunchMemSyncDomainMap_st;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_IGNORE: CUlaunchAttributeID_enum = 0;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_ACCESS_POLICY_WINDOW: CUlaunchAttributeID_enum = 1;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_COOPERATIVE: CUlaunchAttributeID_enum = 2;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_SYNCHRONIZATION_POLICY: CUlaunchAttributeID_enum = 3;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_CLUSTER_DIMENSION: CUlaunchAttributeID_enum = 4;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_CLUSTER_SCHEDULING_POLICY_PREFERENCE: CUlaunchAttributeID_enum = 5;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_PROGRAMMATIC_STREAM_SERIALIZATION: CUlaunchAttributeID_enum = 6;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_PROGRAMMATIC_EVENT: CUlaunchAttributeID_enum = 7;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_PRIORITY: CUlaunchAttributeID_enum = 8;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_MEM_SYNC_DOMAIN_MAP: CUlaunchAttributeID_enum = 9;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_MEM_SYNC_DOMAIN: CUlaunchAttributeID_enum = 10;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_LAUNCH_COMPLETION_EVENT: CUlaunchAttributeID_enum = 12;
+pub const CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_DEVICE_UPDATABLE_KERNEL_NODE: CUlaunchAttributeID_enum = 13;
+pub type CUlaunchAttributeID_enum = ::core::ffi::c_uint;
+pub use self::CUlaunchAttributeID_enum as CUlaunchAttributeID;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUlaunchAttributeValue_union {
+    pub pad: [::core::ffi::c_char; 64usize],
+    pub accessPolicyWindow: CUaccessPolicyWindow,
+    pub cooperative: ::core::ffi::c_int,
+    pub syncPolicy: CUsynchronizationPolicy,
+    pub clusterDim: CUlaunchAttributeValue_union__bindgen_ty_1,
+    pub clusterSchedulingPolicyPreference: CUclusterSchedulingPolicy,
+    pub programmaticStreamSerializationAllowed: ::core::ffi::c_int,
+    pub programmaticEvent: CUlaunchAttributeValue_union__bindgen_ty_2,
+    pub launchCompletionEvent: CUlaunchAttributeValue_union__bindgen_ty_3,
+    pub priority: ::core::ffi::c_int,
+    pub memSyncDomainMap: CUlaunchMemSyncDomainMap,
+    pub memSyncDomain: CUlaunchMemSyncDomain,
+    pub deviceUpdatableKernelNode: CUlaunchAttributeValue_union__bindgen_ty_4,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUlaunchAttributeValue_union__bindgen_ty_1 {
+    pub x: ::core::ffi::c_uint,
+    pub y: ::core::ffi::c_uint,
+    pub z: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUlaunchAttributeValue_union__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<CUlaunchAttributeValue_union__bindgen_ty_1> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUlaunchAttributeValue_union__bindgen_ty_1 > (), 12usize,
+        concat!("Size of: ", stringify!(CUlaunchAttributeValue_union__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUlaunchAttributeValue_union__bindgen_ty_1 > (), 4usize,
+        concat!("Alignment of ", stringify!(CUlaunchAttributeValue_union__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).x) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_1), "::", stringify!(x))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).y) as usize - ptr as usize }, 4usize,
+        concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_1), "::", stringify!(y))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).z) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_1), "::", stringify!(z))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUlaunchAttributeValue_union__bindgen_ty_2 {
+    pub event: CUevent,
+    pub flags: ::core::ffi::c_int,
+    pub triggerAtBlockStart: ::core::ffi::c_int,
+}
+#[test]
+fn bindgen_test_layout_CUlaunchAttributeValue_union__bindgen_ty_2() {
+    const UNINIT: ::core::mem::MaybeUninit<CUlaunchAttributeValue_union__bindgen_ty_2> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUlaunchAttributeValue_union__bindgen_ty_2 > (), 16usize,
+        concat!("Size of: ", stringify!(CUlaunchAttributeValue_union__bindgen_ty_2))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUlaunchAttributeValue_union__bindgen_ty_2 > (), 8usize,
+        concat!("Alignment of ", stringify!(CUlaunchAttributeValue_union__bindgen_ty_2))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).event) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_2), "::", stringify!(event))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_2), "::", stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).triggerAtBlockStart) as usize - ptr as
+        usize }, 12usize, concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_2), "::",
+        stringify!(triggerAtBlockStart))
+    );
+}
+impl Default for CUlaunchAttributeValue_union__bindgen_ty_2 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUlaunchAttributeValue_union__bindgen_ty_3 {
+    pub event: CUevent,
+    pub flags: ::core::ffi::c_int,
+}
+#[test]
+fn bindgen_test_layout_CUlaunchAttributeValue_union__bindgen_ty_3() {
+    const UNINIT: ::core::mem::MaybeUninit<CUlaunchAttributeValue_union__bindgen_ty_3> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUlaunchAttributeValue_union__bindgen_ty_3 > (), 16usize,
+        concat!("Size of: ", stringify!(CUlaunchAttributeValue_union__bindgen_ty_3))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUlaunchAttributeValue_union__bindgen_ty_3 > (), 8usize,
+        concat!("Alignment of ", stringify!(CUlaunchAttributeValue_union__bindgen_ty_3))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).event) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_3), "::", stringify!(event))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_3), "::", stringify!(flags))
+    );
+}
+impl Default for CUlaunchAttributeValue_union__bindgen_ty_3 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUlaunchAttributeValue_union__bindgen_ty_4 {
+    pub deviceUpdatable: ::core::ffi::c_int,
+    pub devNode: CUgraphDeviceNode,
+}
+#[test]
+fn bindgen_test_layout_CUlaunchAttributeValue_union__bindgen_ty_4() {
+    const UNINIT: ::core::mem::MaybeUninit<CUlaunchAttributeValue_union__bindgen_ty_4> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUlaunchAttributeValue_union__bindgen_ty_4 > (), 16usize,
+        concat!("Size of: ", stringify!(CUlaunchAttributeValue_union__bindgen_ty_4))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUlaunchAttributeValue_union__bindgen_ty_4 > (), 8usize,
+        concat!("Alignment of ", stringify!(CUlaunchAttributeValue_union__bindgen_ty_4))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).deviceUpdatable) as usize - ptr as usize
+        }, 0usize, concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_4), "::",
+        stringify!(deviceUpdatable))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).devNode) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union__bindgen_ty_4), "::",
+        stringify!(devNode))
+    );
+}
+impl Default for CUlaunchAttributeValue_union__bindgen_ty_4 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUlaunchAttributeValue_union() {
+    const UNINIT: ::core::mem::MaybeUninit<CUlaunchAttributeValue_union> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUlaunchAttributeValue_union > (), 64usize,
+        concat!("Size of: ", stringify!(CUlaunchAttributeValue_union))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUlaunchAttributeValue_union > (), 8usize,
+        concat!("Alignment of ", stringify!(CUlaunchAttributeValue_union))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).pad) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUlaunchAttributeValue_union), "::",
+        stringify!(pad))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).accessPolicyWindow) as usize - ptr as
+        usize }, 0usize, concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union), "::", stringify!(accessPolicyWindow))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).cooperative) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUlaunchAttributeValue_union),
+        "::", stringify!(cooperative))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).syncPolicy) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUlaunchAttributeValue_union),
+        "::", stringify!(syncPolicy))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).clusterDim) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUlaunchAttributeValue_union),
+        "::", stringify!(clusterDim))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).clusterSchedulingPolicyPreference) as
+        usize - ptr as usize }, 0usize, concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union), "::",
+        stringify!(clusterSchedulingPolicyPreference))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).programmaticStreamSerializationAllowed) as
+        usize - ptr as usize }, 0usize, concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union), "::",
+        stringify!(programmaticStreamSerializationAllowed))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).programmaticEvent) as usize - ptr as usize
+        }, 0usize, concat!("Offset of field: ", stringify!(CUlaunchAttributeValue_union),
+        "::", stringify!(programmaticEvent))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).launchCompletionEvent) as usize - ptr as
+        usize }, 0usize, concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union), "::",
+        stringify!(launchCompletionEvent))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).priority) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUlaunchAttributeValue_union),
+        "::", stringify!(priority))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memSyncDomainMap) as usize - ptr as usize
+        }, 0usize, concat!("Offset of field: ", stringify!(CUlaunchAttributeValue_union),
+        "::", stringify!(memSyncDomainMap))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memSyncDomain) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUlaunchAttributeValue_union),
+        "::", stringify!(memSyncDomain))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).deviceUpdatableKernelNode) as usize - ptr
+        as usize }, 0usize, concat!("Offset of field: ",
+        stringify!(CUlaunchAttributeValue_union), "::",
+        stringify!(deviceUpdatableKernelNode))
+    );
+}
+impl Default for CUlaunchAttributeValue_union {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUlaunchAttributeValue = CUlaunchAttributeValue_union;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUlaunchAttribute_st {
+    pub id: CUlaunchAttributeID,
+    pub pad: [::core::ffi::c_char; 4usize],
+    pub value: CUlaunchAttributeValue,
+}
+#[test]
+fn bindgen_test_layout_CUlaunchAttribute_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUlaunchAttribute_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUlaunchAttribute_st > (), 72usize, concat!("Size of: ",
+        stringify!(CUlaunchAttribute_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUlaunchAttribute_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUlaunchAttribute_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).id) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUlaunchAttribute_st), "::",
+        stringify!(id))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).pad) as usize - ptr as usize }, 4usize,
+        concat!("Offset of field: ", stringify!(CUlaunchAttribute_st), "::",
+        stringify!(pad))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).value) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUlaunchAttribute_st), "::",
+        stringify!(value))
+    );
+}
+impl Default for CUlaunchAttribute_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUlaunchAttribute = CUlaunchAttribute_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUlaunchConfig_st {
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
+    pub hStream: CUstream,
+    pub attrs: *mut CUlaunchAttribute,
+    pub numAttrs: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUlaunchConfig_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUlaunchConfig_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUlaunchConfig_st > (), 56usize, concat!("Size of: ",
+        stringify!(CUlaunchConfig_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUlaunchConfig_st > (), 8usize, concat!("Alignment of ",
+        stringify!(CUlaunchConfig_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimX) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(gridDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimY) as usize - ptr as usize },
+        4usize, concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(gridDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimZ) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(gridDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimX) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(blockDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimY) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(blockDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimZ) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(blockDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).sharedMemBytes) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(sharedMemBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).hStream) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(hStream))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).attrs) as usize - ptr as usize }, 40usize,
+        concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(attrs))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).numAttrs) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ", stringify!(CUlaunchConfig_st), "::",
+        stringify!(numAttrs))
+    );
+}
+impl Default for CUlaunchConfig_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUlaunchConfig = CUlaunchConfig_st;
+pub use self::CUlaunchAttributeID as CUkernelNodeAttrID;
+pub type CUkernelNodeAttrValue_v1 = CUlaunchAttributeValue;
+pub type CUkernelNodeAttrValue = CUkernelNodeAttrValue_v1;
+pub const CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_NONE: CUstreamCaptureStatus_enum = 0;
+pub const CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_ACTIVE: CUstreamCaptureStatus_enum = 1;
+pub const CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_INVALIDATED: CUstreamCaptureStatus_enum = 2;
+pub type CUstreamCaptureStatus_enum = ::core::ffi::c_uint;
+pub use self::CUstreamCaptureStatus_enum as CUstreamCaptureStatus;
+pub const CUstreamCaptureMode_enum_CU_STREAM_CAPTURE_MODE_GLOBAL: CUstreamCaptureMode_enum = 0;
+pub const CUstreamCaptureMode_enum_CU_STREAM_CAPTURE_MODE_THREAD_LOCAL: CUstreamCaptureMode_enum = 1;
+pub const CUstreamCaptureMode_enum_CU_STREAM_CAPTURE_MODE_RELAXED: CUstreamCaptureMode_enum = 2;
+pub type CUstreamCaptureMode_enum = ::core::ffi::c_uint;
+pub use self::CUlaunchAttributeID as CUstreamAttrID;
+pub use self::CUstreamCaptureMode_enum as CUstreamCaptureMode;
+pub type CUstreamAttrValue_v1 = CUlaunchAttributeValue;
+pub type CUstreamAttrValue = CUstreamAttrValue_v1;
+pub const CUdriverProcAddress_flags_enum_CU_GET_PROC_ADDRESS_DEFAULT: CUdriverProcAddress_flags_enum = 0;
+pub const CUdriverProcAddress_flags_enum_CU_GET_PROC_ADDRESS_LEGACY_STREAM: CUdriverProcAddress_flags_enum = 1;
+pub const CUdriverProcAddress_flags_enum_CU_GET_PROC_ADDRESS_PER_THREAD_DEFAULT_STREAM: CUdriverProcAddress_flags_enum = 2;
+pub type CUdriverProcAddress_flags_enum = ::core::ffi::c_uint;
+pub use self::CUdriverProcAddress_flags_enum as CUdriverProcAddress_flags;
+pub const CUdriverProcAddressQueryResult_enum_CU_GET_PROC_ADDRESS_SUCCESS: CUdriverProcAddressQueryResult_enum = 0;
+pub const CUdriverProcAddressQueryResult_enum_CU_GET_PROC_ADDRESS_SYMBOL_NOT_FOUND: CUdriverProcAddressQueryResult_enum = 1;
+pub const CUdriverProcAddressQueryResult_enum_CU_GET_PROC_ADDRESS_VERSION_NOT_SUFFICIENT: CUdriverProcAddressQueryResult_enum = 2;
+pub type CUdriverProcAddressQueryResult_enum = ::core::ffi::c_uint;
+pub use self::CUdriverProcAddressQueryResult_enum as CUdriverProcAddressQueryResult;
+pub const CUexecAffinityType_enum_CU_EXEC_AFFINITY_TYPE_SM_COUNT: CUexecAffinityType_enum = 0;
+pub const CUexecAffinityType_enum_CU_EXEC_AFFINITY_TYPE_MAX: CUexecAffinityType_enum = 1;
+pub type CUexecAffinityType_enum = ::core::ffi::c_uint;
+pub use self::CUexecAffinityType_enum as CUexecAffinityType;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUexecAffinitySmCount_st {
+    pub val: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUexecAffinitySmCount_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUexecAffinitySmCount_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUexecAffinitySmCount_st > (), 4usize,
+        concat!("Size of: ", stringify!(CUexecAffinitySmCount_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUexecAffinitySmCount_st > (), 4usize,
+        concat!("Alignment of ", stringify!(CUexecAffinitySmCount_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).val) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUexecAffinitySmCount_st), "::",
+        stringify!(val))
+    );
+}
+pub type CUexecAffinitySmCount_v1 = CUexecAffinitySmCount_st;
+pub type CUexecAffinitySmCount = CUexecAffinitySmCount_v1;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUexecAffinityParam_st {
+    pub type_: CUexecAffinityType,
+    pub param: CUexecAffinityParam_st__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUexecAffinityParam_st__bindgen_ty_1 {
+    pub smCount: CUexecAffinitySmCount,
+}
+#[test]
+fn bindgen_test_layout_CUexecAffinityParam_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<CUexecAffinityParam_st__bindgen_ty_1> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUexecAffinityParam_st__bindgen_ty_1 > (), 4usize,
+        concat!("Size of: ", stringify!(CUexecAffinityParam_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUexecAffinityParam_st__bindgen_ty_1 > (), 4usize,
+        concat!("Alignment of ", stringify!(CUexecAffinityParam_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).smCount) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUexecAffinityParam_st__bindgen_ty_1), "::", stringify!(smCount))
+    );
+}
+impl Default for CUexecAffinityParam_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUexecAffinityParam_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUexecAffinityParam_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUexecAffinityParam_st > (), 8usize, concat!("Size of: ",
+        stringify!(CUexecAffinityParam_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUexecAffinityParam_st > (), 4usize,
+        concat!("Alignment of ", stringify!(CUexecAffinityParam_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUexecAffinityParam_st), "::",
+        stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).param) as usize - ptr as usize }, 4usize,
+        concat!("Offset of field: ", stringify!(CUexecAffinityParam_st), "::",
+        stringify!(param))
+    );
+}
+impl Default for CUexecAffinityParam_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUexecAffinityParam_v1 = CUexecAffinityParam_st;
+pub type CUexecAffinityParam = CUexecAffinityParam_v1;
+pub const CUlibraryOption_enum_CU_LIBRARY_HOST_UNIVERSAL_FUNCTION_AND_DATA_TABLE: CUlibraryOption_enum = 0;
+pub const CUlibraryOption_enum_CU_LIBRARY_BINARY_IS_PRESERVED: CUlibraryOption_enum = 1;
+pub const CUlibraryOption_enum_CU_LIBRARY_NUM_OPTIONS: CUlibraryOption_enum = 2;
+pub type CUlibraryOption_enum = ::core::ffi::c_uint;
+pub use self::CUlibraryOption_enum as CUlibraryOption;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUlibraryHostUniversalFunctionAndDataTable_st {
+    pub functionTable: *mut ::core::ffi::c_void,
+    pub functionWindowSize: usize,
+    pub dataTable: *mut ::core::ffi::c_void,
+    pub dataWindowSize: usize,
+}
+#[test]
+fn bindgen_test_layout_CUlibraryHostUniversalFunctionAndDataTable_st() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUlibraryHostUniversalFunctionAndDataTable_st,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUlibraryHostUniversalFunctionAndDataTable_st > (),
+        32usize, concat!("Size of: ",
+        stringify!(CUlibraryHostUniversalFunctionAndDataTable_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUlibraryHostUniversalFunctionAndDataTable_st > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUlibraryHostUniversalFunctionAndDataTable_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).functionTable) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUlibraryHostUniversalFunctionAndDataTable_st), "::",
+        stringify!(functionTable))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).functionWindowSize) as usize - ptr as
+        usize }, 8usize, concat!("Offset of field: ",
+        stringify!(CUlibraryHostUniversalFunctionAndDataTable_st), "::",
+        stringify!(functionWindowSize))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dataTable) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUlibraryHostUniversalFunctionAndDataTable_st), "::",
+        stringify!(dataTable))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dataWindowSize) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ",
+        stringify!(CUlibraryHostUniversalFunctionAndDataTable_st), "::",
+        stringify!(dataWindowSize))
+    );
+}
+impl Default for CUlibraryHostUniversalFunctionAndDataTable_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUlibraryHostUniversalFunctionAndDataTable = CUlibraryHostUniversalFunctionAndDataTable_st;
+pub const cudaError_enum_CUDA_SUCCESS: cudaError_enum = 0;
+pub const cudaError_enum_CUDA_ERROR_INVALID_VALUE: cudaError_enum = 1;
+pub const cudaError_enum_CUDA_ERROR_OUT_OF_MEMORY: cudaError_enum = 2;
+pub const cudaError_enum_CUDA_ERROR_NOT_INITIALIZED: cudaError_enum = 3;
+pub const cudaError_enum_CUDA_ERROR_DEINITIALIZED: cudaError_enum = 4;
+pub const cudaError_enum_CUDA_ERROR_PROFILER_DISABLED: cudaError_enum = 5;
+pub const cudaError_enum_CUDA_ERROR_PROFILER_NOT_INITIALIZED: cudaError_enum = 6;
+pub const cudaError_enum_CUDA_ERROR_PROFILER_ALREADY_STARTED: cudaError_enum = 7;
+pub const cudaError_enum_CUDA_ERROR_PROFILER_ALREADY_STOPPED: cudaError_enum = 8;
+pub const cudaError_enum_CUDA_ERROR_STUB_LIBRARY: cudaError_enum = 34;
+pub const cudaError_enum_CUDA_ERROR_DEVICE_UNAVAILABLE: cudaError_enum = 46;
+pub const cudaError_enum_CUDA_ERROR_NO_DEVICE: cudaError_enum = 100;
+pub const cudaError_enum_CUDA_ERROR_INVALID_DEVICE: cudaError_enum = 101;
+pub const cudaError_enum_CUDA_ERROR_DEVICE_NOT_LICENSED: cudaError_enum = 102;
+pub const cudaError_enum_CUDA_ERROR_INVALID_IMAGE: cudaError_enum = 200;
+pub const cudaError_enum_CUDA_ERROR_INVALID_CONTEXT: cudaError_enum = 201;
+pub const cudaError_enum_CUDA_ERROR_CONTEXT_ALREADY_CURRENT: cudaError_enum = 202;
+pub const cudaError_enum_CUDA_ERROR_MAP_FAILED: cudaError_enum = 205;
+pub const cudaError_enum_CUDA_ERROR_UNMAP_FAILED: cudaError_enum = 206;
+pub const cudaError_enum_CUDA_ERROR_ARRAY_IS_MAPPED: cudaError_enum = 207;
+pub const cudaError_enum_CUDA_ERROR_ALREADY_MAPPED: cudaError_enum = 208;
+pub const cudaError_enum_CUDA_ERROR_NO_BINARY_FOR_GPU: cudaError_enum = 209;
+pub const cudaError_enum_CUDA_ERROR_ALREADY_ACQUIRED: cudaError_enum = 210;
+pub const cudaError_enum_CUDA_ERROR_NOT_MAPPED: cudaError_enum = 211;
+pub const cudaError_enum_CUDA_ERROR_NOT_MAPPED_AS_ARRAY: cudaError_enum = 212;
+pub const cudaError_enum_CUDA_ERROR_NOT_MAPPED_AS_POINTER: cudaError_enum = 213;
+pub const cudaError_enum_CUDA_ERROR_ECC_UNCORRECTABLE: cudaError_enum = 214;
+pub const cudaError_enum_CUDA_ERROR_UNSUPPORTED_LIMIT: cudaError_enum = 215;
+pub const cudaError_enum_CUDA_ERROR_CONTEXT_ALREADY_IN_USE: cudaError_enum = 216;
+pub const cudaError_enum_CUDA_ERROR_PEER_ACCESS_UNSUPPORTED: cudaError_enum = 217;
+pub const cudaError_enum_CUDA_ERROR_INVALID_PTX: cudaError_enum = 218;
+pub const cudaError_enum_CUDA_ERROR_INVALID_GRAPHICS_CONTEXT: cudaError_enum = 219;
+pub const cudaError_enum_CUDA_ERROR_NVLINK_UNCORRECTABLE: cudaError_enum = 220;
+pub const cudaError_enum_CUDA_ERROR_JIT_COMPILER_NOT_FOUND: cudaError_enum = 221;
+pub const cudaError_enum_CUDA_ERROR_UNSUPPORTED_PTX_VERSION: cudaError_enum = 222;
+pub const cudaError_enum_CUDA_ERROR_JIT_COMPILATION_DISABLED: cudaError_enum = 223;
+pub const cudaError_enum_CUDA_ERROR_UNSUPPORTED_EXEC_AFFINITY: cudaError_enum = 224;
+pub const cudaError_enum_CUDA_ERROR_UNSUPPORTED_DEVSIDE_SYNC: cudaError_enum = 225;
+pub const cudaError_enum_CUDA_ERROR_INVALID_SOURCE: cudaError_enum = 300;
+pub const cudaError_enum_CUDA_ERROR_FILE_NOT_FOUND: cudaError_enum = 301;
+pub const cudaError_enum_CUDA_ERROR_SHARED_OBJECT_SYMBOL_NOT_FOUND: cudaError_enum = 302;
+pub const cudaError_enum_CUDA_ERROR_SHARED_OBJECT_INIT_FAILED: cudaError_enum = 303;
+pub const cudaError_enum_CUDA_ERROR_OPERATING_SYSTEM: cudaError_enum = 304;
+pub const cudaError_enum_CUDA_ERROR_INVALID_HANDLE: cudaError_enum = 400;
+pub const cudaError_enum_CUDA_ERROR_ILLEGAL_STATE: cudaError_enum = 401;
+pub const cudaError_enum_CUDA_ERROR_LOSSY_QUERY: cudaError_enum = 402;
+pub const cudaError_enum_CUDA_ERROR_NOT_FOUND: cudaError_enum = 500;
+pub const cudaError_enum_CUDA_ERROR_NOT_READY: cudaError_enum = 600;
+pub const cudaError_enum_CUDA_ERROR_ILLEGAL_ADDRESS: cudaError_enum = 700;
+pub const cudaError_enum_CUDA_ERROR_LAUNCH_OUT_OF_RESOURCES: cudaError_enum = 701;
+pub const cudaError_enum_CUDA_ERROR_LAUNCH_TIMEOUT: cudaError_enum = 702;
+pub const cudaError_enum_CUDA_ERROR_LAUNCH_INCOMPATIBLE_TEXTURING: cudaError_enum = 703;
+pub const cudaError_enum_CUDA_ERROR_PEER_ACCESS_ALREADY_ENABLED: cudaError_enum = 704;
+pub const cudaError_enum_CUDA_ERROR_PEER_ACCESS_NOT_ENABLED: cudaError_enum = 705;
+pub const cudaError_enum_CUDA_ERROR_PRIMARY_CONTEXT_ACTIVE: cudaError_enum = 708;
+pub const cudaError_enum_CUDA_ERROR_CONTEXT_IS_DESTROYED: cudaError_enum = 709;
+pub const cudaError_enum_CUDA_ERROR_ASSERT: cudaError_enum = 710;
+pub const cudaError_enum_CUDA_ERROR_TOO_MANY_PEERS: cudaError_enum = 711;
+pub const cudaError_enum_CUDA_ERROR_HOST_MEMORY_ALREADY_REGISTERED: cudaError_enum = 712;
+pub const cudaError_enum_CUDA_ERROR_HOST_MEMORY_NOT_REGISTERED: cudaError_enum = 713;
+pub const cudaError_enum_CUDA_ERROR_HARDWARE_STACK_ERROR: cudaError_enum = 714;
+pub const cudaError_enum_CUDA_ERROR_ILLEGAL_INSTRUCTION: cudaError_enum = 715;
+pub const cudaError_enum_CUDA_ERROR_MISALIGNED_ADDRESS: cudaError_enum = 716;
+pub const cudaError_enum_CUDA_ERROR_INVALID_ADDRESS_SPACE: cudaError_enum = 717;
+pub const cudaError_enum_CUDA_ERROR_INVALID_PC: cudaError_enum = 718;
+pub const cudaError_enum_CUDA_ERROR_LAUNCH_FAILED: cudaError_enum = 719;
+pub const cudaError_enum_CUDA_ERROR_COOPERATIVE_LAUNCH_TOO_LARGE: cudaError_enum = 720;
+pub const cudaError_enum_CUDA_ERROR_NOT_PERMITTED: cudaError_enum = 800;
+pub const cudaError_enum_CUDA_ERROR_NOT_SUPPORTED: cudaError_enum = 801;
+pub const cudaError_enum_CUDA_ERROR_SYSTEM_NOT_READY: cudaError_enum = 802;
+pub const cudaError_enum_CUDA_ERROR_SYSTEM_DRIVER_MISMATCH: cudaError_enum = 803;
+pub const cudaError_enum_CUDA_ERROR_COMPAT_NOT_SUPPORTED_ON_DEVICE: cudaError_enum = 804;
+pub const cudaError_enum_CUDA_ERROR_MPS_CONNECTION_FAILED: cudaError_enum = 805;
+pub const cudaError_enum_CUDA_ERROR_MPS_RPC_FAILURE: cudaError_enum = 806;
+pub const cudaError_enum_CUDA_ERROR_MPS_SERVER_NOT_READY: cudaError_enum = 807;
+pub const cudaError_enum_CUDA_ERROR_MPS_MAX_CLIENTS_REACHED: cudaError_enum = 808;
+pub const cudaError_enum_CUDA_ERROR_MPS_MAX_CONNECTIONS_REACHED: cudaError_enum = 809;
+pub const cudaError_enum_CUDA_ERROR_MPS_CLIENT_TERMINATED: cudaError_enum = 810;
+pub const cudaError_enum_CUDA_ERROR_CDP_NOT_SUPPORTED: cudaError_enum = 811;
+pub const cudaError_enum_CUDA_ERROR_CDP_VERSION_MISMATCH: cudaError_enum = 812;
+pub const cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNSUPPORTED: cudaError_enum = 900;
+pub const cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_INVALIDATED: cudaError_enum = 901;
+pub const cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_MERGE: cudaError_enum = 902;
+pub const cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNMATCHED: cudaError_enum = 903;
+pub const cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNJOINED: cudaError_enum = 904;
+pub const cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_ISOLATION: cudaError_enum = 905;
+pub const cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_IMPLICIT: cudaError_enum = 906;
+pub const cudaError_enum_CUDA_ERROR_CAPTURED_EVENT: cudaError_enum = 907;
+pub const cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_WRONG_THREAD: cudaError_enum = 908;
+pub const cudaError_enum_CUDA_ERROR_TIMEOUT: cudaError_enum = 909;
+pub const cudaError_enum_CUDA_ERROR_GRAPH_EXEC_UPDATE_FAILURE: cudaError_enum = 910;
+pub const cudaError_enum_CUDA_ERROR_EXTERNAL_DEVICE: cudaError_enum = 911;
+pub const cudaError_enum_CUDA_ERROR_INVALID_CLUSTER_SIZE: cudaError_enum = 912;
+pub const cudaError_enum_CUDA_ERROR_FUNCTION_NOT_LOADED: cudaError_enum = 913;
+pub const cudaError_enum_CUDA_ERROR_INVALID_RESOURCE_TYPE: cudaError_enum = 914;
+pub const cudaError_enum_CUDA_ERROR_INVALID_RESOURCE_CONFIGURATION: cudaError_enum = 915;
+pub const cudaError_enum_CUDA_ERROR_UNKNOWN: cudaError_enum = 999;
+pub type cudaError_enum = ::core::ffi::c_uint;
+pub use self::cudaError_enum as CUresult;
+pub const CUdevice_P2PAttribute_enum_CU_DEVICE_P2P_ATTRIBUTE_CUDA_ARRAY_ACCESS_SUPPORTED: CUdevice_P2PAttribute_enum = 4;
+pub const CUdevice_P2PAttribute_enum_CU_DEVICE_P2P_ATTRIBUTE_PERFORMANCE_RANK: CUdevice_P2PAttribute_enum = 1;
+pub const CUdevice_P2PAttribute_enum_CU_DEVICE_P2P_ATTRIBUTE_ACCESS_SUPPORTED: CUdevice_P2PAttribute_enum = 2;
+pub const CUdevice_P2PAttribute_enum_CU_DEVICE_P2P_ATTRIBUTE_NATIVE_ATOMIC_SUPPORTED: CUdevice_P2PAttribute_enum = 3;
+pub const CUdevice_P2PAttribute_enum_CU_DEVICE_P2P_ATTRIBUTE_ACCESS_ACCESS_SUPPORTED: CUdevice_P2PAttribute_enum = 4;
+pub type CUdevice_P2PAttribute_enum = ::core::ffi::c_uint;
+pub use self::CUdevice_P2PAttribute_enum as CUdevice_P2PAttribute;
+pub type CUstreamCallback = ::core::option::Option<
+    unsafe extern "C" fn(
+        hStream: CUstream,
+        status: CUresult,
+        userData: *mut ::core::ffi::c_void,
+    ),
+>;
+pub type CUoccupancyB2DSize = ::core::option::Option<
+    unsafe extern "C" fn(blockSize: ::core::ffi::c_int) -> usize,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_MEMCPY2D_st {
+    pub srcXInBytes: usize,
+    pub srcY: usize,
+    pub srcMemoryType: CUmemorytype,
+    pub srcHost: *const ::core::ffi::c_void,
+    pub srcDevice: CUdeviceptr,
+    pub srcArray: CUarray,
+    pub srcPitch: usize,
+    pub dstXInBytes: usize,
+    pub dstY: usize,
+    pub dstMemoryType: CUmemorytype,
+    pub dstHost: *mut ::core::ffi::c_void,
+    pub dstDevice: CUdeviceptr,
+    pub dstArray: CUarray,
+    pub dstPitch: usize,
+    pub WidthInBytes: usize,
+    pub Height: usize,
+}
+#[test]
+fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_MEMCPY2D_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_MEMCPY2D_st > (), 128usize, concat!("Size of: ",
+        stringify!(CUDA_MEMCPY2D_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_MEMCPY2D_st > (), 8usize, concat!("Alignment of ",
+        stringify!(CUDA_MEMCPY2D_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcXInBytes) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(srcXInBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcY) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(srcY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcMemoryType) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(srcMemoryType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcHost) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(srcHost))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcDevice) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(srcDevice))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcArray) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(srcArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcPitch) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(srcPitch))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstXInBytes) as usize - ptr as usize },
+        56usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(dstXInBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstY) as usize - ptr as usize }, 64usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(dstY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstMemoryType) as usize - ptr as usize },
+        72usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(dstMemoryType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstHost) as usize - ptr as usize },
+        80usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(dstHost))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstDevice) as usize - ptr as usize },
+        88usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(dstDevice))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstArray) as usize - ptr as usize },
+        96usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(dstArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstPitch) as usize - ptr as usize },
+        104usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(dstPitch))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).WidthInBytes) as usize - ptr as usize },
+        112usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(WidthInBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Height) as usize - ptr as usize },
+        120usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY2D_st), "::",
+        stringify!(Height))
+    );
+}
+impl Default for CUDA_MEMCPY2D_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_MEMCPY2D_v2 = CUDA_MEMCPY2D_st;
+pub type CUDA_MEMCPY2D = CUDA_MEMCPY2D_v2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_MEMCPY3D_st {
+    pub srcXInBytes: usize,
+    pub srcY: usize,
+    pub srcZ: usize,
+    pub srcLOD: usize,
+    pub srcMemoryType: CUmemorytype,
+    pub srcHost: *const ::core::ffi::c_void,
+    pub srcDevice: CUdeviceptr,
+    pub srcArray: CUarray,
+    pub reserved0: *mut ::core::ffi::c_void,
+    pub srcPitch: usize,
+    pub srcHeight: usize,
+    pub dstXInBytes: usize,
+    pub dstY: usize,
+    pub dstZ: usize,
+    pub dstLOD: usize,
+    pub dstMemoryType: CUmemorytype,
+    pub dstHost: *mut ::core::ffi::c_void,
+    pub dstDevice: CUdeviceptr,
+    pub dstArray: CUarray,
+    pub reserved1: *mut ::core::ffi::c_void,
+    pub dstPitch: usize,
+    pub dstHeight: usize,
+    pub WidthInBytes: usize,
+    pub Height: usize,
+    pub Depth: usize,
+}
+#[test]
+fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_MEMCPY3D_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_MEMCPY3D_st > (), 200usize, concat!("Size of: ",
+        stringify!(CUDA_MEMCPY3D_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_MEMCPY3D_st > (), 8usize, concat!("Alignment of ",
+        stringify!(CUDA_MEMCPY3D_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcXInBytes) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcXInBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcY) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcZ) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcLOD) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcLOD))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcMemoryType) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcMemoryType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcHost) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcHost))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcDevice) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcDevice))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcArray) as usize - ptr as usize },
+        56usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved0) as usize - ptr as usize },
+        64usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(reserved0))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcPitch) as usize - ptr as usize },
+        72usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcPitch))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcHeight) as usize - ptr as usize },
+        80usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(srcHeight))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstXInBytes) as usize - ptr as usize },
+        88usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstXInBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstY) as usize - ptr as usize }, 96usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstZ) as usize - ptr as usize }, 104usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstLOD) as usize - ptr as usize },
+        112usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstLOD))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstMemoryType) as usize - ptr as usize },
+        120usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstMemoryType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstHost) as usize - ptr as usize },
+        128usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstHost))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstDevice) as usize - ptr as usize },
+        136usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstDevice))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstArray) as usize - ptr as usize },
+        144usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved1) as usize - ptr as usize },
+        152usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(reserved1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstPitch) as usize - ptr as usize },
+        160usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstPitch))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstHeight) as usize - ptr as usize },
+        168usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(dstHeight))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).WidthInBytes) as usize - ptr as usize },
+        176usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(WidthInBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Height) as usize - ptr as usize },
+        184usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(Height))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Depth) as usize - ptr as usize },
+        192usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_st), "::",
+        stringify!(Depth))
+    );
+}
+impl Default for CUDA_MEMCPY3D_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_MEMCPY3D_v2 = CUDA_MEMCPY3D_st;
+pub type CUDA_MEMCPY3D = CUDA_MEMCPY3D_v2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_MEMCPY3D_PEER_st {
+    pub srcXInBytes: usize,
+    pub srcY: usize,
+    pub srcZ: usize,
+    pub srcLOD: usize,
+    pub srcMemoryType: CUmemorytype,
+    pub srcHost: *const ::core::ffi::c_void,
+    pub srcDevice: CUdeviceptr,
+    pub srcArray: CUarray,
+    pub srcContext: CUcontext,
+    pub srcPitch: usize,
+    pub srcHeight: usize,
+    pub dstXInBytes: usize,
+    pub dstY: usize,
+    pub dstZ: usize,
+    pub dstLOD: usize,
+    pub dstMemoryType: CUmemorytype,
+    pub dstHost: *mut ::core::ffi::c_void,
+    pub dstDevice: CUdeviceptr,
+    pub dstArray: CUarray,
+    pub dstContext: CUcontext,
+    pub dstPitch: usize,
+    pub dstHeight: usize,
+    pub WidthInBytes: usize,
+    pub Height: usize,
+    pub Depth: usize,
+}
+#[test]
+fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_MEMCPY3D_PEER_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_MEMCPY3D_PEER_st > (), 200usize,
+        concat!("Size of: ", stringify!(CUDA_MEMCPY3D_PEER_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_MEMCPY3D_PEER_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_MEMCPY3D_PEER_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcXInBytes) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcXInBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcY) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcZ) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcLOD) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcLOD))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcMemoryType) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcMemoryType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcHost) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcHost))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcDevice) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcDevice))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcArray) as usize - ptr as usize },
+        56usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcContext) as usize - ptr as usize },
+        64usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcContext))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcPitch) as usize - ptr as usize },
+        72usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcPitch))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).srcHeight) as usize - ptr as usize },
+        80usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(srcHeight))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstXInBytes) as usize - ptr as usize },
+        88usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstXInBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstY) as usize - ptr as usize }, 96usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstZ) as usize - ptr as usize }, 104usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstLOD) as usize - ptr as usize },
+        112usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstLOD))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstMemoryType) as usize - ptr as usize },
+        120usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstMemoryType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstHost) as usize - ptr as usize },
+        128usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstHost))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstDevice) as usize - ptr as usize },
+        136usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstDevice))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstArray) as usize - ptr as usize },
+        144usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstContext) as usize - ptr as usize },
+        152usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstContext))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstPitch) as usize - ptr as usize },
+        160usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstPitch))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dstHeight) as usize - ptr as usize },
+        168usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(dstHeight))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).WidthInBytes) as usize - ptr as usize },
+        176usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(WidthInBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Height) as usize - ptr as usize },
+        184usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(Height))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Depth) as usize - ptr as usize },
+        192usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY3D_PEER_st), "::",
+        stringify!(Depth))
+    );
+}
+impl Default for CUDA_MEMCPY3D_PEER_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_MEMCPY3D_PEER_v1 = CUDA_MEMCPY3D_PEER_st;
+pub type CUDA_MEMCPY3D_PEER = CUDA_MEMCPY3D_PEER_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_MEMCPY_NODE_PARAMS_st {
+    pub flags: ::core::ffi::c_int,
+    pub reserved: ::core::ffi::c_int,
+    pub copyCtx: CUcontext,
+    pub copyParams: CUDA_MEMCPY3D,
+}
+#[test]
+fn bindgen_test_layout_CUDA_MEMCPY_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_MEMCPY_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_MEMCPY_NODE_PARAMS_st > (), 216usize,
+        concat!("Size of: ", stringify!(CUDA_MEMCPY_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_MEMCPY_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_MEMCPY_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEMCPY_NODE_PARAMS_st), "::",
+        stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        4usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY_NODE_PARAMS_st),
+        "::", stringify!(reserved))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).copyCtx) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY_NODE_PARAMS_st),
+        "::", stringify!(copyCtx))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).copyParams) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUDA_MEMCPY_NODE_PARAMS_st),
+        "::", stringify!(copyParams))
+    );
+}
+impl Default for CUDA_MEMCPY_NODE_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_MEMCPY_NODE_PARAMS = CUDA_MEMCPY_NODE_PARAMS_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_ARRAY_DESCRIPTOR_st {
+    pub Width: usize,
+    pub Height: usize,
+    pub Format: CUarray_format,
+    pub NumChannels: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_ARRAY_DESCRIPTOR_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_ARRAY_DESCRIPTOR_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_ARRAY_DESCRIPTOR_st > (), 24usize,
+        concat!("Size of: ", stringify!(CUDA_ARRAY_DESCRIPTOR_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_ARRAY_DESCRIPTOR_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_ARRAY_DESCRIPTOR_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Width) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_ARRAY_DESCRIPTOR_st), "::",
+        stringify!(Width))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Height) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_ARRAY_DESCRIPTOR_st), "::",
+        stringify!(Height))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Format) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUDA_ARRAY_DESCRIPTOR_st), "::",
+        stringify!(Format))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).NumChannels) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUDA_ARRAY_DESCRIPTOR_st), "::",
+        stringify!(NumChannels))
+    );
+}
+impl Default for CUDA_ARRAY_DESCRIPTOR_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_ARRAY_DESCRIPTOR_v2 = CUDA_ARRAY_DESCRIPTOR_st;
+pub type CUDA_ARRAY_DESCRIPTOR = CUDA_ARRAY_DESCRIPTOR_v2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_ARRAY3D_DESCRIPTOR_st {
+    pub Width: usize,
+    pub Height: usize,
+    pub Depth: usize,
+    pub Format: CUarray_format,
+    pub NumChannels: ::core::ffi::c_uint,
+    pub Flags: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_ARRAY3D_DESCRIPTOR_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_ARRAY3D_DESCRIPTOR_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_ARRAY3D_DESCRIPTOR_st > (), 40usize,
+        concat!("Size of: ", stringify!(CUDA_ARRAY3D_DESCRIPTOR_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_ARRAY3D_DESCRIPTOR_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_ARRAY3D_DESCRIPTOR_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Width) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_ARRAY3D_DESCRIPTOR_st), "::",
+        stringify!(Width))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Height) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_ARRAY3D_DESCRIPTOR_st), "::",
+        stringify!(Height))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Depth) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ", stringify!(CUDA_ARRAY3D_DESCRIPTOR_st), "::",
+        stringify!(Depth))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Format) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUDA_ARRAY3D_DESCRIPTOR_st),
+        "::", stringify!(Format))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).NumChannels) as usize - ptr as usize },
+        28usize, concat!("Offset of field: ", stringify!(CUDA_ARRAY3D_DESCRIPTOR_st),
+        "::", stringify!(NumChannels))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).Flags) as usize - ptr as usize }, 32usize,
+        concat!("Offset of field: ", stringify!(CUDA_ARRAY3D_DESCRIPTOR_st), "::",
+        stringify!(Flags))
+    );
+}
+impl Default for CUDA_ARRAY3D_DESCRIPTOR_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_ARRAY3D_DESCRIPTOR_v2 = CUDA_ARRAY3D_DESCRIPTOR_st;
+pub type CUDA_ARRAY3D_DESCRIPTOR = CUDA_ARRAY3D_DESCRIPTOR_v2;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_ARRAY_SPARSE_PROPERTIES_st {
+    pub tileExtent: CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1,
+    pub miptailFirstLevel: ::core::ffi::c_uint,
+    pub miptailSize: ::core::ffi::c_ulonglong,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 4usize],
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1 {
+    pub width: ::core::ffi::c_uint,
+    pub height: ::core::ffi::c_uint,
+    pub depth: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1 > (),
+        12usize, concat!("Size of: ",
+        stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1 > (),
+        4usize, concat!("Alignment of ",
+        stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).width) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1), "::",
+        stringify!(width))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).height) as usize - ptr as usize }, 4usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1), "::",
+        stringify!(height))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).depth) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1), "::",
+        stringify!(depth))
+    );
+}
+#[test]
+fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_ARRAY_SPARSE_PROPERTIES_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_ARRAY_SPARSE_PROPERTIES_st > (), 48usize,
+        concat!("Size of: ", stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_ARRAY_SPARSE_PROPERTIES_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).tileExtent) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st),
+        "::", stringify!(tileExtent))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).miptailFirstLevel) as usize - ptr as usize
+        }, 12usize, concat!("Offset of field: ",
+        stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st), "::", stringify!(miptailFirstLevel))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).miptailSize) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st), "::", stringify!(miptailSize))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st), "::",
+        stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        28usize, concat!("Offset of field: ",
+        stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st), "::", stringify!(reserved))
+    );
+}
+pub type CUDA_ARRAY_SPARSE_PROPERTIES_v1 = CUDA_ARRAY_SPARSE_PROPERTIES_st;
+pub type CUDA_ARRAY_SPARSE_PROPERTIES = CUDA_ARRAY_SPARSE_PROPERTIES_v1;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_ARRAY_MEMORY_REQUIREMENTS_st {
+    pub size: usize,
+    pub alignment: usize,
+    pub reserved: [::core::ffi::c_uint; 4usize],
+}
+#[test]
+fn bindgen_test_layout_CUDA_ARRAY_MEMORY_REQUIREMENTS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_ARRAY_MEMORY_REQUIREMENTS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_ARRAY_MEMORY_REQUIREMENTS_st > (), 32usize,
+        concat!("Size of: ", stringify!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_ARRAY_MEMORY_REQUIREMENTS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).size) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st), "::",
+        stringify!(size))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).alignment) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st), "::", stringify!(alignment))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st), "::", stringify!(reserved))
+    );
+}
+pub type CUDA_ARRAY_MEMORY_REQUIREMENTS_v1 = CUDA_ARRAY_MEMORY_REQUIREMENTS_st;
+pub type CUDA_ARRAY_MEMORY_REQUIREMENTS = CUDA_ARRAY_MEMORY_REQUIREMENTS_v1;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUDA_RESOURCE_DESC_st {
+    pub resType: CUresourcetype,
+    pub res: CUDA_RESOURCE_DESC_st__bindgen_ty_1,
+    pub flags: ::core::ffi::c_uint,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUDA_RESOURCE_DESC_st__bindgen_ty_1 {
+    pub array: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+    pub mipmap: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2,
+    pub linear: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3,
+    pub pitch2D: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
+    pub reserved: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
+    pub hArray: CUarray,
+}
+#[test]
+fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1 > (),
+        8usize, concat!("Size of: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1 > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).hArray) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1), "::",
+        stringify!(hArray))
+    );
+}
+impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2 {
+    pub hMipmappedArray: CUmipmappedArray,
+}
+#[test]
+fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2 > (),
+        8usize, concat!("Size of: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2 > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).hMipmappedArray) as usize - ptr as usize
+        }, 0usize, concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2), "::",
+        stringify!(hMipmappedArray))
+    );
+}
+impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3 {
+    pub devPtr: CUdeviceptr,
+    pub format: CUarray_format,
+    pub numChannels: ::core::ffi::c_uint,
+    pub sizeInBytes: usize,
+}
+#[test]
+fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3 > (),
+        24usize, concat!("Size of: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3 > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).devPtr) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3), "::",
+        stringify!(devPtr))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).format) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3), "::",
+        stringify!(format))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).numChannels) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3), "::",
+        stringify!(numChannels))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).sizeInBytes) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3), "::",
+        stringify!(sizeInBytes))
+    );
+}
+impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4 {
+    pub devPtr: CUdeviceptr,
+    pub format: CUarray_format,
+    pub numChannels: ::core::ffi::c_uint,
+    pub width: usize,
+    pub height: usize,
+    pub pitchInBytes: usize,
+}
+#[test]
+fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4 > (),
+        40usize, concat!("Size of: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4 > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).devPtr) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4), "::",
+        stringify!(devPtr))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).format) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4), "::",
+        stringify!(format))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).numChannels) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4), "::",
+        stringify!(numChannels))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).width) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4), "::",
+        stringify!(width))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).height) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4), "::",
+        stringify!(height))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).pitchInBytes) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4), "::",
+        stringify!(pitchInBytes))
+    );
+}
+impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5 {
+    pub reserved: [::core::ffi::c_int; 32usize],
+}
+#[test]
+fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5 > (),
+        128usize, concat!("Size of: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5 > (),
+        4usize, concat!("Alignment of ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5), "::",
+        stringify!(reserved))
+    );
+}
+#[test]
+fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_RESOURCE_DESC_st__bindgen_ty_1> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1 > (), 128usize,
+        concat!("Size of: ", stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_RESOURCE_DESC_st__bindgen_ty_1 > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).array) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1),
+        "::", stringify!(array))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).mipmap) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1),
+        "::", stringify!(mipmap))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).linear) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1),
+        "::", stringify!(linear))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).pitch2D) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1), "::", stringify!(pitch2D))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_RESOURCE_DESC_st__bindgen_ty_1), "::", stringify!(reserved))
+    );
+}
+impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUDA_RESOURCE_DESC_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_RESOURCE_DESC_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_RESOURCE_DESC_st > (), 144usize,
+        concat!("Size of: ", stringify!(CUDA_RESOURCE_DESC_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_RESOURCE_DESC_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_RESOURCE_DESC_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).resType) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUDA_RESOURCE_DESC_st), "::",
+        stringify!(resType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).res) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_RESOURCE_DESC_st), "::",
+        stringify!(res))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize },
+        136usize, concat!("Offset of field: ", stringify!(CUDA_RESOURCE_DESC_st), "::",
+        stringify!(flags))
+    );
+}
+impl Default for CUDA_RESOURCE_DESC_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_RESOURCE_DESC_v1 = CUDA_RESOURCE_DESC_st;
+pub type CUDA_RESOURCE_DESC = CUDA_RESOURCE_DESC_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct CUDA_TEXTURE_DESC_st {
+    pub addressMode: [CUaddress_mode; 3usize],
+    pub filterMode: CUfilter_mode,
+    pub flags: ::core::ffi::c_uint,
+    pub maxAnisotropy: ::core::ffi::c_uint,
+    pub mipmapFilterMode: CUfilter_mode,
+    pub mipmapLevelBias: f32,
+    pub minMipmapLevelClamp: f32,
+    pub maxMipmapLevelClamp: f32,
+    pub borderColor: [f32; 4usize],
+    pub reserved: [::core::ffi::c_int; 12usize],
+}
+#[test]
+fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_TEXTURE_DESC_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_TEXTURE_DESC_st > (), 104usize, concat!("Size of: ",
+        stringify!(CUDA_TEXTURE_DESC_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_TEXTURE_DESC_st > (), 4usize,
+        concat!("Alignment of ", stringify!(CUDA_TEXTURE_DESC_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).addressMode) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st), "::",
+        stringify!(addressMode))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).filterMode) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st), "::",
+        stringify!(filterMode))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st), "::",
+        stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).maxAnisotropy) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st), "::",
+        stringify!(maxAnisotropy))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).mipmapFilterMode) as usize - ptr as usize
+        }, 24usize, concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st), "::",
+        stringify!(mipmapFilterMode))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).mipmapLevelBias) as usize - ptr as usize
+        }, 28usize, concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st), "::",
+        stringify!(mipmapLevelBias))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).minMipmapLevelClamp) as usize - ptr as
+        usize }, 32usize, concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st),
+        "::", stringify!(minMipmapLevelClamp))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).maxMipmapLevelClamp) as usize - ptr as
+        usize }, 36usize, concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st),
+        "::", stringify!(maxMipmapLevelClamp))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).borderColor) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st), "::",
+        stringify!(borderColor))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        56usize, concat!("Offset of field: ", stringify!(CUDA_TEXTURE_DESC_st), "::",
+        stringify!(reserved))
+    );
+}
+impl Default for CUDA_TEXTURE_DESC_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_TEXTURE_DESC_v1 = CUDA_TEXTURE_DESC_st;
+pub type CUDA_TEXTURE_DESC = CUDA_TEXTURE_DESC_v1;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_NONE: CUresourceViewFormat_enum = 0;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UINT_1X8: CUresourceViewFormat_enum = 1;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UINT_2X8: CUresourceViewFormat_enum = 2;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UINT_4X8: CUresourceViewFormat_enum = 3;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SINT_1X8: CUresourceViewFormat_enum = 4;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SINT_2X8: CUresourceViewFormat_enum = 5;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SINT_4X8: CUresourceViewFormat_enum = 6;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UINT_1X16: CUresourceViewFormat_enum = 7;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UINT_2X16: CUresourceViewFormat_enum = 8;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UINT_4X16: CUresourceViewFormat_enum = 9;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SINT_1X16: CUresourceViewFormat_enum = 10;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SINT_2X16: CUresourceViewFormat_enum = 11;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SINT_4X16: CUresourceViewFormat_enum = 12;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UINT_1X32: CUresourceViewFormat_enum = 13;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UINT_2X32: CUresourceViewFormat_enum = 14;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UINT_4X32: CUresourceViewFormat_enum = 15;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SINT_1X32: CUresourceViewFormat_enum = 16;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SINT_2X32: CUresourceViewFormat_enum = 17;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SINT_4X32: CUresourceViewFormat_enum = 18;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_FLOAT_1X16: CUresourceViewFormat_enum = 19;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_FLOAT_2X16: CUresourceViewFormat_enum = 20;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_FLOAT_4X16: CUresourceViewFormat_enum = 21;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_FLOAT_1X32: CUresourceViewFormat_enum = 22;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_FLOAT_2X32: CUresourceViewFormat_enum = 23;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_FLOAT_4X32: CUresourceViewFormat_enum = 24;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UNSIGNED_BC1: CUresourceViewFormat_enum = 25;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UNSIGNED_BC2: CUresourceViewFormat_enum = 26;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UNSIGNED_BC3: CUresourceViewFormat_enum = 27;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UNSIGNED_BC4: CUresourceViewFormat_enum = 28;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SIGNED_BC4: CUresourceViewFormat_enum = 29;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UNSIGNED_BC5: CUresourceViewFormat_enum = 30;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SIGNED_BC5: CUresourceViewFormat_enum = 31;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UNSIGNED_BC6H: CUresourceViewFormat_enum = 32;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_SIGNED_BC6H: CUresourceViewFormat_enum = 33;
+pub const CUresourceViewFormat_enum_CU_RES_VIEW_FORMAT_UNSIGNED_BC7: CUresourceViewFormat_enum = 34;
+pub type CUresourceViewFormat_enum = ::core::ffi::c_uint;
+pub use self::CUresourceViewFormat_enum as CUresourceViewFormat;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_RESOURCE_VIEW_DESC_st {
+    pub format: CUresourceViewFormat,
+    pub width: usize,
+    pub height: usize,
+    pub depth: usize,
+    pub firstMipmapLevel: ::core::ffi::c_uint,
+    pub lastMipmapLevel: ::core::ffi::c_uint,
+    pub firstLayer: ::core::ffi::c_uint,
+    pub lastLayer: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
+}
+#[test]
+fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_RESOURCE_VIEW_DESC_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_RESOURCE_VIEW_DESC_st > (), 112usize,
+        concat!("Size of: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_RESOURCE_VIEW_DESC_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_RESOURCE_VIEW_DESC_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).format) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st), "::",
+        stringify!(format))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).width) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st), "::",
+        stringify!(width))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).height) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st),
+        "::", stringify!(height))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).depth) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st), "::",
+        stringify!(depth))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).firstMipmapLevel) as usize - ptr as usize
+        }, 32usize, concat!("Offset of field: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st),
+        "::", stringify!(firstMipmapLevel))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).lastMipmapLevel) as usize - ptr as usize
+        }, 36usize, concat!("Offset of field: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st),
+        "::", stringify!(lastMipmapLevel))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).firstLayer) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st),
+        "::", stringify!(firstLayer))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).lastLayer) as usize - ptr as usize },
+        44usize, concat!("Offset of field: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st),
+        "::", stringify!(lastLayer))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ", stringify!(CUDA_RESOURCE_VIEW_DESC_st),
+        "::", stringify!(reserved))
+    );
+}
+impl Default for CUDA_RESOURCE_VIEW_DESC_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_RESOURCE_VIEW_DESC_v1 = CUDA_RESOURCE_VIEW_DESC_st;
+pub type CUDA_RESOURCE_VIEW_DESC = CUDA_RESOURCE_VIEW_DESC_v1;
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUtensorMap_st {
+    pub opaque: [cuuint64_t; 16usize],
+}
+#[test]
+fn bindgen_test_layout_CUtensorMap_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUtensorMap_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUtensorMap_st > (), 128usize, concat!("Size of: ",
+        stringify!(CUtensorMap_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUtensorMap_st > (), 64usize, concat!("Alignment of ",
+        stringify!(CUtensorMap_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).opaque) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUtensorMap_st), "::",
+        stringify!(opaque))
+    );
+}
+impl Default for CUtensorMap_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUtensorMap = CUtensorMap_st;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_UINT8: CUtensorMapDataType_enum = 0;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_UINT16: CUtensorMapDataType_enum = 1;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_UINT32: CUtensorMapDataType_enum = 2;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_INT32: CUtensorMapDataType_enum = 3;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_UINT64: CUtensorMapDataType_enum = 4;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_INT64: CUtensorMapDataType_enum = 5;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_FLOAT16: CUtensorMapDataType_enum = 6;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_FLOAT32: CUtensorMapDataType_enum = 7;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_FLOAT64: CUtensorMapDataType_enum = 8;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_BFLOAT16: CUtensorMapDataType_enum = 9;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_FLOAT32_FTZ: CUtensorMapDataType_enum = 10;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_TFLOAT32: CUtensorMapDataType_enum = 11;
+pub const CUtensorMapDataType_enum_CU_TENSOR_MAP_DATA_TYPE_TFLOAT32_FTZ: CUtensorMapDataType_enum = 12;
+pub type CUtensorMapDataType_enum = ::core::ffi::c_uint;
+pub use self::CUtensorMapDataType_enum as CUtensorMapDataType;
+pub const CUtensorMapInterleave_enum_CU_TENSOR_MAP_INTERLEAVE_NONE: CUtensorMapInterleave_enum = 0;
+pub const CUtensorMapInterleave_enum_CU_TENSOR_MAP_INTERLEAVE_16B: CUtensorMapInterleave_enum = 1;
+pub const CUtensorMapInterleave_enum_CU_TENSOR_MAP_INTERLEAVE_32B: CUtensorMapInterleave_enum = 2;
+pub type CUtensorMapInterleave_enum = ::core::ffi::c_uint;
+pub use self::CUtensorMapInterleave_enum as CUtensorMapInterleave;
+pub const CUtensorMapSwizzle_enum_CU_TENSOR_MAP_SWIZZLE_NONE: CUtensorMapSwizzle_enum = 0;
+pub const CUtensorMapSwizzle_enum_CU_TENSOR_MAP_SWIZZLE_32B: CUtensorMapSwizzle_enum = 1;
+pub const CUtensorMapSwizzle_enum_CU_TENSOR_MAP_SWIZZLE_64B: CUtensorMapSwizzle_enum = 2;
+pub const CUtensorMapSwizzle_enum_CU_TENSOR_MAP_SWIZZLE_128B: CUtensorMapSwizzle_enum = 3;
+pub type CUtensorMapSwizzle_enum = ::core::ffi::c_uint;
+pub use self::CUtensorMapSwizzle_enum as CUtensorMapSwizzle;
+pub const CUtensorMapL2promotion_enum_CU_TENSOR_MAP_L2_PROMOTION_NONE: CUtensorMapL2promotion_enum = 0;
+pub const CUtensorMapL2promotion_enum_CU_TENSOR_MAP_L2_PROMOTION_L2_64B: CUtensorMapL2promotion_enum = 1;
+pub const CUtensorMapL2promotion_enum_CU_TENSOR_MAP_L2_PROMOTION_L2_128B: CUtensorMapL2promotion_enum = 2;
+pub const CUtensorMapL2promotion_enum_CU_TENSOR_MAP_L2_PROMOTION_L2_256B: CUtensorMapL2promotion_enum = 3;
+pub type CUtensorMapL2promotion_enum = ::core::ffi::c_uint;
+pub use self::CUtensorMapL2promotion_enum as CUtensorMapL2promotion;
+pub const CUtensorMapFloatOOBfill_enum_CU_TENSOR_MAP_FLOAT_OOB_FILL_NONE: CUtensorMapFloatOOBfill_enum = 0;
+pub const CUtensorMapFloatOOBfill_enum_CU_TENSOR_MAP_FLOAT_OOB_FILL_NAN_REQUEST_ZERO_FMA: CUtensorMapFloatOOBfill_enum = 1;
+pub type CUtensorMapFloatOOBfill_enum = ::core::ffi::c_uint;
+pub use self::CUtensorMapFloatOOBfill_enum as CUtensorMapFloatOOBfill;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st {
+    pub p2pToken: ::core::ffi::c_ulonglong,
+    pub vaSpaceToken: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st > (), 16usize,
+        concat!("Size of: ", stringify!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).p2pToken) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st), "::", stringify!(p2pToken))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).vaSpaceToken) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st), "::", stringify!(vaSpaceToken))
+    );
+}
+pub type CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_v1 = CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st;
+pub type CUDA_POINTER_ATTRIBUTE_P2P_TOKENS = CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_v1;
+pub const CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS_enum_CU_POINTER_ATTRIBUTE_ACCESS_FLAG_NONE: CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS_enum = 0;
+pub const CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS_enum_CU_POINTER_ATTRIBUTE_ACCESS_FLAG_READ: CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS_enum = 1;
+pub const CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS_enum_CU_POINTER_ATTRIBUTE_ACCESS_FLAG_READWRITE: CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS_enum = 3;
+pub type CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS_enum = ::core::ffi::c_uint;
+pub use self::CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS_enum as CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_LAUNCH_PARAMS_st {
+    pub function: CUfunction,
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
+    pub hStream: CUstream,
+    pub kernelParams: *mut *mut ::core::ffi::c_void,
+}
+#[test]
+fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_LAUNCH_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_LAUNCH_PARAMS_st > (), 56usize, concat!("Size of: ",
+        stringify!(CUDA_LAUNCH_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_LAUNCH_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_LAUNCH_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).function) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(function))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimX) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(gridDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimY) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(gridDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gridDimZ) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(gridDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimX) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(blockDimX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimY) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(blockDimY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).blockDimZ) as usize - ptr as usize },
+        28usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(blockDimZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).sharedMemBytes) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(sharedMemBytes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).hStream) as usize - ptr as usize },
+        40usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(hStream))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).kernelParams) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ", stringify!(CUDA_LAUNCH_PARAMS_st), "::",
+        stringify!(kernelParams))
+    );
+}
+impl Default for CUDA_LAUNCH_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_LAUNCH_PARAMS_v1 = CUDA_LAUNCH_PARAMS_st;
+pub type CUDA_LAUNCH_PARAMS = CUDA_LAUNCH_PARAMS_v1;
+pub const CUexternalMemoryHandleType_enum_CU_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD: CUexternalMemoryHandleType_enum = 1;
+pub const CUexternalMemoryHandleType_enum_CU_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32: CUexternalMemoryHandleType_enum = 2;
+pub const CUexternalMemoryHandleType_enum_CU_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KMT: CUexternalMemoryHandleType_enum = 3;
+pub const CUexternalMemoryHandleType_enum_CU_EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_HEAP: CUexternalMemoryHandleType_enum = 4;
+pub const CUexternalMemoryHandleType_enum_CU_EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_RESOURCE: CUexternalMemoryHandleType_enum = 5;
+pub const CUexternalMemoryHandleType_enum_CU_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_RESOURCE: CUexternalMemoryHandleType_enum = 6;
+pub const CUexternalMemoryHandleType_enum_CU_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_RESOURCE_KMT: CUexternalMemoryHandleType_enum = 7;
+pub const CUexternalMemoryHandleType_enum_CU_EXTERNAL_MEMORY_HANDLE_TYPE_NVSCIBUF: CUexternalMemoryHandleType_enum = 8;
+pub type CUexternalMemoryHandleType_enum = ::core::ffi::c_uint;
+pub use self::CUexternalMemoryHandleType_enum as CUexternalMemoryHandleType;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st {
+    pub type_: CUexternalMemoryHandleType,
+    pub handle: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1,
+    pub size: ::core::ffi::c_ulonglong,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1 {
+    pub fd: ::core::ffi::c_int,
+    pub win32: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+    pub nvSciBufObject: *const ::core::ffi::c_void,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
+    pub handle: *mut ::core::ffi::c_void,
+    pub name: *const ::core::ffi::c_void,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 > (), 16usize,
+        concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 > (), 8usize,
+        concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).handle) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1),
+        "::", stringify!(handle))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).name) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1),
+        "::", stringify!(name))
+    );
+}
+impl Default for CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1 > (),
+        16usize, concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1 > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).fd) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1), "::",
+        stringify!(fd))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).win32) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1), "::",
+        stringify!(win32))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).nvSciBufObject) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1), "::",
+        stringify!(nvSciBufObject))
+    );
+}
+impl Default for CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st > (), 104usize,
+        concat!("Size of: ", stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st),
+        "::", stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).handle) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st),
+        "::", stringify!(handle))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).size) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st),
+        "::", stringify!(size))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 32usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st),
+        "::", stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        36usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st), "::", stringify!(reserved))
+    );
+}
+impl Default for CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EXTERNAL_MEMORY_HANDLE_DESC_v1 = CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st;
+pub type CUDA_EXTERNAL_MEMORY_HANDLE_DESC = CUDA_EXTERNAL_MEMORY_HANDLE_DESC_v1;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st {
+    pub offset: ::core::ffi::c_ulonglong,
+    pub size: ::core::ffi::c_ulonglong,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st > (), 88usize,
+        concat!("Size of: ", stringify!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).offset) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st),
+        "::", stringify!(offset))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).size) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st),
+        "::", stringify!(size))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st),
+        "::", stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st), "::", stringify!(reserved))
+    );
+}
+pub type CUDA_EXTERNAL_MEMORY_BUFFER_DESC_v1 = CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st;
+pub type CUDA_EXTERNAL_MEMORY_BUFFER_DESC = CUDA_EXTERNAL_MEMORY_BUFFER_DESC_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st {
+    pub offset: ::core::ffi::c_ulonglong,
+    pub arrayDesc: CUDA_ARRAY3D_DESCRIPTOR,
+    pub numLevels: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st > (),
+        120usize, concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).offset) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st), "::",
+        stringify!(offset))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).arrayDesc) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st), "::",
+        stringify!(arrayDesc))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).numLevels) as usize - ptr as usize },
+        48usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st), "::",
+        stringify!(numLevels))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        52usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st), "::",
+        stringify!(reserved))
+    );
+}
+impl Default for CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_v1 = CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st;
+pub type CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC = CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_v1;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD: CUexternalSemaphoreHandleType_enum = 1;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32: CUexternalSemaphoreHandleType_enum = 2;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_KMT: CUexternalSemaphoreHandleType_enum = 3;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D12_FENCE: CUexternalSemaphoreHandleType_enum = 4;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D11_FENCE: CUexternalSemaphoreHandleType_enum = 5;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_NVSCISYNC: CUexternalSemaphoreHandleType_enum = 6;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D11_KEYED_MUTEX: CUexternalSemaphoreHandleType_enum = 7;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D11_KEYED_MUTEX_KMT: CUexternalSemaphoreHandleType_enum = 8;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_TIMELINE_SEMAPHORE_FD: CUexternalSemaphoreHandleType_enum = 9;
+pub const CUexternalSemaphoreHandleType_enum_CU_EXTERNAL_SEMAPHORE_HANDLE_TYPE_TIMELINE_SEMAPHORE_WIN32: CUexternalSemaphoreHandleType_enum = 10;
+pub type CUexternalSemaphoreHandleType_enum = ::core::ffi::c_uint;
+pub use self::CUexternalSemaphoreHandleType_enum as CUexternalSemaphoreHandleType;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st {
+    pub type_: CUexternalSemaphoreHandleType,
+    pub handle: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1 {
+    pub fd: ::core::ffi::c_int,
+    pub win32: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+    pub nvSciSyncObj: *const ::core::ffi::c_void,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
+    pub handle: *mut ::core::ffi::c_void,
+    pub name: *const ::core::ffi::c_void,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 > (), 16usize,
+        concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 > (), 8usize,
+        concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).handle) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1),
+        "::", stringify!(handle))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).name) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1),
+        "::", stringify!(name))
+    );
+}
+impl Default for CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1 >
+        (), 16usize, concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1 >
+        (), 8usize, concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).fd) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1), "::",
+        stringify!(fd))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).win32) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1), "::",
+        stringify!(win32))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).nvSciSyncObj) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1), "::",
+        stringify!(nvSciSyncObj))
+    );
+}
+impl Default for CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st > (), 96usize,
+        concat!("Size of: ", stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st),
+        "::", stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).handle) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st),
+        "::", stringify!(handle))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st),
+        "::", stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        28usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st), "::", stringify!(reserved))
+    );
+}
+impl Default for CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_v1 = CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st;
+pub type CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC = CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_v1;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st {
+    pub params: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1 {
+    pub fence: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
+    pub nvSciSync: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
+    pub keyedMutex: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
+    pub reserved: [::core::ffi::c_uint; 12usize],
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1 {
+    pub value: ::core::ffi::c_ulonglong,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1 > (),
+        8usize, concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1 > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).value) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1),
+        "::", stringify!(value))
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 {
+    pub fence: *mut ::core::ffi::c_void,
+    pub reserved: ::core::ffi::c_ulonglong,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 > (),
+        8usize, concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).fence) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2),
+        "::", stringify!(fence))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2),
+        "::", stringify!(reserved))
+    );
+}
+impl Default for CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3 {
+    pub key: ::core::ffi::c_ulonglong,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3 > (),
+        8usize, concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3 > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).key) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3),
+        "::", stringify!(key))
+    );
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1 >
+        (), 72usize, concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1 >
+        (), 8usize, concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).fence) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1), "::",
+        stringify!(fence))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).nvSciSync) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1), "::",
+        stringify!(nvSciSync))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).keyedMutex) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1), "::",
+        stringify!(keyedMutex))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1), "::",
+        stringify!(reserved))
+    );
+}
+impl Default for CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st > (), 144usize,
+        concat!("Size of: ", stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).params) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st), "::", stringify!(params))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 72usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st), "::", stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        76usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st), "::", stringify!(reserved))
+    );
+}
+impl Default for CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_v1 = CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st;
+pub type CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS = CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_v1;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st {
+    pub params: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1 {
+    pub fence: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
+    pub nvSciSync: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
+    pub keyedMutex: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
+    pub reserved: [::core::ffi::c_uint; 10usize],
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1 {
+    pub value: ::core::ffi::c_ulonglong,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1 > (), 8usize,
+        concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1 > (), 8usize,
+        concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).value) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1),
+        "::", stringify!(value))
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 {
+    pub fence: *mut ::core::ffi::c_void,
+    pub reserved: ::core::ffi::c_ulonglong,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 > (), 8usize,
+        concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 > (), 8usize,
+        concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).fence) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2),
+        "::", stringify!(fence))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2),
+        "::", stringify!(reserved))
+    );
+}
+impl Default for CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3 {
+    pub key: ::core::ffi::c_ulonglong,
+    pub timeoutMs: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3 > (), 16usize,
+        concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3))
+    );
+    assert_eq!(
+        ::core::mem::align_of::<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3 > (), 8usize,
+        concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).key) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3),
+        "::", stringify!(key))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).timeoutMs) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3),
+        "::", stringify!(timeoutMs))
+    );
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1 >
+        (), 72usize, concat!("Size of: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1 >
+        (), 8usize, concat!("Alignment of ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).fence) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1), "::",
+        stringify!(fence))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).nvSciSync) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1), "::",
+        stringify!(nvSciSync))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).keyedMutex) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1), "::",
+        stringify!(keyedMutex))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1), "::",
+        stringify!(reserved))
+    );
+}
+impl Default for CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st > (), 144usize,
+        concat!("Size of: ", stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).params) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st),
+        "::", stringify!(params))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 72usize,
+        concat!("Offset of field: ", stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st),
+        "::", stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        76usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st), "::", stringify!(reserved))
+    );
+}
+impl Default for CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_v1 = CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st;
+pub type CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS = CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st {
+    pub extSemArray: *mut CUexternalSemaphore,
+    pub paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS,
+    pub numExtSems: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st > (), 24usize,
+        concat!("Size of: ", stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extSemArray) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st), "::", stringify!(extSemArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).paramsArray) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st), "::", stringify!(paramsArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).numExtSems) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st), "::", stringify!(numExtSems))
+    );
+}
+impl Default for CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v1 = CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st;
+pub type CUDA_EXT_SEM_SIGNAL_NODE_PARAMS = CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st {
+    pub extSemArray: *mut CUexternalSemaphore,
+    pub paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS,
+    pub numExtSems: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st > (), 24usize,
+        concat!("Size of: ", stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extSemArray) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st), "::", stringify!(extSemArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).paramsArray) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st), "::", stringify!(paramsArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).numExtSems) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st), "::", stringify!(numExtSems))
+    );
+}
+impl Default for CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2 = CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXT_SEM_WAIT_NODE_PARAMS_st {
+    pub extSemArray: *mut CUexternalSemaphore,
+    pub paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS,
+    pub numExtSems: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXT_SEM_WAIT_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EXT_SEM_WAIT_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXT_SEM_WAIT_NODE_PARAMS_st > (), 24usize,
+        concat!("Size of: ", stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXT_SEM_WAIT_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extSemArray) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st), "::", stringify!(extSemArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).paramsArray) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st), "::", stringify!(paramsArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).numExtSems) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st), "::", stringify!(numExtSems))
+    );
+}
+impl Default for CUDA_EXT_SEM_WAIT_NODE_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EXT_SEM_WAIT_NODE_PARAMS_v1 = CUDA_EXT_SEM_WAIT_NODE_PARAMS_st;
+pub type CUDA_EXT_SEM_WAIT_NODE_PARAMS = CUDA_EXT_SEM_WAIT_NODE_PARAMS_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st {
+    pub extSemArray: *mut CUexternalSemaphore,
+    pub paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS,
+    pub numExtSems: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st > (), 24usize,
+        concat!("Size of: ", stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extSemArray) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st), "::", stringify!(extSemArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).paramsArray) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st), "::", stringify!(paramsArray))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).numExtSems) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st), "::", stringify!(numExtSems))
+    );
+}
+impl Default for CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2 = CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st;
+pub type CUmemGenericAllocationHandle_v1 = ::core::ffi::c_ulonglong;
+pub type CUmemGenericAllocationHandle = CUmemGenericAllocationHandle_v1;
+pub const CUmemAllocationHandleType_enum_CU_MEM_HANDLE_TYPE_NONE: CUmemAllocationHandleType_enum = 0;
+pub const CUmemAllocationHandleType_enum_CU_MEM_HANDLE_TYPE_POSIX_FILE_DESCRIPTOR: CUmemAllocationHandleType_enum = 1;
+pub const CUmemAllocationHandleType_enum_CU_MEM_HANDLE_TYPE_WIN32: CUmemAllocationHandleType_enum = 2;
+pub const CUmemAllocationHandleType_enum_CU_MEM_HANDLE_TYPE_WIN32_KMT: CUmemAllocationHandleType_enum = 4;
+pub const CUmemAllocationHandleType_enum_CU_MEM_HANDLE_TYPE_FABRIC: CUmemAllocationHandleType_enum = 8;
+pub const CUmemAllocationHandleType_enum_CU_MEM_HANDLE_TYPE_MAX: CUmemAllocationHandleType_enum = 2147483647;
+pub type CUmemAllocationHandleType_enum = ::core::ffi::c_uint;
+pub use self::CUmemAllocationHandleType_enum as CUmemAllocationHandleType;
+pub const CUmemAccess_flags_enum_CU_MEM_ACCESS_FLAGS_PROT_NONE: CUmemAccess_flags_enum = 0;
+pub const CUmemAccess_flags_enum_CU_MEM_ACCESS_FLAGS_PROT_READ: CUmemAccess_flags_enum = 1;
+pub const CUmemAccess_flags_enum_CU_MEM_ACCESS_FLAGS_PROT_READWRITE: CUmemAccess_flags_enum = 3;
+pub const CUmemAccess_flags_enum_CU_MEM_ACCESS_FLAGS_PROT_MAX: CUmemAccess_flags_enum = 2147483647;
+pub type CUmemAccess_flags_enum = ::core::ffi::c_uint;
+pub use self::CUmemAccess_flags_enum as CUmemAccess_flags;
+pub const CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_INVALID: CUmemLocationType_enum = 0;
+pub const CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_DEVICE: CUmemLocationType_enum = 1;
+pub const CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_HOST: CUmemLocationType_enum = 2;
+pub const CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_HOST_NUMA: CUmemLocationType_enum = 3;
+pub const CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_HOST_NUMA_CURRENT: CUmemLocationType_enum = 4;
+pub const CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_MAX: CUmemLocationType_enum = 2147483647;
+pub type CUmemLocationType_enum = ::core::ffi::c_uint;
+pub use self::CUmemLocationType_enum as CUmemLocationType;
+pub const CUmemAllocationType_enum_CU_MEM_ALLOCATION_TYPE_INVALID: CUmemAllocationType_enum = 0;
+pub const CUmemAllocationType_enum_CU_MEM_ALLOCATION_TYPE_PINNED: CUmemAllocationType_enum = 1;
+pub const CUmemAllocationType_enum_CU_MEM_ALLOCATION_TYPE_MAX: CUmemAllocationType_enum = 2147483647;
+pub type CUmemAllocationType_enum = ::core::ffi::c_uint;
+pub use self::CUmemAllocationType_enum as CUmemAllocationType;
+pub const CUmemAllocationGranularity_flags_enum_CU_MEM_ALLOC_GRANULARITY_MINIMUM: CUmemAllocationGranularity_flags_enum = 0;
+pub const CUmemAllocationGranularity_flags_enum_CU_MEM_ALLOC_GRANULARITY_RECOMMENDED: CUmemAllocationGranularity_flags_enum = 1;
+pub type CUmemAllocationGranularity_flags_enum = ::core::ffi::c_uint;
+pub use self::CUmemAllocationGranularity_flags_enum as CUmemAllocationGranularity_flags;
+pub const CUmemRangeHandleType_enum_CU_MEM_RANGE_HANDLE_TYPE_DMA_BUF_FD: CUmemRangeHandleType_enum = 1;
+pub const CUmemRangeHandleType_enum_CU_MEM_RANGE_HANDLE_TYPE_MAX: CUmemRangeHandleType_enum = 2147483647;
+pub type CUmemRangeHandleType_enum = ::core::ffi::c_uint;
+pub use self::CUmemRangeHandleType_enum as CUmemRangeHandleType;
+pub const CUarraySparseSubresourceType_enum_CU_ARRAY_SPARSE_SUBRESOURCE_TYPE_SPARSE_LEVEL: CUarraySparseSubresourceType_enum = 0;
+pub const CUarraySparseSubresourceType_enum_CU_ARRAY_SPARSE_SUBRESOURCE_TYPE_MIPTAIL: CUarraySparseSubresourceType_enum = 1;
+pub type CUarraySparseSubresourceType_enum = ::core::ffi::c_uint;
+pub use self::CUarraySparseSubresourceType_enum as CUarraySparseSubresourceType;
+pub const CUmemOperationType_enum_CU_MEM_OPERATION_TYPE_MAP: CUmemOperationType_enum = 1;
+pub const CUmemOperationType_enum_CU_MEM_OPERATION_TYPE_UNMAP: CUmemOperationType_enum = 2;
+pub type CUmemOperationType_enum = ::core::ffi::c_uint;
+pub use self::CUmemOperationType_enum as CUmemOperationType;
+pub const CUmemHandleType_enum_CU_MEM_HANDLE_TYPE_GENERIC: CUmemHandleType_enum = 0;
+pub type CUmemHandleType_enum = ::core::ffi::c_uint;
+pub use self::CUmemHandleType_enum as CUmemHandleType;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUarrayMapInfo_st {
+    pub resourceType: CUresourcetype,
+    pub resource: CUarrayMapInfo_st__bindgen_ty_1,
+    pub subresourceType: CUarraySparseSubresourceType,
+    pub subresource: CUarrayMapInfo_st__bindgen_ty_2,
+    pub memOperationType: CUmemOperationType,
+    pub memHandleType: CUmemHandleType,
+    pub memHandle: CUarrayMapInfo_st__bindgen_ty_3,
+    pub offset: ::core::ffi::c_ulonglong,
+    pub deviceBitMask: ::core::ffi::c_uint,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 2usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUarrayMapInfo_st__bindgen_ty_1 {
+    pub mipmap: CUmipmappedArray,
+    pub array: CUarray,
+}
+#[test]
+fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<CUarrayMapInfo_st__bindgen_ty_1> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUarrayMapInfo_st__bindgen_ty_1 > (), 8usize,
+        concat!("Size of: ", stringify!(CUarrayMapInfo_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUarrayMapInfo_st__bindgen_ty_1 > (), 8usize,
+        concat!("Alignment of ", stringify!(CUarrayMapInfo_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).mipmap) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUarrayMapInfo_st__bindgen_ty_1), "::",
+        stringify!(mipmap))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).array) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUarrayMapInfo_st__bindgen_ty_1), "::",
+        stringify!(array))
+    );
+}
+impl Default for CUarrayMapInfo_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUarrayMapInfo_st__bindgen_ty_2 {
+    pub sparseLevel: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1,
+    pub miptail: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1 {
+    pub level: ::core::ffi::c_uint,
+    pub layer: ::core::ffi::c_uint,
+    pub offsetX: ::core::ffi::c_uint,
+    pub offsetY: ::core::ffi::c_uint,
+    pub offsetZ: ::core::ffi::c_uint,
+    pub extentWidth: ::core::ffi::c_uint,
+    pub extentHeight: ::core::ffi::c_uint,
+    pub extentDepth: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1 > (),
+        32usize, concat!("Size of: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1 > (),
+        4usize, concat!("Alignment of ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).level) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1), "::",
+        stringify!(level))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).layer) as usize - ptr as usize }, 4usize,
+        concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1), "::",
+        stringify!(layer))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).offsetX) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1), "::",
+        stringify!(offsetX))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).offsetY) as usize - ptr as usize },
+        12usize, concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1), "::",
+        stringify!(offsetY))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).offsetZ) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1), "::",
+        stringify!(offsetZ))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extentWidth) as usize - ptr as usize },
+        20usize, concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1), "::",
+        stringify!(extentWidth))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extentHeight) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1), "::",
+        stringify!(extentHeight))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extentDepth) as usize - ptr as usize },
+        28usize, concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1), "::",
+        stringify!(extentDepth))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2 {
+    pub layer: ::core::ffi::c_uint,
+    pub offset: ::core::ffi::c_ulonglong,
+    pub size: ::core::ffi::c_ulonglong,
+}
+#[test]
+fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2() {
+    const UNINIT: ::core::mem::MaybeUninit<
+        CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2,
+    > = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2 > (),
+        24usize, concat!("Size of: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2 > (),
+        8usize, concat!("Alignment of ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).layer) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2), "::",
+        stringify!(layer))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).offset) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2), "::",
+        stringify!(offset))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).size) as usize - ptr as usize }, 16usize,
+        concat!("Offset of field: ",
+        stringify!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2), "::",
+        stringify!(size))
+    );
+}
+#[test]
+fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2() {
+    const UNINIT: ::core::mem::MaybeUninit<CUarrayMapInfo_st__bindgen_ty_2> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUarrayMapInfo_st__bindgen_ty_2 > (), 32usize,
+        concat!("Size of: ", stringify!(CUarrayMapInfo_st__bindgen_ty_2))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUarrayMapInfo_st__bindgen_ty_2 > (), 8usize,
+        concat!("Alignment of ", stringify!(CUarrayMapInfo_st__bindgen_ty_2))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).sparseLevel) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st__bindgen_ty_2),
+        "::", stringify!(sparseLevel))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).miptail) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st__bindgen_ty_2),
+        "::", stringify!(miptail))
+    );
+}
+impl Default for CUarrayMapInfo_st__bindgen_ty_2 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUarrayMapInfo_st__bindgen_ty_3 {
+    pub memHandle: CUmemGenericAllocationHandle,
+}
+#[test]
+fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_3() {
+    const UNINIT: ::core::mem::MaybeUninit<CUarrayMapInfo_st__bindgen_ty_3> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUarrayMapInfo_st__bindgen_ty_3 > (), 8usize,
+        concat!("Size of: ", stringify!(CUarrayMapInfo_st__bindgen_ty_3))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUarrayMapInfo_st__bindgen_ty_3 > (), 8usize,
+        concat!("Alignment of ", stringify!(CUarrayMapInfo_st__bindgen_ty_3))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memHandle) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st__bindgen_ty_3),
+        "::", stringify!(memHandle))
+    );
+}
+impl Default for CUarrayMapInfo_st__bindgen_ty_3 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUarrayMapInfo_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUarrayMapInfo_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUarrayMapInfo_st > (), 96usize, concat!("Size of: ",
+        stringify!(CUarrayMapInfo_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUarrayMapInfo_st > (), 8usize, concat!("Alignment of ",
+        stringify!(CUarrayMapInfo_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).resourceType) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(resourceType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).resource) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(resource))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).subresourceType) as usize - ptr as usize
+        }, 16usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(subresourceType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).subresource) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(subresource))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memOperationType) as usize - ptr as usize
+        }, 56usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(memOperationType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memHandleType) as usize - ptr as usize },
+        60usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(memHandleType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memHandle) as usize - ptr as usize },
+        64usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(memHandle))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).offset) as usize - ptr as usize },
+        72usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(offset))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).deviceBitMask) as usize - ptr as usize },
+        80usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(deviceBitMask))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 84usize,
+        concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(flags))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        88usize, concat!("Offset of field: ", stringify!(CUarrayMapInfo_st), "::",
+        stringify!(reserved))
+    );
+}
+impl Default for CUarrayMapInfo_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUarrayMapInfo_v1 = CUarrayMapInfo_st;
+pub type CUarrayMapInfo = CUarrayMapInfo_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUmemLocation_st {
+    pub type_: CUmemLocationType,
+    pub id: ::core::ffi::c_int,
+}
+#[test]
+fn bindgen_test_layout_CUmemLocation_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUmemLocation_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUmemLocation_st > (), 8usize, concat!("Size of: ",
+        stringify!(CUmemLocation_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUmemLocation_st > (), 4usize, concat!("Alignment of ",
+        stringify!(CUmemLocation_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUmemLocation_st), "::",
+        stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).id) as usize - ptr as usize }, 4usize,
+        concat!("Offset of field: ", stringify!(CUmemLocation_st), "::", stringify!(id))
+    );
+}
+impl Default for CUmemLocation_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUmemLocation_v1 = CUmemLocation_st;
+pub type CUmemLocation = CUmemLocation_v1;
+pub const CUmemAllocationCompType_enum_CU_MEM_ALLOCATION_COMP_NONE: CUmemAllocationCompType_enum = 0;
+pub const CUmemAllocationCompType_enum_CU_MEM_ALLOCATION_COMP_GENERIC: CUmemAllocationCompType_enum = 1;
+pub type CUmemAllocationCompType_enum = ::core::ffi::c_uint;
+pub use self::CUmemAllocationCompType_enum as CUmemAllocationCompType;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUmemAllocationProp_st {
+    pub type_: CUmemAllocationType,
+    pub requestedHandleTypes: CUmemAllocationHandleType,
+    pub location: CUmemLocation,
+    pub win32HandleMetaData: *mut ::core::ffi::c_void,
+    pub allocFlags: CUmemAllocationProp_st__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUmemAllocationProp_st__bindgen_ty_1 {
+    pub compressionType: ::core::ffi::c_uchar,
+    pub gpuDirectRDMACapable: ::core::ffi::c_uchar,
+    pub usage: ::core::ffi::c_ushort,
+    pub reserved: [::core::ffi::c_uchar; 4usize],
+}
+#[test]
+fn bindgen_test_layout_CUmemAllocationProp_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<CUmemAllocationProp_st__bindgen_ty_1> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUmemAllocationProp_st__bindgen_ty_1 > (), 8usize,
+        concat!("Size of: ", stringify!(CUmemAllocationProp_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUmemAllocationProp_st__bindgen_ty_1 > (), 2usize,
+        concat!("Alignment of ", stringify!(CUmemAllocationProp_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).compressionType) as usize - ptr as usize
+        }, 0usize, concat!("Offset of field: ",
+        stringify!(CUmemAllocationProp_st__bindgen_ty_1), "::",
+        stringify!(compressionType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).gpuDirectRDMACapable) as usize - ptr as
+        usize }, 1usize, concat!("Offset of field: ",
+        stringify!(CUmemAllocationProp_st__bindgen_ty_1), "::",
+        stringify!(gpuDirectRDMACapable))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).usage) as usize - ptr as usize }, 2usize,
+        concat!("Offset of field: ", stringify!(CUmemAllocationProp_st__bindgen_ty_1),
+        "::", stringify!(usage))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        4usize, concat!("Offset of field: ",
+        stringify!(CUmemAllocationProp_st__bindgen_ty_1), "::", stringify!(reserved))
+    );
+}
+#[test]
+fn bindgen_test_layout_CUmemAllocationProp_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUmemAllocationProp_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUmemAllocationProp_st > (), 32usize,
+        concat!("Size of: ", stringify!(CUmemAllocationProp_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUmemAllocationProp_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUmemAllocationProp_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUmemAllocationProp_st), "::",
+        stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).requestedHandleTypes) as usize - ptr as
+        usize }, 4usize, concat!("Offset of field: ", stringify!(CUmemAllocationProp_st),
+        "::", stringify!(requestedHandleTypes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).location) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUmemAllocationProp_st), "::",
+        stringify!(location))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).win32HandleMetaData) as usize - ptr as
+        usize }, 16usize, concat!("Offset of field: ",
+        stringify!(CUmemAllocationProp_st), "::", stringify!(win32HandleMetaData))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).allocFlags) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUmemAllocationProp_st), "::",
+        stringify!(allocFlags))
+    );
+}
+impl Default for CUmemAllocationProp_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUmemAllocationProp_v1 = CUmemAllocationProp_st;
+pub type CUmemAllocationProp = CUmemAllocationProp_v1;
+pub const CUmulticastGranularity_flags_enum_CU_MULTICAST_GRANULARITY_MINIMUM: CUmulticastGranularity_flags_enum = 0;
+pub const CUmulticastGranularity_flags_enum_CU_MULTICAST_GRANULARITY_RECOMMENDED: CUmulticastGranularity_flags_enum = 1;
+pub type CUmulticastGranularity_flags_enum = ::core::ffi::c_uint;
+pub use self::CUmulticastGranularity_flags_enum as CUmulticastGranularity_flags;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUmulticastObjectProp_st {
+    pub numDevices: ::core::ffi::c_uint,
+    pub size: usize,
+    pub handleTypes: ::core::ffi::c_ulonglong,
+    pub flags: ::core::ffi::c_ulonglong,
+}
+#[test]
+fn bindgen_test_layout_CUmulticastObjectProp_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUmulticastObjectProp_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUmulticastObjectProp_st > (), 32usize,
+        concat!("Size of: ", stringify!(CUmulticastObjectProp_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUmulticastObjectProp_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUmulticastObjectProp_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).numDevices) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUmulticastObjectProp_st), "::",
+        stringify!(numDevices))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).size) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUmulticastObjectProp_st), "::",
+        stringify!(size))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).handleTypes) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUmulticastObjectProp_st), "::",
+        stringify!(handleTypes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 24usize,
+        concat!("Offset of field: ", stringify!(CUmulticastObjectProp_st), "::",
+        stringify!(flags))
+    );
+}
+pub type CUmulticastObjectProp_v1 = CUmulticastObjectProp_st;
+pub type CUmulticastObjectProp = CUmulticastObjectProp_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUmemAccessDesc_st {
+    pub location: CUmemLocation,
+    pub flags: CUmemAccess_flags,
+}
+#[test]
+fn bindgen_test_layout_CUmemAccessDesc_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUmemAccessDesc_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUmemAccessDesc_st > (), 12usize, concat!("Size of: ",
+        stringify!(CUmemAccessDesc_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUmemAccessDesc_st > (), 4usize,
+        concat!("Alignment of ", stringify!(CUmemAccessDesc_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).location) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUmemAccessDesc_st), "::",
+        stringify!(location))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).flags) as usize - ptr as usize }, 8usize,
+        concat!("Offset of field: ", stringify!(CUmemAccessDesc_st), "::",
+        stringify!(flags))
+    );
+}
+impl Default for CUmemAccessDesc_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUmemAccessDesc_v1 = CUmemAccessDesc_st;
+pub type CUmemAccessDesc = CUmemAccessDesc_v1;
+pub const CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_SUCCESS: CUgraphExecUpdateResult_enum = 0;
+pub const CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR: CUgraphExecUpdateResult_enum = 1;
+pub const CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_TOPOLOGY_CHANGED: CUgraphExecUpdateResult_enum = 2;
+pub const CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_NODE_TYPE_CHANGED: CUgraphExecUpdateResult_enum = 3;
+pub const CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_FUNCTION_CHANGED: CUgraphExecUpdateResult_enum = 4;
+pub const CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_PARAMETERS_CHANGED: CUgraphExecUpdateResult_enum = 5;
+pub const CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_NOT_SUPPORTED: CUgraphExecUpdateResult_enum = 6;
+pub const CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_UNSUPPORTED_FUNCTION_CHANGE: CUgraphExecUpdateResult_enum = 7;
+pub const CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_ATTRIBUTES_CHANGED: CUgraphExecUpdateResult_enum = 8;
+pub type CUgraphExecUpdateResult_enum = ::core::ffi::c_uint;
+pub use self::CUgraphExecUpdateResult_enum as CUgraphExecUpdateResult;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUgraphExecUpdateResultInfo_st {
+    pub result: CUgraphExecUpdateResult,
+    pub errorNode: CUgraphNode,
+    pub errorFromNode: CUgraphNode,
+}
+#[test]
+fn bindgen_test_layout_CUgraphExecUpdateResultInfo_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUgraphExecUpdateResultInfo_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUgraphExecUpdateResultInfo_st > (), 24usize,
+        concat!("Size of: ", stringify!(CUgraphExecUpdateResultInfo_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUgraphExecUpdateResultInfo_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUgraphExecUpdateResultInfo_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).result) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphExecUpdateResultInfo_st), "::",
+        stringify!(result))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).errorNode) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUgraphExecUpdateResultInfo_st),
+        "::", stringify!(errorNode))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).errorFromNode) as usize - ptr as usize },
+        16usize, concat!("Offset of field: ", stringify!(CUgraphExecUpdateResultInfo_st),
+        "::", stringify!(errorFromNode))
+    );
+}
+impl Default for CUgraphExecUpdateResultInfo_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUgraphExecUpdateResultInfo_v1 = CUgraphExecUpdateResultInfo_st;
+pub type CUgraphExecUpdateResultInfo = CUgraphExecUpdateResultInfo_v1;
+pub const CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_REUSE_FOLLOW_EVENT_DEPENDENCIES: CUmemPool_attribute_enum = 1;
+pub const CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_REUSE_ALLOW_OPPORTUNISTIC: CUmemPool_attribute_enum = 2;
+pub const CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_REUSE_ALLOW_INTERNAL_DEPENDENCIES: CUmemPool_attribute_enum = 3;
+pub const CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_RELEASE_THRESHOLD: CUmemPool_attribute_enum = 4;
+pub const CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_RESERVED_MEM_CURRENT: CUmemPool_attribute_enum = 5;
+pub const CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_RESERVED_MEM_HIGH: CUmemPool_attribute_enum = 6;
+pub const CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_USED_MEM_CURRENT: CUmemPool_attribute_enum = 7;
+pub const CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_USED_MEM_HIGH: CUmemPool_attribute_enum = 8;
+pub type CUmemPool_attribute_enum = ::core::ffi::c_uint;
+pub use self::CUmemPool_attribute_enum as CUmemPool_attribute;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUmemPoolProps_st {
+    pub allocType: CUmemAllocationType,
+    pub handleTypes: CUmemAllocationHandleType,
+    pub location: CUmemLocation,
+    pub win32SecurityAttributes: *mut ::core::ffi::c_void,
+    pub maxSize: usize,
+    pub reserved: [::core::ffi::c_uchar; 56usize],
+}
+#[test]
+fn bindgen_test_layout_CUmemPoolProps_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUmemPoolProps_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUmemPoolProps_st > (), 88usize, concat!("Size of: ",
+        stringify!(CUmemPoolProps_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUmemPoolProps_st > (), 8usize, concat!("Alignment of ",
+        stringify!(CUmemPoolProps_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).allocType) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUmemPoolProps_st), "::",
+        stringify!(allocType))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).handleTypes) as usize - ptr as usize },
+        4usize, concat!("Offset of field: ", stringify!(CUmemPoolProps_st), "::",
+        stringify!(handleTypes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).location) as usize - ptr as usize },
+        8usize, concat!("Offset of field: ", stringify!(CUmemPoolProps_st), "::",
+        stringify!(location))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).win32SecurityAttributes) as usize - ptr as
+        usize }, 16usize, concat!("Offset of field: ", stringify!(CUmemPoolProps_st),
+        "::", stringify!(win32SecurityAttributes))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).maxSize) as usize - ptr as usize },
+        24usize, concat!("Offset of field: ", stringify!(CUmemPoolProps_st), "::",
+        stringify!(maxSize))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        32usize, concat!("Offset of field: ", stringify!(CUmemPoolProps_st), "::",
+        stringify!(reserved))
+    );
+}
+impl Default for CUmemPoolProps_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUmemPoolProps_v1 = CUmemPoolProps_st;
+pub type CUmemPoolProps = CUmemPoolProps_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUmemPoolPtrExportData_st {
+    pub reserved: [::core::ffi::c_uchar; 64usize],
+}
+#[test]
+fn bindgen_test_layout_CUmemPoolPtrExportData_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUmemPoolPtrExportData_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUmemPoolPtrExportData_st > (), 64usize,
+        concat!("Size of: ", stringify!(CUmemPoolPtrExportData_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUmemPoolPtrExportData_st > (), 1usize,
+        concat!("Alignment of ", stringify!(CUmemPoolPtrExportData_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUmemPoolPtrExportData_st), "::",
+        stringify!(reserved))
+    );
+}
+impl Default for CUmemPoolPtrExportData_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUmemPoolPtrExportData_v1 = CUmemPoolPtrExportData_st;
+pub type CUmemPoolPtrExportData = CUmemPoolPtrExportData_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_MEM_ALLOC_NODE_PARAMS_v1_st {
+    pub poolProps: CUmemPoolProps,
+    pub accessDescs: *const CUmemAccessDesc,
+    pub accessDescCount: usize,
+    pub bytesize: usize,
+    pub dptr: CUdeviceptr,
+}
+#[test]
+fn bindgen_test_layout_CUDA_MEM_ALLOC_NODE_PARAMS_v1_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_MEM_ALLOC_NODE_PARAMS_v1_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_MEM_ALLOC_NODE_PARAMS_v1_st > (), 120usize,
+        concat!("Size of: ", stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_MEM_ALLOC_NODE_PARAMS_v1_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).poolProps) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st), "::", stringify!(poolProps))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).accessDescs) as usize - ptr as usize },
+        88usize, concat!("Offset of field: ",
+        stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st), "::", stringify!(accessDescs))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).accessDescCount) as usize - ptr as usize
+        }, 96usize, concat!("Offset of field: ",
+        stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st), "::", stringify!(accessDescCount))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).bytesize) as usize - ptr as usize },
+        104usize, concat!("Offset of field: ",
+        stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st), "::", stringify!(bytesize))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dptr) as usize - ptr as usize }, 112usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st), "::",
+        stringify!(dptr))
+    );
+}
+impl Default for CUDA_MEM_ALLOC_NODE_PARAMS_v1_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_MEM_ALLOC_NODE_PARAMS_v1 = CUDA_MEM_ALLOC_NODE_PARAMS_v1_st;
+pub type CUDA_MEM_ALLOC_NODE_PARAMS = CUDA_MEM_ALLOC_NODE_PARAMS_v1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_MEM_ALLOC_NODE_PARAMS_v2_st {
+    pub poolProps: CUmemPoolProps,
+    pub accessDescs: *const CUmemAccessDesc,
+    pub accessDescCount: usize,
+    pub bytesize: usize,
+    pub dptr: CUdeviceptr,
+}
+#[test]
+fn bindgen_test_layout_CUDA_MEM_ALLOC_NODE_PARAMS_v2_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_MEM_ALLOC_NODE_PARAMS_v2_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_MEM_ALLOC_NODE_PARAMS_v2_st > (), 120usize,
+        concat!("Size of: ", stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_MEM_ALLOC_NODE_PARAMS_v2_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).poolProps) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st), "::", stringify!(poolProps))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).accessDescs) as usize - ptr as usize },
+        88usize, concat!("Offset of field: ",
+        stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st), "::", stringify!(accessDescs))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).accessDescCount) as usize - ptr as usize
+        }, 96usize, concat!("Offset of field: ",
+        stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st), "::", stringify!(accessDescCount))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).bytesize) as usize - ptr as usize },
+        104usize, concat!("Offset of field: ",
+        stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st), "::", stringify!(bytesize))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dptr) as usize - ptr as usize }, 112usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st), "::",
+        stringify!(dptr))
+    );
+}
+impl Default for CUDA_MEM_ALLOC_NODE_PARAMS_v2_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_MEM_ALLOC_NODE_PARAMS_v2 = CUDA_MEM_ALLOC_NODE_PARAMS_v2_st;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_MEM_FREE_NODE_PARAMS_st {
+    pub dptr: CUdeviceptr,
+}
+#[test]
+fn bindgen_test_layout_CUDA_MEM_FREE_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_MEM_FREE_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_MEM_FREE_NODE_PARAMS_st > (), 8usize,
+        concat!("Size of: ", stringify!(CUDA_MEM_FREE_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_MEM_FREE_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_MEM_FREE_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).dptr) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_MEM_FREE_NODE_PARAMS_st), "::",
+        stringify!(dptr))
+    );
+}
+pub type CUDA_MEM_FREE_NODE_PARAMS = CUDA_MEM_FREE_NODE_PARAMS_st;
+pub const CUgraphMem_attribute_enum_CU_GRAPH_MEM_ATTR_USED_MEM_CURRENT: CUgraphMem_attribute_enum = 0;
+pub const CUgraphMem_attribute_enum_CU_GRAPH_MEM_ATTR_USED_MEM_HIGH: CUgraphMem_attribute_enum = 1;
+pub const CUgraphMem_attribute_enum_CU_GRAPH_MEM_ATTR_RESERVED_MEM_CURRENT: CUgraphMem_attribute_enum = 2;
+pub const CUgraphMem_attribute_enum_CU_GRAPH_MEM_ATTR_RESERVED_MEM_HIGH: CUgraphMem_attribute_enum = 3;
+pub type CUgraphMem_attribute_enum = ::core::ffi::c_uint;
+pub use self::CUgraphMem_attribute_enum as CUgraphMem_attribute;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_CHILD_GRAPH_NODE_PARAMS_st {
+    pub graph: CUgraph,
+}
+#[test]
+fn bindgen_test_layout_CUDA_CHILD_GRAPH_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_CHILD_GRAPH_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_CHILD_GRAPH_NODE_PARAMS_st > (), 8usize,
+        concat!("Size of: ", stringify!(CUDA_CHILD_GRAPH_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_CHILD_GRAPH_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_CHILD_GRAPH_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).graph) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_CHILD_GRAPH_NODE_PARAMS_st), "::",
+        stringify!(graph))
+    );
+}
+impl Default for CUDA_CHILD_GRAPH_NODE_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_CHILD_GRAPH_NODE_PARAMS = CUDA_CHILD_GRAPH_NODE_PARAMS_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EVENT_RECORD_NODE_PARAMS_st {
+    pub event: CUevent,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EVENT_RECORD_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EVENT_RECORD_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EVENT_RECORD_NODE_PARAMS_st > (), 8usize,
+        concat!("Size of: ", stringify!(CUDA_EVENT_RECORD_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EVENT_RECORD_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EVENT_RECORD_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).event) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_EVENT_RECORD_NODE_PARAMS_st), "::",
+        stringify!(event))
+    );
+}
+impl Default for CUDA_EVENT_RECORD_NODE_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EVENT_RECORD_NODE_PARAMS = CUDA_EVENT_RECORD_NODE_PARAMS_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUDA_EVENT_WAIT_NODE_PARAMS_st {
+    pub event: CUevent,
+}
+#[test]
+fn bindgen_test_layout_CUDA_EVENT_WAIT_NODE_PARAMS_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUDA_EVENT_WAIT_NODE_PARAMS_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUDA_EVENT_WAIT_NODE_PARAMS_st > (), 8usize,
+        concat!("Size of: ", stringify!(CUDA_EVENT_WAIT_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUDA_EVENT_WAIT_NODE_PARAMS_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUDA_EVENT_WAIT_NODE_PARAMS_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).event) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUDA_EVENT_WAIT_NODE_PARAMS_st), "::",
+        stringify!(event))
+    );
+}
+impl Default for CUDA_EVENT_WAIT_NODE_PARAMS_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUDA_EVENT_WAIT_NODE_PARAMS = CUDA_EVENT_WAIT_NODE_PARAMS_st;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUgraphNodeParams_st {
+    pub type_: CUgraphNodeType,
+    pub reserved0: [::core::ffi::c_int; 3usize],
+    pub __bindgen_anon_1: CUgraphNodeParams_st__bindgen_ty_1,
+    pub reserved2: ::core::ffi::c_longlong,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUgraphNodeParams_st__bindgen_ty_1 {
+    pub reserved1: [::core::ffi::c_longlong; 29usize],
+    pub kernel: CUDA_KERNEL_NODE_PARAMS_v3,
+    pub memcpy: CUDA_MEMCPY_NODE_PARAMS,
+    pub memset: CUDA_MEMSET_NODE_PARAMS_v2,
+    pub host: CUDA_HOST_NODE_PARAMS_v2,
+    pub graph: CUDA_CHILD_GRAPH_NODE_PARAMS,
+    pub eventWait: CUDA_EVENT_WAIT_NODE_PARAMS,
+    pub eventRecord: CUDA_EVENT_RECORD_NODE_PARAMS,
+    pub extSemSignal: CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2,
+    pub extSemWait: CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2,
+    pub alloc: CUDA_MEM_ALLOC_NODE_PARAMS_v2,
+    pub free: CUDA_MEM_FREE_NODE_PARAMS,
+    pub memOp: CUDA_BATCH_MEM_OP_NODE_PARAMS_v2,
+    pub conditional: CUDA_CONDITIONAL_NODE_PARAMS,
+}
+#[test]
+fn bindgen_test_layout_CUgraphNodeParams_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<CUgraphNodeParams_st__bindgen_ty_1> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUgraphNodeParams_st__bindgen_ty_1 > (), 232usize,
+        concat!("Size of: ", stringify!(CUgraphNodeParams_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUgraphNodeParams_st__bindgen_ty_1 > (), 8usize,
+        concat!("Alignment of ", stringify!(CUgraphNodeParams_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved1) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUgraphNodeParams_st__bindgen_ty_1), "::", stringify!(reserved1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).kernel) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphNodeParams_st__bindgen_ty_1),
+        "::", stringify!(kernel))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memcpy) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphNodeParams_st__bindgen_ty_1),
+        "::", stringify!(memcpy))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memset) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphNodeParams_st__bindgen_ty_1),
+        "::", stringify!(memset))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).host) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphNodeParams_st__bindgen_ty_1),
+        "::", stringify!(host))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).graph) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphNodeParams_st__bindgen_ty_1),
+        "::", stringify!(graph))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).eventWait) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUgraphNodeParams_st__bindgen_ty_1), "::", stringify!(eventWait))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).eventRecord) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUgraphNodeParams_st__bindgen_ty_1), "::", stringify!(eventRecord))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extSemSignal) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUgraphNodeParams_st__bindgen_ty_1), "::", stringify!(extSemSignal))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).extSemWait) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUgraphNodeParams_st__bindgen_ty_1), "::", stringify!(extSemWait))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).alloc) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphNodeParams_st__bindgen_ty_1),
+        "::", stringify!(alloc))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).free) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphNodeParams_st__bindgen_ty_1),
+        "::", stringify!(free))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).memOp) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphNodeParams_st__bindgen_ty_1),
+        "::", stringify!(memOp))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).conditional) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ",
+        stringify!(CUgraphNodeParams_st__bindgen_ty_1), "::", stringify!(conditional))
+    );
+}
+impl Default for CUgraphNodeParams_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUgraphNodeParams_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUgraphNodeParams_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUgraphNodeParams_st > (), 256usize, concat!("Size of: ",
+        stringify!(CUgraphNodeParams_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUgraphNodeParams_st > (), 8usize,
+        concat!("Alignment of ", stringify!(CUgraphNodeParams_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUgraphNodeParams_st), "::",
+        stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved0) as usize - ptr as usize },
+        4usize, concat!("Offset of field: ", stringify!(CUgraphNodeParams_st), "::",
+        stringify!(reserved0))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).reserved2) as usize - ptr as usize },
+        248usize, concat!("Offset of field: ", stringify!(CUgraphNodeParams_st), "::",
+        stringify!(reserved2))
+    );
+}
+impl Default for CUgraphNodeParams_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUgraphNodeParams = CUgraphNodeParams_st;
+pub const CUflushGPUDirectRDMAWritesOptions_enum_CU_FLUSH_GPU_DIRECT_RDMA_WRITES_OPTION_HOST: CUflushGPUDirectRDMAWritesOptions_enum = 1;
+pub const CUflushGPUDirectRDMAWritesOptions_enum_CU_FLUSH_GPU_DIRECT_RDMA_WRITES_OPTION_MEMOPS: CUflushGPUDirectRDMAWritesOptions_enum = 2;
+pub type CUflushGPUDirectRDMAWritesOptions_enum = ::core::ffi::c_uint;
+pub use self::CUflushGPUDirectRDMAWritesOptions_enum as CUflushGPUDirectRDMAWritesOptions;
+pub const CUGPUDirectRDMAWritesOrdering_enum_CU_GPU_DIRECT_RDMA_WRITES_ORDERING_NONE: CUGPUDirectRDMAWritesOrdering_enum = 0;
+pub const CUGPUDirectRDMAWritesOrdering_enum_CU_GPU_DIRECT_RDMA_WRITES_ORDERING_OWNER: CUGPUDirectRDMAWritesOrdering_enum = 100;
+pub const CUGPUDirectRDMAWritesOrdering_enum_CU_GPU_DIRECT_RDMA_WRITES_ORDERING_ALL_DEVICES: CUGPUDirectRDMAWritesOrdering_enum = 200;
+pub type CUGPUDirectRDMAWritesOrdering_enum = ::core::ffi::c_uint;
+pub use self::CUGPUDirectRDMAWritesOrdering_enum as CUGPUDirectRDMAWritesOrdering;
+pub const CUflushGPUDirectRDMAWritesScope_enum_CU_FLUSH_GPU_DIRECT_RDMA_WRITES_TO_OWNER: CUflushGPUDirectRDMAWritesScope_enum = 100;
+pub const CUflushGPUDirectRDMAWritesScope_enum_CU_FLUSH_GPU_DIRECT_RDMA_WRITES_TO_ALL_DEVICES: CUflushGPUDirectRDMAWritesScope_enum = 200;
+pub type CUflushGPUDirectRDMAWritesScope_enum = ::core::ffi::c_uint;
+pub use self::CUflushGPUDirectRDMAWritesScope_enum as CUflushGPUDirectRDMAWritesScope;
+pub const CUflushGPUDirectRDMAWritesTarget_enum_CU_FLUSH_GPU_DIRECT_RDMA_WRITES_TARGET_CURRENT_CTX: CUflushGPUDirectRDMAWritesTarget_enum = 0;
+pub type CUflushGPUDirectRDMAWritesTarget_enum = ::core::ffi::c_uint;
+pub use self::CUflushGPUDirectRDMAWritesTarget_enum as CUflushGPUDirectRDMAWritesTarget;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_VERBOSE: CUgraphDebugDot_flags_enum = 1;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_RUNTIME_TYPES: CUgraphDebugDot_flags_enum = 2;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_KERNEL_NODE_PARAMS: CUgraphDebugDot_flags_enum = 4;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_MEMCPY_NODE_PARAMS: CUgraphDebugDot_flags_enum = 8;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_MEMSET_NODE_PARAMS: CUgraphDebugDot_flags_enum = 16;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_HOST_NODE_PARAMS: CUgraphDebugDot_flags_enum = 32;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_EVENT_NODE_PARAMS: CUgraphDebugDot_flags_enum = 64;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_EXT_SEMAS_SIGNAL_NODE_PARAMS: CUgraphDebugDot_flags_enum = 128;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_EXT_SEMAS_WAIT_NODE_PARAMS: CUgraphDebugDot_flags_enum = 256;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_KERNEL_NODE_ATTRIBUTES: CUgraphDebugDot_flags_enum = 512;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_HANDLES: CUgraphDebugDot_flags_enum = 1024;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_MEM_ALLOC_NODE_PARAMS: CUgraphDebugDot_flags_enum = 2048;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_MEM_FREE_NODE_PARAMS: CUgraphDebugDot_flags_enum = 4096;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_BATCH_MEM_OP_NODE_PARAMS: CUgraphDebugDot_flags_enum = 8192;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_EXTRA_TOPO_INFO: CUgraphDebugDot_flags_enum = 16384;
+pub const CUgraphDebugDot_flags_enum_CU_GRAPH_DEBUG_DOT_FLAGS_CONDITIONAL_NODE_PARAMS: CUgraphDebugDot_flags_enum = 32768;
+pub type CUgraphDebugDot_flags_enum = ::core::ffi::c_uint;
+pub use self::CUgraphDebugDot_flags_enum as CUgraphDebugDot_flags;
+pub const CUuserObject_flags_enum_CU_USER_OBJECT_NO_DESTRUCTOR_SYNC: CUuserObject_flags_enum = 1;
+pub type CUuserObject_flags_enum = ::core::ffi::c_uint;
+pub use self::CUuserObject_flags_enum as CUuserObject_flags;
+pub const CUuserObjectRetain_flags_enum_CU_GRAPH_USER_OBJECT_MOVE: CUuserObjectRetain_flags_enum = 1;
+pub type CUuserObjectRetain_flags_enum = ::core::ffi::c_uint;
+pub use self::CUuserObjectRetain_flags_enum as CUuserObjectRetain_flags;
+pub const CUgraphInstantiate_flags_enum_CUDA_GRAPH_INSTANTIATE_FLAG_AUTO_FREE_ON_LAUNCH: CUgraphInstantiate_flags_enum = 1;
+pub const CUgraphInstantiate_flags_enum_CUDA_GRAPH_INSTANTIATE_FLAG_UPLOAD: CUgraphInstantiate_flags_enum = 2;
+pub const CUgraphInstantiate_flags_enum_CUDA_GRAPH_INSTANTIATE_FLAG_DEVICE_LAUNCH: CUgraphInstantiate_flags_enum = 4;
+pub const CUgraphInstantiate_flags_enum_CUDA_GRAPH_INSTANTIATE_FLAG_USE_NODE_PRIORITY: CUgraphInstantiate_flags_enum = 8;
+pub type CUgraphInstantiate_flags_enum = ::core::ffi::c_uint;
+pub use self::CUgraphInstantiate_flags_enum as CUgraphInstantiate_flags;
+pub const CUdeviceNumaConfig_enum_CU_DEVICE_NUMA_CONFIG_NONE: CUdeviceNumaConfig_enum = 0;
+pub const CUdeviceNumaConfig_enum_CU_DEVICE_NUMA_CONFIG_NUMA_NODE: CUdeviceNumaConfig_enum = 1;
+pub type CUdeviceNumaConfig_enum = ::core::ffi::c_uint;
+pub use self::CUdeviceNumaConfig_enum as CUdeviceNumaConfig;
+pub const CUmoduleLoadingMode_enum_CU_MODULE_EAGER_LOADING: CUmoduleLoadingMode_enum = 1;
+pub const CUmoduleLoadingMode_enum_CU_MODULE_LAZY_LOADING: CUmoduleLoadingMode_enum = 2;
+pub type CUmoduleLoadingMode_enum = ::core::ffi::c_uint;
+pub use self::CUmoduleLoadingMode_enum as CUmoduleLoadingMode;
+pub const CUfunctionLoadingState_enum_CU_FUNCTION_LOADING_STATE_UNLOADED: CUfunctionLoadingState_enum = 0;
+pub const CUfunctionLoadingState_enum_CU_FUNCTION_LOADING_STATE_LOADED: CUfunctionLoadingState_enum = 1;
+pub const CUfunctionLoadingState_enum_CU_FUNCTION_LOADING_STATE_MAX: CUfunctionLoadingState_enum = 2;
+pub type CUfunctionLoadingState_enum = ::core::ffi::c_uint;
+pub use self::CUfunctionLoadingState_enum as CUfunctionLoadingState;
+pub const CUcoredumpSettings_enum_CU_COREDUMP_ENABLE_ON_EXCEPTION: CUcoredumpSettings_enum = 1;
+pub const CUcoredumpSettings_enum_CU_COREDUMP_TRIGGER_HOST: CUcoredumpSettings_enum = 2;
+pub const CUcoredumpSettings_enum_CU_COREDUMP_LIGHTWEIGHT: CUcoredumpSettings_enum = 3;
+pub const CUcoredumpSettings_enum_CU_COREDUMP_ENABLE_USER_TRIGGER: CUcoredumpSettings_enum = 4;
+pub const CUcoredumpSettings_enum_CU_COREDUMP_FILE: CUcoredumpSettings_enum = 5;
+pub const CUcoredumpSettings_enum_CU_COREDUMP_PIPE: CUcoredumpSettings_enum = 6;
+pub const CUcoredumpSettings_enum_CU_COREDUMP_MAX: CUcoredumpSettings_enum = 7;
+pub type CUcoredumpSettings_enum = ::core::ffi::c_uint;
+pub use self::CUcoredumpSettings_enum as CUcoredumpSettings;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUgreenCtx_st {
+    _unused: [u8; 0],
+}
+pub type CUgreenCtx = *mut CUgreenCtx_st;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CUdevResourceDesc_st {
+    _unused: [u8; 0],
+}
+pub type CUdevResourceDesc = *mut CUdevResourceDesc_st;
+pub const CUgreenCtxCreate_flags_CU_GREEN_CTX_DEFAULT_STREAM: CUgreenCtxCreate_flags = 1;
+pub type CUgreenCtxCreate_flags = ::core::ffi::c_uint;
+pub const CUdevResourceType_CU_DEV_RESOURCE_TYPE_INVALID: CUdevResourceType = 0;
+pub const CUdevResourceType_CU_DEV_RESOURCE_TYPE_SM: CUdevResourceType = 1;
+pub type CUdevResourceType = ::core::ffi::c_uint;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct CUdevSmResource_st {
+    pub smCount: ::core::ffi::c_uint,
+}
+#[test]
+fn bindgen_test_layout_CUdevSmResource_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUdevSmResource_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUdevSmResource_st > (), 4usize, concat!("Size of: ",
+        stringify!(CUdevSmResource_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUdevSmResource_st > (), 4usize,
+        concat!("Alignment of ", stringify!(CUdevSmResource_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).smCount) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUdevSmResource_st), "::",
+        stringify!(smCount))
+    );
+}
+pub type CUdevSmResource = CUdevSmResource_st;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CUdevResource_st {
+    pub type_: CUdevResourceType,
+    pub _internal_padding: [::core::ffi::c_uchar; 92usize],
+    pub __bindgen_anon_1: CUdevResource_st__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union CUdevResource_st__bindgen_ty_1 {
+    pub sm: CUdevSmResource,
+    pub _oversize: [::core::ffi::c_uchar; 48usize],
+}
+#[test]
+fn bindgen_test_layout_CUdevResource_st__bindgen_ty_1() {
+    const UNINIT: ::core::mem::MaybeUninit<CUdevResource_st__bindgen_ty_1> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUdevResource_st__bindgen_ty_1 > (), 48usize,
+        concat!("Size of: ", stringify!(CUdevResource_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUdevResource_st__bindgen_ty_1 > (), 4usize,
+        concat!("Alignment of ", stringify!(CUdevResource_st__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).sm) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUdevResource_st__bindgen_ty_1), "::",
+        stringify!(sm))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr)._oversize) as usize - ptr as usize },
+        0usize, concat!("Offset of field: ", stringify!(CUdevResource_st__bindgen_ty_1),
+        "::", stringify!(_oversize))
+    );
+}
+impl Default for CUdevResource_st__bindgen_ty_1 {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[test]
+fn bindgen_test_layout_CUdevResource_st() {
+    const UNINIT: ::core::mem::MaybeUninit<CUdevResource_st> = ::core::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::core::mem::size_of::< CUdevResource_st > (), 144usize, concat!("Size of: ",
+        stringify!(CUdevResource_st))
+    );
+    assert_eq!(
+        ::core::mem::align_of::< CUdevResource_st > (), 4usize, concat!("Alignment of ",
+        stringify!(CUdevResource_st))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr).type_) as usize - ptr as usize }, 0usize,
+        concat!("Offset of field: ", stringify!(CUdevResource_st), "::",
+        stringify!(type_))
+    );
+    assert_eq!(
+        unsafe { ::core::ptr::addr_of!((* ptr)._internal_padding) as usize - ptr as usize
+        }, 4usize, concat!("Offset of field: ", stringify!(CUdevResource_st), "::",
+        stringify!(_internal_padding))
+    );
+}
+impl Default for CUdevResource_st {
+    fn default() -> Self {
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+pub type CUdevResource_v1 = CUdevResource_st;
+pub type CUdevResource = CUdevResource_v1;
+pub const CUoutput_mode_enum_CU_OUT_KEY_VALUE_PAIR: CUoutput_mode_enum = 0;
+pub const CUoutput_mode_enum_CU_OUT_CSV: CUoutput_mode_enum = 1;
+pub type CUoutput_mode_enum = ::core::ffi::c_uint;
+pub use self::CUoutput_mode_enum as CUoutput_mode;
+extern "C" {
+    pub fn cuGetErrorString(
+        error: CUresult,
+        pStr: *mut *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGetErrorName(
+        error: CUresult,
+        pStr: *mut *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuInit(Flags: ::core::ffi::c_uint) -> CUresult;
+}
+extern "C" {
+    pub fn cuDriverGetVersion(driverVersion: *mut ::core::ffi::c_int) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGet(device: *mut CUdevice, ordinal: ::core::ffi::c_int) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetCount(count: *mut ::core::ffi::c_int) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetName(
+        name: *mut ::core::ffi::c_char,
+        len: ::core::ffi::c_int,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetUuid(uuid: *mut CUuuid, dev: CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetUuid_v2(uuid: *mut CUuuid, dev: CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetLuid(
+        luid: *mut ::core::ffi::c_char,
+        deviceNodeMask: *mut ::core::ffi::c_uint,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceTotalMem_v2(bytes: *mut usize, dev: CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetTexture1DLinearMaxWidth(
+        maxWidthInElements: *mut usize,
+        format: CUarray_format,
+        numChannels: ::core::ffi::c_uint,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetAttribute(
+        pi: *mut ::core::ffi::c_int,
+        attrib: CUdevice_attribute,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetNvSciSyncAttributes(
+        nvSciSyncAttrList: *mut ::core::ffi::c_void,
+        dev: CUdevice,
+        flags: ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceSetMemPool(dev: CUdevice, pool: CUmemoryPool) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetMemPool(pool: *mut CUmemoryPool, dev: CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetDefaultMemPool(
+        pool_out: *mut CUmemoryPool,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetExecAffinitySupport(
+        pi: *mut ::core::ffi::c_int,
+        type_: CUexecAffinityType,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFlushGPUDirectRDMAWrites(
+        target: CUflushGPUDirectRDMAWritesTarget,
+        scope: CUflushGPUDirectRDMAWritesScope,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetProperties(prop: *mut CUdevprop, dev: CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceComputeCapability(
+        major: *mut ::core::ffi::c_int,
+        minor: *mut ::core::ffi::c_int,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDevicePrimaryCtxRetain(pctx: *mut CUcontext, dev: CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuDevicePrimaryCtxRelease_v2(dev: CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuDevicePrimaryCtxSetFlags_v2(
+        dev: CUdevice,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDevicePrimaryCtxGetState(
+        dev: CUdevice,
+        flags: *mut ::core::ffi::c_uint,
+        active: *mut ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDevicePrimaryCtxReset_v2(dev: CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxCreate_v2(
+        pctx: *mut CUcontext,
+        flags: ::core::ffi::c_uint,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxCreate_v3(
+        pctx: *mut CUcontext,
+        paramsArray: *mut CUexecAffinityParam,
+        numParams: ::core::ffi::c_int,
+        flags: ::core::ffi::c_uint,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxDestroy_v2(ctx: CUcontext) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxPushCurrent_v2(ctx: CUcontext) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxPopCurrent_v2(pctx: *mut CUcontext) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxSetCurrent(ctx: CUcontext) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetCurrent(pctx: *mut CUcontext) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetDevice(device: *mut CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetFlags(flags: *mut ::core::ffi::c_uint) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxSetFlags(flags: ::core::ffi::c_uint) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetId(ctx: CUcontext, ctxId: *mut ::core::ffi::c_ulonglong) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxSynchronize() -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxSetLimit(limit: CUlimit, value: usize) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetLimit(pvalue: *mut usize, limit: CUlimit) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetCacheConfig(pconfig: *mut CUfunc_cache) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxSetCacheConfig(config: CUfunc_cache) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetApiVersion(
+        ctx: CUcontext,
+        version: *mut ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetStreamPriorityRange(
+        leastPriority: *mut ::core::ffi::c_int,
+        greatestPriority: *mut ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxResetPersistingL2Cache() -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetExecAffinity(
+        pExecAffinity: *mut CUexecAffinityParam,
+        type_: CUexecAffinityType,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxAttach(pctx: *mut CUcontext, flags: ::core::ffi::c_uint) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxDetach(ctx: CUcontext) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetSharedMemConfig(pConfig: *mut CUsharedconfig) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxSetSharedMemConfig(config: CUsharedconfig) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleLoad(
+        module: *mut CUmodule,
+        fname: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleLoadData(
+        module: *mut CUmodule,
+        image: *const ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleLoadDataEx(
+        module: *mut CUmodule,
+        image: *const ::core::ffi::c_void,
+        numOptions: ::core::ffi::c_uint,
+        options: *mut CUjit_option,
+        optionValues: *mut *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleLoadFatBinary(
+        module: *mut CUmodule,
+        fatCubin: *const ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleUnload(hmod: CUmodule) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleGetLoadingMode(mode: *mut CUmoduleLoadingMode) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleGetFunction(
+        hfunc: *mut CUfunction,
+        hmod: CUmodule,
+        name: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleGetFunctionCount(
+        count: *mut ::core::ffi::c_uint,
+        mod_: CUmodule,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleEnumerateFunctions(
+        functions: *mut CUfunction,
+        numFunctions: ::core::ffi::c_uint,
+        mod_: CUmodule,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleGetGlobal_v2(
+        dptr: *mut CUdeviceptr,
+        bytes: *mut usize,
+        hmod: CUmodule,
+        name: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLinkCreate_v2(
+        numOptions: ::core::ffi::c_uint,
+        options: *mut CUjit_option,
+        optionValues: *mut *mut ::core::ffi::c_void,
+        stateOut: *mut CUlinkState,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLinkAddData_v2(
+        state: CUlinkState,
+        type_: CUjitInputType,
+        data: *mut ::core::ffi::c_void,
+        size: usize,
+        name: *const ::core::ffi::c_char,
+        numOptions: ::core::ffi::c_uint,
+        options: *mut CUjit_option,
+        optionValues: *mut *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLinkAddFile_v2(
+        state: CUlinkState,
+        type_: CUjitInputType,
+        path: *const ::core::ffi::c_char,
+        numOptions: ::core::ffi::c_uint,
+        options: *mut CUjit_option,
+        optionValues: *mut *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLinkComplete(
+        state: CUlinkState,
+        cubinOut: *mut *mut ::core::ffi::c_void,
+        sizeOut: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLinkDestroy(state: CUlinkState) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleGetTexRef(
+        pTexRef: *mut CUtexref,
+        hmod: CUmodule,
+        name: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuModuleGetSurfRef(
+        pSurfRef: *mut CUsurfref,
+        hmod: CUmodule,
+        name: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryLoadData(
+        library: *mut CUlibrary,
+        code: *const ::core::ffi::c_void,
+        jitOptions: *mut CUjit_option,
+        jitOptionsValues: *mut *mut ::core::ffi::c_void,
+        numJitOptions: ::core::ffi::c_uint,
+        libraryOptions: *mut CUlibraryOption,
+        libraryOptionValues: *mut *mut ::core::ffi::c_void,
+        numLibraryOptions: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryLoadFromFile(
+        library: *mut CUlibrary,
+        fileName: *const ::core::ffi::c_char,
+        jitOptions: *mut CUjit_option,
+        jitOptionsValues: *mut *mut ::core::ffi::c_void,
+        numJitOptions: ::core::ffi::c_uint,
+        libraryOptions: *mut CUlibraryOption,
+        libraryOptionValues: *mut *mut ::core::ffi::c_void,
+        numLibraryOptions: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryUnload(library: CUlibrary) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryGetKernel(
+        pKernel: *mut CUkernel,
+        library: CUlibrary,
+        name: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryGetKernelCount(
+        count: *mut ::core::ffi::c_uint,
+        lib: CUlibrary,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryEnumerateKernels(
+        kernels: *mut CUkernel,
+        numKernels: ::core::ffi::c_uint,
+        lib: CUlibrary,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryGetModule(pMod: *mut CUmodule, library: CUlibrary) -> CUresult;
+}
+extern "C" {
+    pub fn cuKernelGetFunction(pFunc: *mut CUfunction, kernel: CUkernel) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryGetGlobal(
+        dptr: *mut CUdeviceptr,
+        bytes: *mut usize,
+        library: CUlibrary,
+        name: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryGetManaged(
+        dptr: *mut CUdeviceptr,
+        bytes: *mut usize,
+        library: CUlibrary,
+        name: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLibraryGetUnifiedFunction(
+        fptr: *mut *mut ::core::ffi::c_void,
+        library: CUlibrary,
+        symbol: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuKernelGetAttribute(
+        pi: *mut ::core::ffi::c_int,
+        attrib: CUfunction_attribute,
+        kernel: CUkernel,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuKernelSetAttribute(
+        attrib: CUfunction_attribute,
+        val: ::core::ffi::c_int,
+        kernel: CUkernel,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuKernelSetCacheConfig(
+        kernel: CUkernel,
+        config: CUfunc_cache,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuKernelGetName(
+        name: *mut *const ::core::ffi::c_char,
+        hfunc: CUkernel,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuKernelGetParamInfo(
+        kernel: CUkernel,
+        paramIndex: usize,
+        paramOffset: *mut usize,
+        paramSize: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemGetInfo_v2(free: *mut usize, total: *mut usize) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAlloc_v2(dptr: *mut CUdeviceptr, bytesize: usize) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAllocPitch_v2(
+        dptr: *mut CUdeviceptr,
+        pPitch: *mut usize,
+        WidthInBytes: usize,
+        Height: usize,
+        ElementSizeBytes: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemFree_v2(dptr: CUdeviceptr) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemGetAddressRange_v2(
+        pbase: *mut CUdeviceptr,
+        psize: *mut usize,
+        dptr: CUdeviceptr,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAllocHost_v2(
+        pp: *mut *mut ::core::ffi::c_void,
+        bytesize: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemFreeHost(p: *mut ::core::ffi::c_void) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemHostAlloc(
+        pp: *mut *mut ::core::ffi::c_void,
+        bytesize: usize,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemHostGetDevicePointer_v2(
+        pdptr: *mut CUdeviceptr,
+        p: *mut ::core::ffi::c_void,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemHostGetFlags(
+        pFlags: *mut ::core::ffi::c_uint,
+        p: *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAllocManaged(
+        dptr: *mut CUdeviceptr,
+        bytesize: usize,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceRegisterAsyncNotification(
+        device: CUdevice,
+        callbackFunc: CUasyncCallback,
+        userData: *mut ::core::ffi::c_void,
+        callback: *mut CUasyncCallbackHandle,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceUnregisterAsyncNotification(
+        device: CUdevice,
+        callback: CUasyncCallbackHandle,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetByPCIBusId(
+        dev: *mut CUdevice,
+        pciBusId: *const ::core::ffi::c_char,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetPCIBusId(
+        pciBusId: *mut ::core::ffi::c_char,
+        len: ::core::ffi::c_int,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuIpcGetEventHandle(
+        pHandle: *mut CUipcEventHandle,
+        event: CUevent,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuIpcOpenEventHandle(
+        phEvent: *mut CUevent,
+        handle: CUipcEventHandle,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuIpcGetMemHandle(
+        pHandle: *mut CUipcMemHandle,
+        dptr: CUdeviceptr,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuIpcOpenMemHandle_v2(
+        pdptr: *mut CUdeviceptr,
+        handle: CUipcMemHandle,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuIpcCloseMemHandle(dptr: CUdeviceptr) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemHostRegister_v2(
+        p: *mut ::core::ffi::c_void,
+        bytesize: usize,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemHostUnregister(p: *mut ::core::ffi::c_void) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpy(dst: CUdeviceptr, src: CUdeviceptr, ByteCount: usize) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyPeer(
+        dstDevice: CUdeviceptr,
+        dstContext: CUcontext,
+        srcDevice: CUdeviceptr,
+        srcContext: CUcontext,
+        ByteCount: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyHtoD_v2(
+        dstDevice: CUdeviceptr,
+        srcHost: *const ::core::ffi::c_void,
+        ByteCount: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyDtoH_v2(
+        dstHost: *mut ::core::ffi::c_void,
+        srcDevice: CUdeviceptr,
+        ByteCount: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyDtoD_v2(
+        dstDevice: CUdeviceptr,
+        srcDevice: CUdeviceptr,
+        ByteCount: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyDtoA_v2(
+        dstArray: CUarray,
+        dstOffset: usize,
+        srcDevice: CUdeviceptr,
+        ByteCount: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyAtoD_v2(
+        dstDevice: CUdeviceptr,
+        srcArray: CUarray,
+        srcOffset: usize,
+        ByteCount: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyHtoA_v2(
+        dstArray: CUarray,
+        dstOffset: usize,
+        srcHost: *const ::core::ffi::c_void,
+        ByteCount: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyAtoH_v2(
+        dstHost: *mut ::core::ffi::c_void,
+        srcArray: CUarray,
+        srcOffset: usize,
+        ByteCount: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyAtoA_v2(
+        dstArray: CUarray,
+        dstOffset: usize,
+        srcArray: CUarray,
+        srcOffset: usize,
+        ByteCount: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpy2D_v2(pCopy: *const CUDA_MEMCPY2D) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpy2DUnaligned_v2(pCopy: *const CUDA_MEMCPY2D) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpy3D_v2(pCopy: *const CUDA_MEMCPY3D) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpy3DPeer(pCopy: *const CUDA_MEMCPY3D_PEER) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyAsync(
+        dst: CUdeviceptr,
+        src: CUdeviceptr,
+        ByteCount: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyPeerAsync(
+        dstDevice: CUdeviceptr,
+        dstContext: CUcontext,
+        srcDevice: CUdeviceptr,
+        srcContext: CUcontext,
+        ByteCount: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyHtoDAsync_v2(
+        dstDevice: CUdeviceptr,
+        srcHost: *const ::core::ffi::c_void,
+        ByteCount: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyDtoHAsync_v2(
+        dstHost: *mut ::core::ffi::c_void,
+        srcDevice: CUdeviceptr,
+        ByteCount: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyDtoDAsync_v2(
+        dstDevice: CUdeviceptr,
+        srcDevice: CUdeviceptr,
+        ByteCount: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyHtoAAsync_v2(
+        dstArray: CUarray,
+        dstOffset: usize,
+        srcHost: *const ::core::ffi::c_void,
+        ByteCount: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpyAtoHAsync_v2(
+        dstHost: *mut ::core::ffi::c_void,
+        srcArray: CUarray,
+        srcOffset: usize,
+        ByteCount: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpy2DAsync_v2(
+        pCopy: *const CUDA_MEMCPY2D,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpy3DAsync_v2(
+        pCopy: *const CUDA_MEMCPY3D,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemcpy3DPeerAsync(
+        pCopy: *const CUDA_MEMCPY3D_PEER,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD8_v2(
+        dstDevice: CUdeviceptr,
+        uc: ::core::ffi::c_uchar,
+        N: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD16_v2(
+        dstDevice: CUdeviceptr,
+        us: ::core::ffi::c_ushort,
+        N: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD32_v2(
+        dstDevice: CUdeviceptr,
+        ui: ::core::ffi::c_uint,
+        N: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD2D8_v2(
+        dstDevice: CUdeviceptr,
+        dstPitch: usize,
+        uc: ::core::ffi::c_uchar,
+        Width: usize,
+        Height: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD2D16_v2(
+        dstDevice: CUdeviceptr,
+        dstPitch: usize,
+        us: ::core::ffi::c_ushort,
+        Width: usize,
+        Height: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD2D32_v2(
+        dstDevice: CUdeviceptr,
+        dstPitch: usize,
+        ui: ::core::ffi::c_uint,
+        Width: usize,
+        Height: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD8Async(
+        dstDevice: CUdeviceptr,
+        uc: ::core::ffi::c_uchar,
+        N: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD16Async(
+        dstDevice: CUdeviceptr,
+        us: ::core::ffi::c_ushort,
+        N: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD32Async(
+        dstDevice: CUdeviceptr,
+        ui: ::core::ffi::c_uint,
+        N: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD2D8Async(
+        dstDevice: CUdeviceptr,
+        dstPitch: usize,
+        uc: ::core::ffi::c_uchar,
+        Width: usize,
+        Height: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD2D16Async(
+        dstDevice: CUdeviceptr,
+        dstPitch: usize,
+        us: ::core::ffi::c_ushort,
+        Width: usize,
+        Height: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemsetD2D32Async(
+        dstDevice: CUdeviceptr,
+        dstPitch: usize,
+        ui: ::core::ffi::c_uint,
+        Width: usize,
+        Height: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuArrayCreate_v2(
+        pHandle: *mut CUarray,
+        pAllocateArray: *const CUDA_ARRAY_DESCRIPTOR,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuArrayGetDescriptor_v2(
+        pArrayDescriptor: *mut CUDA_ARRAY_DESCRIPTOR,
+        hArray: CUarray,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuArrayGetSparseProperties(
+        sparseProperties: *mut CUDA_ARRAY_SPARSE_PROPERTIES,
+        array: CUarray,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMipmappedArrayGetSparseProperties(
+        sparseProperties: *mut CUDA_ARRAY_SPARSE_PROPERTIES,
+        mipmap: CUmipmappedArray,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuArrayGetMemoryRequirements(
+        memoryRequirements: *mut CUDA_ARRAY_MEMORY_REQUIREMENTS,
+        array: CUarray,
+        device: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMipmappedArrayGetMemoryRequirements(
+        memoryRequirements: *mut CUDA_ARRAY_MEMORY_REQUIREMENTS,
+        mipmap: CUmipmappedArray,
+        device: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuArrayGetPlane(
+        pPlaneArray: *mut CUarray,
+        hArray: CUarray,
+        planeIdx: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuArrayDestroy(hArray: CUarray) -> CUresult;
+}
+extern "C" {
+    pub fn cuArray3DCreate_v2(
+        pHandle: *mut CUarray,
+        pAllocateArray: *const CUDA_ARRAY3D_DESCRIPTOR,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuArray3DGetDescriptor_v2(
+        pArrayDescriptor: *mut CUDA_ARRAY3D_DESCRIPTOR,
+        hArray: CUarray,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMipmappedArrayCreate(
+        pHandle: *mut CUmipmappedArray,
+        pMipmappedArrayDesc: *const CUDA_ARRAY3D_DESCRIPTOR,
+        numMipmapLevels: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMipmappedArrayGetLevel(
+        pLevelArray: *mut CUarray,
+        hMipmappedArray: CUmipmappedArray,
+        level: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMipmappedArrayDestroy(hMipmappedArray: CUmipmappedArray) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemGetHandleForAddressRange(
+        handle: *mut ::core::ffi::c_void,
+        dptr: CUdeviceptr,
+        size: usize,
+        handleType: CUmemRangeHandleType,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAddressReserve(
+        ptr: *mut CUdeviceptr,
+        size: usize,
+        alignment: usize,
+        addr: CUdeviceptr,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAddressFree(ptr: CUdeviceptr, size: usize) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemCreate(
+        handle: *mut CUmemGenericAllocationHandle,
+        size: usize,
+        prop: *const CUmemAllocationProp,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemRelease(handle: CUmemGenericAllocationHandle) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemMap(
+        ptr: CUdeviceptr,
+        size: usize,
+        offset: usize,
+        handle: CUmemGenericAllocationHandle,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemMapArrayAsync(
+        mapInfoList: *mut CUarrayMapInfo,
+        count: ::core::ffi::c_uint,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemUnmap(ptr: CUdeviceptr, size: usize) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemSetAccess(
+        ptr: CUdeviceptr,
+        size: usize,
+        desc: *const CUmemAccessDesc,
+        count: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemGetAccess(
+        flags: *mut ::core::ffi::c_ulonglong,
+        location: *const CUmemLocation,
+        ptr: CUdeviceptr,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemExportToShareableHandle(
+        shareableHandle: *mut ::core::ffi::c_void,
+        handle: CUmemGenericAllocationHandle,
+        handleType: CUmemAllocationHandleType,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemImportFromShareableHandle(
+        handle: *mut CUmemGenericAllocationHandle,
+        osHandle: *mut ::core::ffi::c_void,
+        shHandleType: CUmemAllocationHandleType,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemGetAllocationGranularity(
+        granularity: *mut usize,
+        prop: *const CUmemAllocationProp,
+        option: CUmemAllocationGranularity_flags,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemGetAllocationPropertiesFromHandle(
+        prop: *mut CUmemAllocationProp,
+        handle: CUmemGenericAllocationHandle,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemRetainAllocationHandle(
+        handle: *mut CUmemGenericAllocationHandle,
+        addr: *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemFreeAsync(dptr: CUdeviceptr, hStream: CUstream) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAllocAsync(
+        dptr: *mut CUdeviceptr,
+        bytesize: usize,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolTrimTo(pool: CUmemoryPool, minBytesToKeep: usize) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolSetAttribute(
+        pool: CUmemoryPool,
+        attr: CUmemPool_attribute,
+        value: *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolGetAttribute(
+        pool: CUmemoryPool,
+        attr: CUmemPool_attribute,
+        value: *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolSetAccess(
+        pool: CUmemoryPool,
+        map: *const CUmemAccessDesc,
+        count: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolGetAccess(
+        flags: *mut CUmemAccess_flags,
+        memPool: CUmemoryPool,
+        location: *mut CUmemLocation,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolCreate(
+        pool: *mut CUmemoryPool,
+        poolProps: *const CUmemPoolProps,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolDestroy(pool: CUmemoryPool) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAllocFromPoolAsync(
+        dptr: *mut CUdeviceptr,
+        bytesize: usize,
+        pool: CUmemoryPool,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolExportToShareableHandle(
+        handle_out: *mut ::core::ffi::c_void,
+        pool: CUmemoryPool,
+        handleType: CUmemAllocationHandleType,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolImportFromShareableHandle(
+        pool_out: *mut CUmemoryPool,
+        handle: *mut ::core::ffi::c_void,
+        handleType: CUmemAllocationHandleType,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolExportPointer(
+        shareData_out: *mut CUmemPoolPtrExportData,
+        ptr: CUdeviceptr,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPoolImportPointer(
+        ptr_out: *mut CUdeviceptr,
+        pool: CUmemoryPool,
+        shareData: *mut CUmemPoolPtrExportData,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMulticastCreate(
+        mcHandle: *mut CUmemGenericAllocationHandle,
+        prop: *const CUmulticastObjectProp,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMulticastAddDevice(
+        mcHandle: CUmemGenericAllocationHandle,
+        dev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMulticastBindMem(
+        mcHandle: CUmemGenericAllocationHandle,
+        mcOffset: usize,
+        memHandle: CUmemGenericAllocationHandle,
+        memOffset: usize,
+        size: usize,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMulticastBindAddr(
+        mcHandle: CUmemGenericAllocationHandle,
+        mcOffset: usize,
+        memptr: CUdeviceptr,
+        size: usize,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMulticastUnbind(
+        mcHandle: CUmemGenericAllocationHandle,
+        dev: CUdevice,
+        mcOffset: usize,
+        size: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMulticastGetGranularity(
+        granularity: *mut usize,
+        prop: *const CUmulticastObjectProp,
+        option: CUmulticastGranularity_flags,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuPointerGetAttribute(
+        data: *mut ::core::ffi::c_void,
+        attribute: CUpointer_attribute,
+        ptr: CUdeviceptr,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPrefetchAsync(
+        devPtr: CUdeviceptr,
+        count: usize,
+        dstDevice: CUdevice,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemPrefetchAsync_v2(
+        devPtr: CUdeviceptr,
+        count: usize,
+        location: CUmemLocation,
+        flags: ::core::ffi::c_uint,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAdvise(
+        devPtr: CUdeviceptr,
+        count: usize,
+        advice: CUmem_advise,
+        device: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemAdvise_v2(
+        devPtr: CUdeviceptr,
+        count: usize,
+        advice: CUmem_advise,
+        location: CUmemLocation,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemRangeGetAttribute(
+        data: *mut ::core::ffi::c_void,
+        dataSize: usize,
+        attribute: CUmem_range_attribute,
+        devPtr: CUdeviceptr,
+        count: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuMemRangeGetAttributes(
+        data: *mut *mut ::core::ffi::c_void,
+        dataSizes: *mut usize,
+        attributes: *mut CUmem_range_attribute,
+        numAttributes: usize,
+        devPtr: CUdeviceptr,
+        count: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuPointerSetAttribute(
+        value: *const ::core::ffi::c_void,
+        attribute: CUpointer_attribute,
+        ptr: CUdeviceptr,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuPointerGetAttributes(
+        numAttributes: ::core::ffi::c_uint,
+        attributes: *mut CUpointer_attribute,
+        data: *mut *mut ::core::ffi::c_void,
+        ptr: CUdeviceptr,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamCreate(
+        phStream: *mut CUstream,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamCreateWithPriority(
+        phStream: *mut CUstream,
+        flags: ::core::ffi::c_uint,
+        priority: ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamGetPriority(
+        hStream: CUstream,
+        priority: *mut ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamGetFlags(
+        hStream: CUstream,
+        flags: *mut ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamGetId(
+        hStream: CUstream,
+        streamId: *mut ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamGetCtx(hStream: CUstream, pctx: *mut CUcontext) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamWaitEvent(
+        hStream: CUstream,
+        hEvent: CUevent,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamAddCallback(
+        hStream: CUstream,
+        callback: CUstreamCallback,
+        userData: *mut ::core::ffi::c_void,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamBeginCapture_v2(
+        hStream: CUstream,
+        mode: CUstreamCaptureMode,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamBeginCaptureToGraph(
+        hStream: CUstream,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        dependencyData: *const CUgraphEdgeData,
+        numDependencies: usize,
+        mode: CUstreamCaptureMode,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuThreadExchangeStreamCaptureMode(mode: *mut CUstreamCaptureMode) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamEndCapture(hStream: CUstream, phGraph: *mut CUgraph) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamIsCapturing(
+        hStream: CUstream,
+        captureStatus: *mut CUstreamCaptureStatus,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamGetCaptureInfo_v2(
+        hStream: CUstream,
+        captureStatus_out: *mut CUstreamCaptureStatus,
+        id_out: *mut cuuint64_t,
+        graph_out: *mut CUgraph,
+        dependencies_out: *mut *const CUgraphNode,
+        numDependencies_out: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamGetCaptureInfo_v3(
+        hStream: CUstream,
+        captureStatus_out: *mut CUstreamCaptureStatus,
+        id_out: *mut cuuint64_t,
+        graph_out: *mut CUgraph,
+        dependencies_out: *mut *const CUgraphNode,
+        edgeData_out: *mut *const CUgraphEdgeData,
+        numDependencies_out: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamUpdateCaptureDependencies(
+        hStream: CUstream,
+        dependencies: *mut CUgraphNode,
+        numDependencies: usize,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamUpdateCaptureDependencies_v2(
+        hStream: CUstream,
+        dependencies: *mut CUgraphNode,
+        dependencyData: *const CUgraphEdgeData,
+        numDependencies: usize,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamAttachMemAsync(
+        hStream: CUstream,
+        dptr: CUdeviceptr,
+        length: usize,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamQuery(hStream: CUstream) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamSynchronize(hStream: CUstream) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamDestroy_v2(hStream: CUstream) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamCopyAttributes(dst: CUstream, src: CUstream) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamGetAttribute(
+        hStream: CUstream,
+        attr: CUstreamAttrID,
+        value_out: *mut CUstreamAttrValue,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamSetAttribute(
+        hStream: CUstream,
+        attr: CUstreamAttrID,
+        value: *const CUstreamAttrValue,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuEventCreate(phEvent: *mut CUevent, Flags: ::core::ffi::c_uint) -> CUresult;
+}
+extern "C" {
+    pub fn cuEventRecord(hEvent: CUevent, hStream: CUstream) -> CUresult;
+}
+extern "C" {
+    pub fn cuEventRecordWithFlags(
+        hEvent: CUevent,
+        hStream: CUstream,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuEventQuery(hEvent: CUevent) -> CUresult;
+}
+extern "C" {
+    pub fn cuEventSynchronize(hEvent: CUevent) -> CUresult;
+}
+extern "C" {
+    pub fn cuEventDestroy_v2(hEvent: CUevent) -> CUresult;
+}
+extern "C" {
+    pub fn cuEventElapsedTime(
+        pMilliseconds: *mut f32,
+        hStart: CUevent,
+        hEnd: CUevent,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuImportExternalMemory(
+        extMem_out: *mut CUexternalMemory,
+        memHandleDesc: *const CUDA_EXTERNAL_MEMORY_HANDLE_DESC,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuExternalMemoryGetMappedBuffer(
+        devPtr: *mut CUdeviceptr,
+        extMem: CUexternalMemory,
+        bufferDesc: *const CUDA_EXTERNAL_MEMORY_BUFFER_DESC,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuExternalMemoryGetMappedMipmappedArray(
+        mipmap: *mut CUmipmappedArray,
+        extMem: CUexternalMemory,
+        mipmapDesc: *const CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDestroyExternalMemory(extMem: CUexternalMemory) -> CUresult;
+}
+extern "C" {
+    pub fn cuImportExternalSemaphore(
+        extSem_out: *mut CUexternalSemaphore,
+        semHandleDesc: *const CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuSignalExternalSemaphoresAsync(
+        extSemArray: *const CUexternalSemaphore,
+        paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS,
+        numExtSems: ::core::ffi::c_uint,
+        stream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuWaitExternalSemaphoresAsync(
+        extSemArray: *const CUexternalSemaphore,
+        paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS,
+        numExtSems: ::core::ffi::c_uint,
+        stream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDestroyExternalSemaphore(extSem: CUexternalSemaphore) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamWaitValue32_v2(
+        stream: CUstream,
+        addr: CUdeviceptr,
+        value: cuuint32_t,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamWaitValue64_v2(
+        stream: CUstream,
+        addr: CUdeviceptr,
+        value: cuuint64_t,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamWriteValue32_v2(
+        stream: CUstream,
+        addr: CUdeviceptr,
+        value: cuuint32_t,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamWriteValue64_v2(
+        stream: CUstream,
+        addr: CUdeviceptr,
+        value: cuuint64_t,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamBatchMemOp_v2(
+        stream: CUstream,
+        count: ::core::ffi::c_uint,
+        paramArray: *mut CUstreamBatchMemOpParams,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncGetAttribute(
+        pi: *mut ::core::ffi::c_int,
+        attrib: CUfunction_attribute,
+        hfunc: CUfunction,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncSetAttribute(
+        hfunc: CUfunction,
+        attrib: CUfunction_attribute,
+        value: ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncSetCacheConfig(hfunc: CUfunction, config: CUfunc_cache) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncGetModule(hmod: *mut CUmodule, hfunc: CUfunction) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncGetName(
+        name: *mut *const ::core::ffi::c_char,
+        hfunc: CUfunction,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncGetParamInfo(
+        func: CUfunction,
+        paramIndex: usize,
+        paramOffset: *mut usize,
+        paramSize: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncIsLoaded(
+        state: *mut CUfunctionLoadingState,
+        function: CUfunction,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncLoad(function: CUfunction) -> CUresult;
+}
+extern "C" {
+    pub fn cuLaunchKernel(
+        f: CUfunction,
+        gridDimX: ::core::ffi::c_uint,
+        gridDimY: ::core::ffi::c_uint,
+        gridDimZ: ::core::ffi::c_uint,
+        blockDimX: ::core::ffi::c_uint,
+        blockDimY: ::core::ffi::c_uint,
+        blockDimZ: ::core::ffi::c_uint,
+        sharedMemBytes: ::core::ffi::c_uint,
+        hStream: CUstream,
+        kernelParams: *mut *mut ::core::ffi::c_void,
+        extra: *mut *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLaunchKernelEx(
+        config: *const CUlaunchConfig,
+        f: CUfunction,
+        kernelParams: *mut *mut ::core::ffi::c_void,
+        extra: *mut *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLaunchCooperativeKernel(
+        f: CUfunction,
+        gridDimX: ::core::ffi::c_uint,
+        gridDimY: ::core::ffi::c_uint,
+        gridDimZ: ::core::ffi::c_uint,
+        blockDimX: ::core::ffi::c_uint,
+        blockDimY: ::core::ffi::c_uint,
+        blockDimZ: ::core::ffi::c_uint,
+        sharedMemBytes: ::core::ffi::c_uint,
+        hStream: CUstream,
+        kernelParams: *mut *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLaunchCooperativeKernelMultiDevice(
+        launchParamsList: *mut CUDA_LAUNCH_PARAMS,
+        numDevices: ::core::ffi::c_uint,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLaunchHostFunc(
+        hStream: CUstream,
+        fn_: CUhostFn,
+        userData: *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncSetBlockShape(
+        hfunc: CUfunction,
+        x: ::core::ffi::c_int,
+        y: ::core::ffi::c_int,
+        z: ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncSetSharedSize(
+        hfunc: CUfunction,
+        bytes: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuParamSetSize(hfunc: CUfunction, numbytes: ::core::ffi::c_uint) -> CUresult;
+}
+extern "C" {
+    pub fn cuParamSeti(
+        hfunc: CUfunction,
+        offset: ::core::ffi::c_int,
+        value: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuParamSetf(
+        hfunc: CUfunction,
+        offset: ::core::ffi::c_int,
+        value: f32,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuParamSetv(
+        hfunc: CUfunction,
+        offset: ::core::ffi::c_int,
+        ptr: *mut ::core::ffi::c_void,
+        numbytes: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLaunch(f: CUfunction) -> CUresult;
+}
+extern "C" {
+    pub fn cuLaunchGrid(
+        f: CUfunction,
+        grid_width: ::core::ffi::c_int,
+        grid_height: ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuLaunchGridAsync(
+        f: CUfunction,
+        grid_width: ::core::ffi::c_int,
+        grid_height: ::core::ffi::c_int,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuParamSetTexRef(
+        hfunc: CUfunction,
+        texunit: ::core::ffi::c_int,
+        hTexRef: CUtexref,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuFuncSetSharedMemConfig(
+        hfunc: CUfunction,
+        config: CUsharedconfig,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphCreate(phGraph: *mut CUgraph, flags: ::core::ffi::c_uint) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddKernelNode_v2(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        nodeParams: *const CUDA_KERNEL_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphKernelNodeGetParams_v2(
+        hNode: CUgraphNode,
+        nodeParams: *mut CUDA_KERNEL_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphKernelNodeSetParams_v2(
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_KERNEL_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddMemcpyNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        copyParams: *const CUDA_MEMCPY3D,
+        ctx: CUcontext,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphMemcpyNodeGetParams(
+        hNode: CUgraphNode,
+        nodeParams: *mut CUDA_MEMCPY3D,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphMemcpyNodeSetParams(
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_MEMCPY3D,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddMemsetNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        memsetParams: *const CUDA_MEMSET_NODE_PARAMS,
+        ctx: CUcontext,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphMemsetNodeGetParams(
+        hNode: CUgraphNode,
+        nodeParams: *mut CUDA_MEMSET_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphMemsetNodeSetParams(
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_MEMSET_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddHostNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        nodeParams: *const CUDA_HOST_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphHostNodeGetParams(
+        hNode: CUgraphNode,
+        nodeParams: *mut CUDA_HOST_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphHostNodeSetParams(
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_HOST_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddChildGraphNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        childGraph: CUgraph,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphChildGraphNodeGetGraph(
+        hNode: CUgraphNode,
+        phGraph: *mut CUgraph,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddEmptyNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddEventRecordNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        event: CUevent,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphEventRecordNodeGetEvent(
+        hNode: CUgraphNode,
+        event_out: *mut CUevent,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphEventRecordNodeSetEvent(
+        hNode: CUgraphNode,
+        event: CUevent,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddEventWaitNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        event: CUevent,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphEventWaitNodeGetEvent(
+        hNode: CUgraphNode,
+        event_out: *mut CUevent,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphEventWaitNodeSetEvent(hNode: CUgraphNode, event: CUevent) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddExternalSemaphoresSignalNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        nodeParams: *const CUDA_EXT_SEM_SIGNAL_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExternalSemaphoresSignalNodeGetParams(
+        hNode: CUgraphNode,
+        params_out: *mut CUDA_EXT_SEM_SIGNAL_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExternalSemaphoresSignalNodeSetParams(
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_EXT_SEM_SIGNAL_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddExternalSemaphoresWaitNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        nodeParams: *const CUDA_EXT_SEM_WAIT_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExternalSemaphoresWaitNodeGetParams(
+        hNode: CUgraphNode,
+        params_out: *mut CUDA_EXT_SEM_WAIT_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExternalSemaphoresWaitNodeSetParams(
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_EXT_SEM_WAIT_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddBatchMemOpNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        nodeParams: *const CUDA_BATCH_MEM_OP_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphBatchMemOpNodeGetParams(
+        hNode: CUgraphNode,
+        nodeParams_out: *mut CUDA_BATCH_MEM_OP_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphBatchMemOpNodeSetParams(
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_BATCH_MEM_OP_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecBatchMemOpNodeSetParams(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_BATCH_MEM_OP_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddMemAllocNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        nodeParams: *mut CUDA_MEM_ALLOC_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphMemAllocNodeGetParams(
+        hNode: CUgraphNode,
+        params_out: *mut CUDA_MEM_ALLOC_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddMemFreeNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        dptr: CUdeviceptr,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphMemFreeNodeGetParams(
+        hNode: CUgraphNode,
+        dptr_out: *mut CUdeviceptr,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGraphMemTrim(device: CUdevice) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetGraphMemAttribute(
+        device: CUdevice,
+        attr: CUgraphMem_attribute,
+        value: *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceSetGraphMemAttribute(
+        device: CUdevice,
+        attr: CUgraphMem_attribute,
+        value: *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphClone(phGraphClone: *mut CUgraph, originalGraph: CUgraph) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphNodeFindInClone(
+        phNode: *mut CUgraphNode,
+        hOriginalNode: CUgraphNode,
+        hClonedGraph: CUgraph,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphNodeGetType(
+        hNode: CUgraphNode,
+        type_: *mut CUgraphNodeType,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphGetNodes(
+        hGraph: CUgraph,
+        nodes: *mut CUgraphNode,
+        numNodes: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphGetRootNodes(
+        hGraph: CUgraph,
+        rootNodes: *mut CUgraphNode,
+        numRootNodes: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphGetEdges(
+        hGraph: CUgraph,
+        from: *mut CUgraphNode,
+        to: *mut CUgraphNode,
+        numEdges: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphGetEdges_v2(
+        hGraph: CUgraph,
+        from: *mut CUgraphNode,
+        to: *mut CUgraphNode,
+        edgeData: *mut CUgraphEdgeData,
+        numEdges: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphNodeGetDependencies(
+        hNode: CUgraphNode,
+        dependencies: *mut CUgraphNode,
+        numDependencies: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphNodeGetDependencies_v2(
+        hNode: CUgraphNode,
+        dependencies: *mut CUgraphNode,
+        edgeData: *mut CUgraphEdgeData,
+        numDependencies: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphNodeGetDependentNodes(
+        hNode: CUgraphNode,
+        dependentNodes: *mut CUgraphNode,
+        numDependentNodes: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphNodeGetDependentNodes_v2(
+        hNode: CUgraphNode,
+        dependentNodes: *mut CUgraphNode,
+        edgeData: *mut CUgraphEdgeData,
+        numDependentNodes: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddDependencies(
+        hGraph: CUgraph,
+        from: *const CUgraphNode,
+        to: *const CUgraphNode,
+        numDependencies: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddDependencies_v2(
+        hGraph: CUgraph,
+        from: *const CUgraphNode,
+        to: *const CUgraphNode,
+        edgeData: *const CUgraphEdgeData,
+        numDependencies: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphRemoveDependencies(
+        hGraph: CUgraph,
+        from: *const CUgraphNode,
+        to: *const CUgraphNode,
+        numDependencies: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphRemoveDependencies_v2(
+        hGraph: CUgraph,
+        from: *const CUgraphNode,
+        to: *const CUgraphNode,
+        edgeData: *const CUgraphEdgeData,
+        numDependencies: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphDestroyNode(hNode: CUgraphNode) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphInstantiateWithFlags(
+        phGraphExec: *mut CUgraphExec,
+        hGraph: CUgraph,
+        flags: ::core::ffi::c_ulonglong,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphInstantiateWithParams(
+        phGraphExec: *mut CUgraphExec,
+        hGraph: CUgraph,
+        instantiateParams: *mut CUDA_GRAPH_INSTANTIATE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecGetFlags(
+        hGraphExec: CUgraphExec,
+        flags: *mut cuuint64_t,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecKernelNodeSetParams_v2(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_KERNEL_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecMemcpyNodeSetParams(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        copyParams: *const CUDA_MEMCPY3D,
+        ctx: CUcontext,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecMemsetNodeSetParams(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        memsetParams: *const CUDA_MEMSET_NODE_PARAMS,
+        ctx: CUcontext,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecHostNodeSetParams(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_HOST_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecChildGraphNodeSetParams(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        childGraph: CUgraph,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecEventRecordNodeSetEvent(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        event: CUevent,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecEventWaitNodeSetEvent(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        event: CUevent,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecExternalSemaphoresSignalNodeSetParams(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_EXT_SEM_SIGNAL_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecExternalSemaphoresWaitNodeSetParams(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        nodeParams: *const CUDA_EXT_SEM_WAIT_NODE_PARAMS,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphNodeSetEnabled(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        isEnabled: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphNodeGetEnabled(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        isEnabled: *mut ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphUpload(hGraphExec: CUgraphExec, hStream: CUstream) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphLaunch(hGraphExec: CUgraphExec, hStream: CUstream) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecDestroy(hGraphExec: CUgraphExec) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphDestroy(hGraph: CUgraph) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecUpdate_v2(
+        hGraphExec: CUgraphExec,
+        hGraph: CUgraph,
+        resultInfo: *mut CUgraphExecUpdateResultInfo,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphKernelNodeCopyAttributes(
+        dst: CUgraphNode,
+        src: CUgraphNode,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphKernelNodeGetAttribute(
+        hNode: CUgraphNode,
+        attr: CUkernelNodeAttrID,
+        value_out: *mut CUkernelNodeAttrValue,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphKernelNodeSetAttribute(
+        hNode: CUgraphNode,
+        attr: CUkernelNodeAttrID,
+        value: *const CUkernelNodeAttrValue,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphDebugDotPrint(
+        hGraph: CUgraph,
+        path: *const ::core::ffi::c_char,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuUserObjectCreate(
+        object_out: *mut CUuserObject,
+        ptr: *mut ::core::ffi::c_void,
+        destroy: CUhostFn,
+        initialRefcount: ::core::ffi::c_uint,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuUserObjectRetain(
+        object: CUuserObject,
+        count: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuUserObjectRelease(
+        object: CUuserObject,
+        count: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphRetainUserObject(
+        graph: CUgraph,
+        object: CUuserObject,
+        count: ::core::ffi::c_uint,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphReleaseUserObject(
+        graph: CUgraph,
+        object: CUuserObject,
+        count: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddNode(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        numDependencies: usize,
+        nodeParams: *mut CUgraphNodeParams,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphAddNode_v2(
+        phGraphNode: *mut CUgraphNode,
+        hGraph: CUgraph,
+        dependencies: *const CUgraphNode,
+        dependencyData: *const CUgraphEdgeData,
+        numDependencies: usize,
+        nodeParams: *mut CUgraphNodeParams,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphNodeSetParams(
+        hNode: CUgraphNode,
+        nodeParams: *mut CUgraphNodeParams,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphExecNodeSetParams(
+        hGraphExec: CUgraphExec,
+        hNode: CUgraphNode,
+        nodeParams: *mut CUgraphNodeParams,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphConditionalHandleCreate(
+        pHandle_out: *mut CUgraphConditionalHandle,
+        hGraph: CUgraph,
+        ctx: CUcontext,
+        defaultLaunchValue: ::core::ffi::c_uint,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuOccupancyMaxActiveBlocksPerMultiprocessor(
+        numBlocks: *mut ::core::ffi::c_int,
+        func: CUfunction,
+        blockSize: ::core::ffi::c_int,
+        dynamicSMemSize: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuOccupancyMaxActiveBlocksPerMultiprocessorWithFlags(
+        numBlocks: *mut ::core::ffi::c_int,
+        func: CUfunction,
+        blockSize: ::core::ffi::c_int,
+        dynamicSMemSize: usize,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuOccupancyMaxPotentialBlockSize(
+        minGridSize: *mut ::core::ffi::c_int,
+        blockSize: *mut ::core::ffi::c_int,
+        func: CUfunction,
+        blockSizeToDynamicSMemSize: CUoccupancyB2DSize,
+        dynamicSMemSize: usize,
+        blockSizeLimit: ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuOccupancyMaxPotentialBlockSizeWithFlags(
+        minGridSize: *mut ::core::ffi::c_int,
+        blockSize: *mut ::core::ffi::c_int,
+        func: CUfunction,
+        blockSizeToDynamicSMemSize: CUoccupancyB2DSize,
+        dynamicSMemSize: usize,
+        blockSizeLimit: ::core::ffi::c_int,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuOccupancyAvailableDynamicSMemPerBlock(
+        dynamicSmemSize: *mut usize,
+        func: CUfunction,
+        numBlocks: ::core::ffi::c_int,
+        blockSize: ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuOccupancyMaxPotentialClusterSize(
+        clusterSize: *mut ::core::ffi::c_int,
+        func: CUfunction,
+        config: *const CUlaunchConfig,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuOccupancyMaxActiveClusters(
+        numClusters: *mut ::core::ffi::c_int,
+        func: CUfunction,
+        config: *const CUlaunchConfig,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetArray(
+        hTexRef: CUtexref,
+        hArray: CUarray,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetMipmappedArray(
+        hTexRef: CUtexref,
+        hMipmappedArray: CUmipmappedArray,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetAddress_v2(
+        ByteOffset: *mut usize,
+        hTexRef: CUtexref,
+        dptr: CUdeviceptr,
+        bytes: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetAddress2D_v3(
+        hTexRef: CUtexref,
+        desc: *const CUDA_ARRAY_DESCRIPTOR,
+        dptr: CUdeviceptr,
+        Pitch: usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetFormat(
+        hTexRef: CUtexref,
+        fmt: CUarray_format,
+        NumPackedComponents: ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetAddressMode(
+        hTexRef: CUtexref,
+        dim: ::core::ffi::c_int,
+        am: CUaddress_mode,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetFilterMode(hTexRef: CUtexref, fm: CUfilter_mode) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetMipmapFilterMode(hTexRef: CUtexref, fm: CUfilter_mode) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetMipmapLevelBias(hTexRef: CUtexref, bias: f32) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetMipmapLevelClamp(
+        hTexRef: CUtexref,
+        minMipmapLevelClamp: f32,
+        maxMipmapLevelClamp: f32,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetMaxAnisotropy(
+        hTexRef: CUtexref,
+        maxAniso: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetBorderColor(hTexRef: CUtexref, pBorderColor: *mut f32) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefSetFlags(hTexRef: CUtexref, Flags: ::core::ffi::c_uint) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetAddress_v2(pdptr: *mut CUdeviceptr, hTexRef: CUtexref) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetArray(phArray: *mut CUarray, hTexRef: CUtexref) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetMipmappedArray(
+        phMipmappedArray: *mut CUmipmappedArray,
+        hTexRef: CUtexref,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetAddressMode(
+        pam: *mut CUaddress_mode,
+        hTexRef: CUtexref,
+        dim: ::core::ffi::c_int,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetFilterMode(pfm: *mut CUfilter_mode, hTexRef: CUtexref) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetFormat(
+        pFormat: *mut CUarray_format,
+        pNumChannels: *mut ::core::ffi::c_int,
+        hTexRef: CUtexref,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetMipmapFilterMode(
+        pfm: *mut CUfilter_mode,
+        hTexRef: CUtexref,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetMipmapLevelBias(pbias: *mut f32, hTexRef: CUtexref) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetMipmapLevelClamp(
+        pminMipmapLevelClamp: *mut f32,
+        pmaxMipmapLevelClamp: *mut f32,
+        hTexRef: CUtexref,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetMaxAnisotropy(
+        pmaxAniso: *mut ::core::ffi::c_int,
+        hTexRef: CUtexref,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetBorderColor(pBorderColor: *mut f32, hTexRef: CUtexref) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefGetFlags(
+        pFlags: *mut ::core::ffi::c_uint,
+        hTexRef: CUtexref,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefCreate(pTexRef: *mut CUtexref) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexRefDestroy(hTexRef: CUtexref) -> CUresult;
+}
+extern "C" {
+    pub fn cuSurfRefSetArray(
+        hSurfRef: CUsurfref,
+        hArray: CUarray,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuSurfRefGetArray(phArray: *mut CUarray, hSurfRef: CUsurfref) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexObjectCreate(
+        pTexObject: *mut CUtexObject,
+        pResDesc: *const CUDA_RESOURCE_DESC,
+        pTexDesc: *const CUDA_TEXTURE_DESC,
+        pResViewDesc: *const CUDA_RESOURCE_VIEW_DESC,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexObjectDestroy(texObject: CUtexObject) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexObjectGetResourceDesc(
+        pResDesc: *mut CUDA_RESOURCE_DESC,
+        texObject: CUtexObject,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexObjectGetTextureDesc(
+        pTexDesc: *mut CUDA_TEXTURE_DESC,
+        texObject: CUtexObject,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTexObjectGetResourceViewDesc(
+        pResViewDesc: *mut CUDA_RESOURCE_VIEW_DESC,
+        texObject: CUtexObject,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuSurfObjectCreate(
+        pSurfObject: *mut CUsurfObject,
+        pResDesc: *const CUDA_RESOURCE_DESC,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuSurfObjectDestroy(surfObject: CUsurfObject) -> CUresult;
+}
+extern "C" {
+    pub fn cuSurfObjectGetResourceDesc(
+        pResDesc: *mut CUDA_RESOURCE_DESC,
+        surfObject: CUsurfObject,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTensorMapEncodeTiled(
+        tensorMap: *mut CUtensorMap,
+        tensorDataType: CUtensorMapDataType,
+        tensorRank: cuuint32_t,
+        globalAddress: *mut ::core::ffi::c_void,
+        globalDim: *const cuuint64_t,
+        globalStrides: *const cuuint64_t,
+        boxDim: *const cuuint32_t,
+        elementStrides: *const cuuint32_t,
+        interleave: CUtensorMapInterleave,
+        swizzle: CUtensorMapSwizzle,
+        l2Promotion: CUtensorMapL2promotion,
+        oobFill: CUtensorMapFloatOOBfill,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTensorMapEncodeIm2col(
+        tensorMap: *mut CUtensorMap,
+        tensorDataType: CUtensorMapDataType,
+        tensorRank: cuuint32_t,
+        globalAddress: *mut ::core::ffi::c_void,
+        globalDim: *const cuuint64_t,
+        globalStrides: *const cuuint64_t,
+        pixelBoxLowerCorner: *const ::core::ffi::c_int,
+        pixelBoxUpperCorner: *const ::core::ffi::c_int,
+        channelsPerPixel: cuuint32_t,
+        pixelsPerColumn: cuuint32_t,
+        elementStrides: *const cuuint32_t,
+        interleave: CUtensorMapInterleave,
+        swizzle: CUtensorMapSwizzle,
+        l2Promotion: CUtensorMapL2promotion,
+        oobFill: CUtensorMapFloatOOBfill,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuTensorMapReplaceAddress(
+        tensorMap: *mut CUtensorMap,
+        globalAddress: *mut ::core::ffi::c_void,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceCanAccessPeer(
+        canAccessPeer: *mut ::core::ffi::c_int,
+        dev: CUdevice,
+        peerDev: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxEnablePeerAccess(
+        peerContext: CUcontext,
+        Flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxDisablePeerAccess(peerContext: CUcontext) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetP2PAttribute(
+        value: *mut ::core::ffi::c_int,
+        attrib: CUdevice_P2PAttribute,
+        srcDevice: CUdevice,
+        dstDevice: CUdevice,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphicsUnregisterResource(resource: CUgraphicsResource) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphicsSubResourceGetMappedArray(
+        pArray: *mut CUarray,
+        resource: CUgraphicsResource,
+        arrayIndex: ::core::ffi::c_uint,
+        mipLevel: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphicsResourceGetMappedMipmappedArray(
+        pMipmappedArray: *mut CUmipmappedArray,
+        resource: CUgraphicsResource,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphicsResourceGetMappedPointer_v2(
+        pDevPtr: *mut CUdeviceptr,
+        pSize: *mut usize,
+        resource: CUgraphicsResource,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphicsResourceSetMapFlags_v2(
+        resource: CUgraphicsResource,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphicsMapResources(
+        count: ::core::ffi::c_uint,
+        resources: *mut CUgraphicsResource,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGraphicsUnmapResources(
+        count: ::core::ffi::c_uint,
+        resources: *mut CUgraphicsResource,
+        hStream: CUstream,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGetProcAddress_v2(
+        symbol: *const ::core::ffi::c_char,
+        pfn: *mut *mut ::core::ffi::c_void,
+        cudaVersion: ::core::ffi::c_int,
+        flags: cuuint64_t,
+        symbolStatus: *mut CUdriverProcAddressQueryResult,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCoredumpGetAttribute(
+        attrib: CUcoredumpSettings,
+        value: *mut ::core::ffi::c_void,
+        size: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCoredumpGetAttributeGlobal(
+        attrib: CUcoredumpSettings,
+        value: *mut ::core::ffi::c_void,
+        size: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCoredumpSetAttribute(
+        attrib: CUcoredumpSettings,
+        value: *mut ::core::ffi::c_void,
+        size: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCoredumpSetAttributeGlobal(
+        attrib: CUcoredumpSettings,
+        value: *mut ::core::ffi::c_void,
+        size: *mut usize,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGetExportTable(
+        ppExportTable: *mut *const ::core::ffi::c_void,
+        pExportTableId: *const CUuuid,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGreenCtxCreate(
+        phCtx: *mut CUgreenCtx,
+        desc: CUdevResourceDesc,
+        dev: CUdevice,
+        flags: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGreenCtxDestroy(hCtx: CUgreenCtx) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxFromGreenCtx(pContext: *mut CUcontext, hCtx: CUgreenCtx) -> CUresult;
+}
+extern "C" {
+    pub fn cuDeviceGetDevResource(
+        device: CUdevice,
+        resource: *mut CUdevResource,
+        type_: CUdevResourceType,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuCtxGetDevResource(
+        hCtx: CUcontext,
+        resource: *mut CUdevResource,
+        type_: CUdevResourceType,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGreenCtxGetDevResource(
+        hCtx: CUgreenCtx,
+        resource: *mut CUdevResource,
+        type_: CUdevResourceType,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDevSmResourceSplitByCount(
+        result: *mut CUdevResource,
+        nbGroups: *mut ::core::ffi::c_uint,
+        input: *const CUdevResource,
+        remaining: *mut CUdevResource,
+        useFlags: ::core::ffi::c_uint,
+        minCount: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuDevResourceGenerateDesc(
+        phDesc: *mut CUdevResourceDesc,
+        resources: *mut CUdevResource,
+        nbResources: ::core::ffi::c_uint,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuGreenCtxRecordEvent(hCtx: CUgreenCtx, hEvent: CUevent) -> CUresult;
+}
+extern "C" {
+    pub fn cuGreenCtxWaitEvent(hCtx: CUgreenCtx, hEvent: CUevent) -> CUresult;
+}
+extern "C" {
+    pub fn cuStreamGetGreenCtx(hStream: CUstream, phCtx: *mut CUgreenCtx) -> CUresult;
+}
+extern "C" {
+    pub fn cuProfilerInitialize(
+        configFile: *const ::core::ffi::c_char,
+        outputFile: *const ::core::ffi::c_char,
+        outputMode: CUoutput_mode,
+    ) -> CUresult;
+}
+extern "C" {
+    pub fn cuProfilerStart() -> CUresult;
+}
+extern "C" {
+    pub fn cuProfilerStop() -> CUresult;
+}
@@ -0,0 +1,81 @@
+//! Runtime dispatch of the CUDA driver API.
+//!
+//! With the `dynamic-loading` feature enabled the crate does not link against
+//! `libcuda` at build time. The library is opened on first use instead, and
+//! every `cu*` function dispatches through a [`DriverTable`]. When the library
+//! cannot be loaded, all entry points return `CUDA_ERROR_NO_DEVICE`; entry
+//! points missing from an older driver return `CUDA_ERROR_NOT_FOUND`.
+//!
+//! With the `mock` feature enabled the table is filled from the simulation in
+//! [`crate::mock`] instead, and no library is loaded.
+
+use crate::{
+    cudaError_enum_CUDA_ERROR_NOT_FOUND,
+    CUresult,
+    DriverTable,
+};
+#[cfg(feature = "dynamic-loading")]
+use std::sync::OnceLock;
+
+pub(crate) const SYMBOL_NOT_FOUND: CUresult = cudaError_enum_CUDA_ERROR_NOT_FOUND;
+
+#[cfg(feature = "dynamic-loading")]
+pub(crate) const LIBRARY_NOT_FOUND: CUresult = crate::cudaError_enum_CUDA_ERROR_NO_DEVICE;
+
+#[cfg(all(feature = "dynamic-loading", windows))]
+const LIBRARY_NAMES: &[&str] = &["nvcuda.dll"];
+#[cfg(all(feature = "dynamic-loading", not(windows)))]
+const LIBRARY_NAMES: &[&str] = &["libcuda.so.1", "libcuda.so"];
+
+#[cfg(feature = "dynamic-loading")]
+struct Driver {
+    // Keeps the symbols in `table` valid for the lifetime of the process.
+    _library: libloading::Library,
+    table: DriverTable,
+}
+
+#[cfg(feature = "dynamic-loading")]
+static DRIVER: OnceLock<Result<Driver, libloading::Error>> = OnceLock::new();
+
+#[cfg(feature = "dynamic-loading")]
+fn open() -> Result<Driver, libloading::Error> {
+    let mut last_err = None;
+    for name in LIBRARY_NAMES {
+        match unsafe { libloading::Library::new(name) } {
+            Ok(library) => {
+                let table = unsafe {
+                    DriverTable::load(|symbol| {
+                        library
+                            .get::<*const std::os::raw::c_void>(symbol)
+                            .map_or(std::ptr::null(), |sym| *sym)
+                    })
+                };
+
+                return Ok(Driver { _library: library, table });
+            }
+            Err(err) => last_err = Some(err),
+        }
+    }
+
+    Err(last_err.unwrap())
+}
+
+/// Loads the driver library if needed and returns its entry points, or the
+/// error that prevented it from being opened.
+#[cfg(feature = "dynamic-loading")]
+pub fn load() -> Result<&'static DriverTable, &'static libloading::Error> {
+    match DRIVER.get_or_init(open) {
+        Ok(driver) => Ok(&driver.table),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(not(feature = "mock"))]
+pub(crate) fn table() -> Result<&'static DriverTable, CUresult> {
+    load().map_err(|_| LIBRARY_NOT_FOUND)
+}
+
+#[cfg(feature = "mock")]
+pub(crate) fn table() -> Result<&'static DriverTable, CUresult> {
+    Ok(crate::mock::table())
+}
@@ -2,5 +2,15 @@
 #![allow(non_snake_case)]
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
+#![allow(unpredictable_function_pointer_comparisons)]
+#![allow(clippy::missing_safety_doc)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::type_complexity)]
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+#[cfg(any(feature = "dynamic-loading", feature = "mock"))]
+pub mod driver;
+
+#[cfg(feature = "mock")]
+pub mod mock;
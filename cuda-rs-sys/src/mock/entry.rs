@@ -0,0 +1,2197 @@
+//! The simulated `cu*` entry points.
+
+use super::state::{
+    context_stack, lock, lock_any, AccessPolicy, Context, Event, EventState, Op, Res, State,
+    DEFAULT_STREAM, LEGACY_STREAM, PER_THREAD_STREAM, STREAM_PRIORITIES,
+};
+use super::graph::{node_type, Destructor, NodeKind};
+use crate::*;
+use std::os::raw::{c_char, c_int, c_uchar, c_uint, c_void};
+
+/// Defines the simulated entry points along with `lookup`, which resolves
+/// them by symbol name for the driver table. Each body returns a [`Res`],
+/// and a failure injected with [`super::fail_next`] or
+/// [`super::fail_always`] is returned before the body runs.
+macro_rules! entry_points {
+    ($(
+        $(#[$attr:meta])*
+        fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $body:block
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[allow(clippy::missing_safety_doc)]
+            unsafe extern "C" fn $name($($arg: $ty),*) -> CUresult {
+                unsafe fn simulate($($arg: $ty),*) -> Res $body
+
+                if let Some(err) = super::injected_failure(stringify!($name)) {
+                    return err;
+                }
+
+                let res = match simulate($($arg),*) {
+                    Ok(()) => cudaError_enum_CUDA_SUCCESS,
+                    Err(err) => err,
+                };
+                super::state::run_host_fns();
+
+                res
+            }
+        )*
+
+        pub(super) fn lookup(symbol: &[u8]) -> *const c_void {
+            $(
+                $(#[$attr])*
+                if symbol == concat!(stringify!($name), "\0").as_bytes() {
+                    let f: unsafe extern "C" fn($($ty),*) -> CUresult = $name;
+                    return f as *const c_void;
+                }
+            )*
+
+            std::ptr::null()
+        }
+
+        /// Fails to compile if a simulated entry point does not have the
+        /// signature declared in the bindings.
+        #[allow(dead_code)]
+        fn check_signatures(table: &DriverTable) {
+            $(
+                $(#[$attr])*
+                let _: Option<unsafe extern "C" fn($($ty),*) -> CUresult> = table.$name;
+            )*
+        }
+    };
+}
+
+unsafe fn write<T>(dst: *mut T, value: T) -> Res {
+    if dst.is_null() {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+    dst.write(value);
+
+    Ok(())
+}
+
+/// Copies `value` into a NUL-terminated buffer of `len` bytes, truncating
+/// it if needed.
+unsafe fn write_str(dst: *mut c_char, len: c_int, value: &str) -> Res {
+    if dst.is_null() || len <= 0 {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    let n = value.len().min(len as usize - 1);
+    std::ptr::copy_nonoverlapping(value.as_ptr() as *const c_char, dst, n);
+    *dst.add(n) = 0;
+
+    Ok(())
+}
+
+fn check_context_flags(flags: c_uint) -> Res {
+    let sched = flags & CUctx_flags_enum_CU_CTX_SCHED_MASK;
+    if flags & !CUctx_flags_enum_CU_CTX_FLAGS_MASK != 0 || !matches!(sched, 0 | 1 | 2 | 4) {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    Ok(())
+}
+
+/// Attributes of the simulated devices, loosely modeled on a compute
+/// capability 8.6 GPU. Attributes not listed here are 0.
+fn device_attribute(attrib: CUdevice_attribute, dev: CUdevice) -> c_int {
+    match attrib {
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_BLOCK => 1024,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_X => 1024,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_Y => 1024,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_Z => 64,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_X => i32::MAX,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_Y => 65535,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_Z => 65535,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK => 48 << 10,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_TOTAL_CONSTANT_MEMORY => 64 << 10,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_WARP_SIZE => 32,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_PITCH => i32::MAX,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_REGISTERS_PER_BLOCK => 64 << 10,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CLOCK_RATE => 1_695_000,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_TEXTURE_ALIGNMENT => 512,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GPU_OVERLAP => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MULTIPROCESSOR_COUNT => 84,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CAN_MAP_HOST_MEMORY => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_SURFACE_ALIGNMENT => 512,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CONCURRENT_KERNELS => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_PCI_BUS_ID => dev + 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MEMORY_CLOCK_RATE => 9_501_000,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GLOBAL_MEMORY_BUS_WIDTH => 384,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_L2_CACHE_SIZE => 6 << 20,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_MULTIPROCESSOR => 1536,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_ASYNC_ENGINE_COUNT => 2,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_UNIFIED_ADDRESSING => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MAJOR => 8,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MINOR => 6,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_STREAM_PRIORITIES_SUPPORTED => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_GLOBAL_L1_CACHE_SUPPORTED => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_LOCAL_L1_CACHE_SUPPORTED => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_MULTIPROCESSOR => 100 << 10,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_REGISTERS_PER_MULTIPROCESSOR => 64 << 10,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MANAGED_MEMORY => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MULTI_GPU_BOARD_GROUP_ID => dev,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CONCURRENT_MANAGED_ACCESS => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COMPUTE_PREEMPTION_SUPPORTED => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COOPERATIVE_LAUNCH => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK_OPTIN => 99 << 10,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_HOST_REGISTER_SUPPORTED => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_VIRTUAL_MEMORY_MANAGEMENT_SUPPORTED => 1,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCKS_PER_MULTIPROCESSOR => 16,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_PERSISTING_L2_CACHE_SIZE => 3 << 20,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_ACCESS_POLICY_WINDOW_SIZE => 128 << 20,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_RESERVED_SHARED_MEMORY_PER_BLOCK => 1 << 10,
+        CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MEMORY_POOLS_SUPPORTED => 1,
+        _ => 0,
+    }
+}
+
+fn stream_handle(key: usize) -> CUstream {
+    key as CUstream
+}
+
+fn event_handle(key: usize) -> CUevent {
+    key as CUevent
+}
+
+fn context_handle(key: usize) -> CUcontext {
+    key as CUcontext
+}
+
+fn graph_handle(key: usize) -> CUgraph {
+    key as CUgraph
+}
+
+fn graph_key(st: &State, graph: CUgraph) -> Result<usize, CUresult> {
+    let key = graph as usize;
+    if st.graphs.contains_key(&key) {
+        Ok(key)
+    } else {
+        Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE)
+    }
+}
+
+fn node_handle(key: usize) -> CUgraphNode {
+    key as CUgraphNode
+}
+
+fn node_key(st: &State, node: CUgraphNode) -> Result<usize, CUresult> {
+    let key = node as usize;
+    if st.nodes.contains_key(&key) {
+        Ok(key)
+    } else {
+        Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE)
+    }
+}
+
+/// Writes `keys` as nodes. With a buffer, `count` is its capacity on input
+/// and the number of nodes written on output; without one, it is the number
+/// of nodes.
+unsafe fn write_nodes(keys: &[usize], nodes: *mut CUgraphNode, count: *mut usize) -> Res {
+    if count.is_null() {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    if !nodes.is_null() {
+        let n = keys.len().min(*count);
+        for (i, &key) in keys.iter().take(n).enumerate() {
+            *nodes.add(i) = node_handle(key);
+        }
+        *count = n;
+    } else {
+        *count = keys.len();
+    }
+
+    Ok(())
+}
+
+/// The kind of `node`, which must be a node of `kind`.
+fn node_kind(st: &State, node: CUgraphNode, kind: CUgraphNodeType) -> Result<&NodeKind, CUresult> {
+    let node = &st.nodes[&node_key(st, node)?];
+    if node_type(&node.kind) != kind {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    Ok(&node.kind)
+}
+
+fn exec_key(st: &State, exec: CUgraphExec) -> Result<usize, CUresult> {
+    let key = exec as usize;
+    if st.execs.contains_key(&key) {
+        Ok(key)
+    } else {
+        Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE)
+    }
+}
+
+fn user_object_key(st: &State, object: CUuserObject) -> Result<usize, CUresult> {
+    let key = object as usize;
+    if st.user_objects.contains_key(&key) {
+        Ok(key)
+    } else {
+        Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE)
+    }
+}
+
+/// Resolves the dependencies of a node being added to `graph`. They must be
+/// distinct nodes of the same graph.
+unsafe fn node_deps(st: &State, graph: usize, deps: *const CUgraphNode, count: usize) -> Result<Vec<usize>, CUresult> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    if deps.is_null() {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    let mut keys = Vec::with_capacity(count);
+    for &dep in std::slice::from_raw_parts(deps, count) {
+        let key = dep as usize;
+        if st.nodes.get(&key).is_none_or(|node| node.graph != graph) || keys.contains(&key) {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        keys.push(key);
+    }
+
+    Ok(keys)
+}
+
+unsafe fn add_node(st: &mut State, node: *mut CUgraphNode, graph: usize, deps: Vec<usize>, kind: NodeKind) -> Res {
+    if node.is_null() {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    write(node, node_handle(st.add_node(graph, kind, deps)))
+}
+
+unsafe fn add_kernel_node(
+    node: *mut CUgraphNode,
+    graph: CUgraph,
+    deps: *const CUgraphNode,
+    num_deps: usize,
+    params: *const CUDA_KERNEL_NODE_PARAMS,
+) -> Res {
+    let mut st = lock()?;
+    let key = graph_key(&st, graph)?;
+    let deps = node_deps(&st, key, deps, num_deps)?;
+    let p = params.as_ref().ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+    let dims = [p.gridDimX, p.gridDimY, p.gridDimZ, p.blockDimX, p.blockDimY, p.blockDimZ];
+    if p.func.is_null() || dims.contains(&0) {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    let kind = NodeKind::Kernel {
+        func: p.func as usize,
+        grid: [p.gridDimX, p.gridDimY, p.gridDimZ],
+        block: [p.blockDimX, p.blockDimY, p.blockDimZ],
+        shared_mem: p.sharedMemBytes,
+    };
+
+    add_node(&mut st, node, key, deps, kind)
+}
+
+unsafe fn kernel_node_params(node: CUgraphNode, params: *mut CUDA_KERNEL_NODE_PARAMS) -> Res {
+    let st = lock()?;
+    let &NodeKind::Kernel { func, grid, block, shared_mem } =
+        node_kind(&st, node, CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_KERNEL)?
+    else {
+        unreachable!()
+    };
+    if params.is_null() {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    // The mock doesn't keep kernel arguments.
+    let mut p: CUDA_KERNEL_NODE_PARAMS = std::mem::zeroed();
+    p.func = func as CUfunction;
+    [p.gridDimX, p.gridDimY, p.gridDimZ] = grid;
+    [p.blockDimX, p.blockDimY, p.blockDimZ] = block;
+    p.sharedMemBytes = shared_mem;
+
+    write(params, p)
+}
+
+/// The memory type of `ptr`, as a simulated copy sees it: device memory if
+/// it was allocated as such, and host memory otherwise.
+fn memory_type(st: &State, ptr: usize, len: usize) -> CUmemorytype {
+    if st.check_device_range(ptr, len).is_ok() {
+        CUmemorytype_enum_CU_MEMORYTYPE_DEVICE
+    } else {
+        CUmemorytype_enum_CU_MEMORYTYPE_HOST
+    }
+}
+
+fn check_context(st: &State, ctx: CUcontext) -> Res {
+    if st.contexts.contains_key(&(ctx as usize)) {
+        Ok(())
+    } else {
+        Err(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT)
+    }
+}
+
+fn capture_status(st: &State, stream: usize) -> CUstreamCaptureStatus {
+    match st.streams[&stream].capture {
+        Some(ref capture) if st.captures[&capture.id].invalidated => {
+            CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_INVALIDATED
+        }
+        Some(_) => CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_ACTIVE,
+        None => CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_NONE,
+    }
+}
+
+unsafe fn create_stream(stream: *mut CUstream, flags: c_uint, priority: c_int) -> Res {
+    let mut st = lock()?;
+    let ctx = st.current_context()?;
+    if flags & !CUstream_flags_enum_CU_STREAM_NON_BLOCKING != 0 {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    let key = st.create_stream(ctx, flags, priority);
+
+    write(stream, stream_handle(key))
+}
+
+/// Checks that `addr` is an aligned word of simulated memory.
+fn check_mem_op_addr(st: &State, addr: CUdeviceptr, wide: bool) -> Res {
+    let len = if wide { 8 } else { 4 };
+    if !(addr as usize).is_multiple_of(len) || !st.check_range(addr as usize, len)? {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    Ok(())
+}
+
+fn wait_value_op(st: &State, addr: CUdeviceptr, value: u64, wide: bool, flags: c_uint) -> Result<Op, CUresult> {
+    check_mem_op_addr(st, addr, wide)?;
+    let cond = flags & !CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_FLUSH;
+    if cond > CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_NOR {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    Ok(Op::WaitValue { addr: addr as usize, cond, value, wide })
+}
+
+fn write_value_op(st: &State, addr: CUdeviceptr, value: u64, wide: bool, flags: c_uint) -> Result<Op, CUresult> {
+    check_mem_op_addr(st, addr, wide)?;
+    if flags & !CUstreamWriteValue_flags_enum_CU_STREAM_WRITE_VALUE_NO_MEMORY_BARRIER != 0 {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+
+    Ok(Op::WriteValue { addr: addr as usize, value, wide })
+}
+
+/// Resolves `stream` and checks that it belongs to the current context.
+fn current_stream(st: &State, stream: CUstream) -> Result<usize, CUresult> {
+    let ctx = st.current_context()?;
+    let key = st.stream_key(stream)?;
+    if key != LEGACY_STREAM && key != PER_THREAD_STREAM && st.streams[&key].ctx != ctx {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_HANDLE);
+    }
+
+    Ok(key)
+}
+
+/// Enqueues a copy of `len` bytes. Copies touching pageable host memory are
+/// synchronous, as with the real driver.
+fn copy(st: &mut State, dst: usize, src: usize, len: usize, stream: usize) -> Res {
+    let dst_owned = st.check_range(dst, len)?;
+    let src_owned = st.check_range(src, len)?;
+    st.enqueue(stream, Op::Copy { dst, src, len }, !(dst_owned && src_owned))?;
+
+    Ok(())
+}
+
+/// Checks a 2D copy, returning it as an operation along with whether it
+/// only touches mock-owned memory, or `None` if there is nothing to copy.
+fn copy_2d_op(st: &State, p: &CUDA_MEMCPY2D) -> Result<Option<(Op, bool)>, CUresult> {
+    fn address(
+        memory_type: CUmemorytype,
+        host: usize,
+        device: CUdeviceptr,
+        x: usize,
+        y: usize,
+        pitch: usize,
+    ) -> Result<usize, CUresult> {
+        let base = match memory_type {
+            CUmemorytype_enum_CU_MEMORYTYPE_HOST => host,
+            CUmemorytype_enum_CU_MEMORYTYPE_DEVICE | CUmemorytype_enum_CU_MEMORYTYPE_UNIFIED => {
+                device as usize
+            }
+            CUmemorytype_enum_CU_MEMORYTYPE_ARRAY => {
+                return Err(cudaError_enum_CUDA_ERROR_NOT_SUPPORTED)
+            }
+            _ => return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        };
+
+        Ok(base + y * pitch + x)
+    }
+
+    let width = p.WidthInBytes;
+    let height = p.Height;
+    if height > 1 && (p.srcPitch < width || p.dstPitch < width) {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+    if width == 0 || height == 0 {
+        return Ok(None);
+    }
+
+    let src = address(p.srcMemoryType, p.srcHost as usize, p.srcDevice, p.srcXInBytes, p.srcY, p.srcPitch)?;
+    let dst = address(p.dstMemoryType, p.dstHost as usize, p.dstDevice, p.dstXInBytes, p.dstY, p.dstPitch)?;
+    let src_len = (height - 1) * p.srcPitch + width;
+    let dst_len = (height - 1) * p.dstPitch + width;
+    if p.srcMemoryType == CUmemorytype_enum_CU_MEMORYTYPE_DEVICE {
+        st.check_device_range(src, src_len)?;
+    }
+    if p.dstMemoryType == CUmemorytype_enum_CU_MEMORYTYPE_DEVICE {
+        st.check_device_range(dst, dst_len)?;
+    }
+    let src_owned = st.check_range(src, src_len)?;
+    let dst_owned = st.check_range(dst, dst_len)?;
+
+    let op = Op::Copy2D {
+        dst,
+        dst_pitch: p.dstPitch,
+        src,
+        src_pitch: p.srcPitch,
+        width,
+        height,
+    };
+
+    Ok(Some((op, src_owned && dst_owned)))
+}
+
+fn copy_2d(st: &mut State, p: &CUDA_MEMCPY2D, stream: usize) -> Res {
+    if let Some((op, owned)) = copy_2d_op(st, p)? {
+        st.enqueue(stream, op, !owned)?;
+    }
+
+    Ok(())
+}
+
+fn memset_op(st: &State, dst: CUdeviceptr, value: u32, elem_size: usize, count: usize) -> Result<Op, CUresult> {
+    let dst = dst as usize;
+    if dst & (elem_size - 1) != 0 {
+        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+    }
+    st.check_device_range(dst, elem_size * count)?;
+
+    Ok(Op::Memset { dst, value, elem_size, count })
+}
+
+fn memset(st: &mut State, dst: CUdeviceptr, value: u32, elem_size: usize, count: usize, stream: usize) -> Res {
+    let op = memset_op(st, dst, value, elem_size, count)?;
+    st.enqueue(stream, op, false)?;
+
+    Ok(())
+}
+
+fn error_name(error: CUresult) -> Result<*const c_char, CUresult> {
+    ERROR_NAMES
+        .iter()
+        .find(|(code, _)| *code == error)
+        .map(|(_, name)| name.as_ptr() as *const c_char)
+        .ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)
+}
+
+/// Lets pending work on `stream` run unless the stream is held. Returns
+/// whether `seq` has completed.
+fn poll(st: &mut State, stream: usize, seq: u64) -> bool {
+    st.advance(stream, seq, false)
+}
+
+fn poll_event(st: &mut State, event: usize) -> EventState {
+    if let EventState::Pending { stream, seq } = st.events[&event].state {
+        poll(st, stream, seq);
+    }
+
+    st.events[&event].state
+}
+
+/// Fails synchronization with a capturing stream, which invalidates its
+/// capture.
+fn check_not_capturing(st: &mut State, stream: usize) -> Res {
+    if st.streams[&stream].capture.is_some() {
+        return Err(st.invalidate_capture(stream, cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNSUPPORTED));
+    }
+
+    Ok(())
+}
+
+entry_points! {
+    fn cuInit(flags: c_uint) {
+        if flags != 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        lock_any().initialized = true;
+
+        Ok(())
+    }
+
+    fn cuDriverGetVersion(version: *mut c_int) {
+        write(version, CUDA_VERSION as c_int)
+    }
+
+    fn cuGetErrorName(error: CUresult, name: *mut *const c_char) {
+        write(name, error_name(error)?)
+    }
+
+    // The mock has no prose descriptions, so it reports the name instead.
+    fn cuGetErrorString(error: CUresult, string: *mut *const c_char) {
+        write(string, error_name(error)?)
+    }
+
+    fn cuDeviceGet(device: *mut CUdevice, ordinal: c_int) {
+        let st = lock()?;
+        st.check_device(ordinal)?;
+
+        write(device, ordinal)
+    }
+
+    fn cuDeviceGetCount(count: *mut c_int) {
+        let st = lock()?;
+
+        write(count, st.config.device_count)
+    }
+
+    fn cuDeviceGetName(name: *mut c_char, len: c_int, dev: CUdevice) {
+        let st = lock()?;
+        st.check_device(dev)?;
+
+        write_str(name, len, &format!("Mock CUDA Device {}", dev))
+    }
+
+    fn cuDeviceGetUuid_v2(uuid: *mut CUuuid, dev: CUdevice) {
+        let st = lock()?;
+        st.check_device(dev)?;
+
+        let mut bytes = [0x5a as c_char; 16];
+        bytes[4] = dev as c_char;
+        write(uuid, CUuuid { bytes })
+    }
+
+    fn cuDeviceGetPCIBusId(pci_bus_id: *mut c_char, len: c_int, dev: CUdevice) {
+        let st = lock()?;
+        st.check_device(dev)?;
+
+        write_str(pci_bus_id, len, &format!("0000:{:02x}:00.0", dev + 1))
+    }
+
+    fn cuDeviceGetByPCIBusId(dev: *mut CUdevice, pci_bus_id: *const c_char) {
+        let st = lock()?;
+        if pci_bus_id.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        // Accepts `[domain:]bus:device.function`, like the driver does.
+        let id = std::ffi::CStr::from_ptr(pci_bus_id)
+            .to_str()
+            .map_err(|_| cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        let mut parts: Vec<_> = id.split(':').collect();
+        if parts.len() == 2 {
+            parts.insert(0, "0");
+        }
+        let bus = match parts.as_slice() {
+            [domain, bus, rest] if u32::from_str_radix(domain, 16) == Ok(0) && rest.contains('.') => {
+                i32::from_str_radix(bus, 16).map_err(|_| cudaError_enum_CUDA_ERROR_INVALID_VALUE)?
+            }
+            _ => return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        };
+        st.check_device(bus - 1)?;
+
+        write(dev, bus - 1)
+    }
+
+    fn cuDeviceGetAttribute(pi: *mut c_int, attrib: CUdevice_attribute, dev: CUdevice) {
+        let st = lock()?;
+        st.check_device(dev)?;
+        if attrib == 0 || attrib >= CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        write(pi, device_attribute(attrib, dev))
+    }
+
+    fn cuDeviceTotalMem_v2(bytes: *mut usize, dev: CUdevice) {
+        let st = lock()?;
+        st.check_device(dev)?;
+
+        write(bytes, st.config.total_memory)
+    }
+
+    fn cuCtxCreate_v2(pctx: *mut CUcontext, flags: c_uint, dev: CUdevice) {
+        let mut st = lock()?;
+        st.check_device(dev)?;
+        check_context_flags(flags)?;
+
+        let ctx = st.new_handle();
+        st.contexts.insert(ctx, Context::new(dev, flags, false, 1));
+        context_stack(|stack| stack.push(ctx));
+
+        write(pctx, context_handle(ctx))
+    }
+
+    fn cuCtxDestroy_v2(ctx: CUcontext) {
+        let mut st = lock()?;
+        let key = ctx as usize;
+        match st.contexts.get(&key) {
+            Some(c) if !c.primary => {}
+            _ => return Err(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT),
+        }
+
+        st.drain_all();
+        st.contexts.remove(&key);
+        st.streams.retain(|_, s| s.ctx != key);
+        context_stack(|stack| stack.retain(|&c| c != key));
+
+        Ok(())
+    }
+
+    fn cuDevicePrimaryCtxRetain(pctx: *mut CUcontext, dev: CUdevice) {
+        let mut st = lock()?;
+        st.check_device(dev)?;
+
+        let ctx = st.primary_context(dev);
+        st.contexts.get_mut(&ctx).unwrap().refcount += 1;
+
+        write(pctx, context_handle(ctx))
+    }
+
+    fn cuDevicePrimaryCtxRelease_v2(dev: CUdevice) {
+        let mut st = lock()?;
+        st.check_device(dev)?;
+
+        let ctx = *st.primary.get(&dev).ok_or(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT)?;
+        let context = st.contexts.get_mut(&ctx).unwrap();
+        if context.refcount == 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT);
+        }
+        context.refcount -= 1;
+        if context.refcount == 0 {
+            // The driver destroys the primary context with its last reference;
+            // only the flags survive until it is retained again.
+            context.reset();
+            st.drain_all();
+            st.streams.retain(|_, s| s.ctx != ctx);
+        }
+
+        Ok(())
+    }
+
+    fn cuDevicePrimaryCtxGetState(dev: CUdevice, flags: *mut c_uint, active: *mut c_int) {
+        let mut st = lock()?;
+        st.check_device(dev)?;
+
+        let ctx = st.primary_context(dev);
+        let ctx = &st.contexts[&ctx];
+        write(flags, ctx.flags)?;
+        write(active, (ctx.refcount > 0) as c_int)
+    }
+
+    fn cuDevicePrimaryCtxSetFlags_v2(dev: CUdevice, flags: c_uint) {
+        let mut st = lock()?;
+        st.check_device(dev)?;
+        check_context_flags(flags)?;
+
+        let ctx = st.primary_context(dev);
+        st.contexts.get_mut(&ctx).unwrap().flags = flags;
+
+        Ok(())
+    }
+
+    fn cuDevicePrimaryCtxReset_v2(dev: CUdevice) {
+        let mut st = lock()?;
+        st.check_device(dev)?;
+
+        let Some(&ctx) = st.primary.get(&dev) else {
+            return Ok(());
+        };
+        st.streams.retain(|_, s| s.ctx != ctx);
+        let context = st.contexts.get_mut(&ctx).unwrap();
+        context.reset();
+        context.flags = 0;
+
+        Ok(())
+    }
+
+    fn cuCtxGetCurrent(pctx: *mut CUcontext) {
+        let _st = lock()?;
+        let ctx = context_stack(|stack| stack.last().copied()).unwrap_or(0);
+
+        write(pctx, context_handle(ctx))
+    }
+
+    fn cuCtxSetCurrent(ctx: CUcontext) {
+        let st = lock()?;
+        let key = ctx as usize;
+        if key != 0 && !st.contexts.contains_key(&key) {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT);
+        }
+
+        context_stack(|stack| {
+            stack.pop();
+            if key != 0 {
+                stack.push(key);
+            }
+        });
+
+        Ok(())
+    }
+
+    fn cuCtxPushCurrent_v2(ctx: CUcontext) {
+        let st = lock()?;
+        let key = ctx as usize;
+        if !st.contexts.contains_key(&key) {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT);
+        }
+        context_stack(|stack| stack.push(key));
+
+        Ok(())
+    }
+
+    fn cuCtxPopCurrent_v2(pctx: *mut CUcontext) {
+        let _st = lock()?;
+        let ctx = context_stack(|stack| stack.pop())
+            .ok_or(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT)?;
+        if !pctx.is_null() {
+            *pctx = context_handle(ctx);
+        }
+
+        Ok(())
+    }
+
+    fn cuCtxGetFlags(flags: *mut c_uint) {
+        let st = lock()?;
+        let ctx = st.current_context()?;
+
+        write(flags, st.contexts[&ctx].flags)
+    }
+
+    fn cuCtxSetLimit(limit: CUlimit, value: usize) {
+        let mut st = lock()?;
+        let ctx = st.current_context()?;
+        match limit {
+            CUlimit_enum_CU_LIMIT_MAX_L2_FETCH_GRANULARITY if value > 128 => {
+                return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE)
+            }
+            CUlimit_enum_CU_LIMIT_PERSISTING_L2_CACHE_SIZE if value > 3 << 20 => {
+                return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE)
+            }
+            _ => {}
+        }
+
+        match st.contexts.get_mut(&ctx).unwrap().limits.get_mut(&limit) {
+            Some(v) => {
+                *v = value;
+                Ok(())
+            }
+            None => Err(cudaError_enum_CUDA_ERROR_UNSUPPORTED_LIMIT),
+        }
+    }
+
+    fn cuCtxGetLimit(pvalue: *mut usize, limit: CUlimit) {
+        let st = lock()?;
+        let ctx = st.current_context()?;
+        let value = *st.contexts[&ctx]
+            .limits
+            .get(&limit)
+            .ok_or(cudaError_enum_CUDA_ERROR_UNSUPPORTED_LIMIT)?;
+
+        write(pvalue, value)
+    }
+
+    fn cuCtxSetCacheConfig(config: CUfunc_cache) {
+        let mut st = lock()?;
+        let ctx = st.current_context()?;
+        if config > CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_EQUAL {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        st.contexts.get_mut(&ctx).unwrap().cache_config = config;
+
+        Ok(())
+    }
+
+    fn cuCtxGetCacheConfig(pconfig: *mut CUfunc_cache) {
+        let st = lock()?;
+        let ctx = st.current_context()?;
+
+        write(pconfig, st.contexts[&ctx].cache_config)
+    }
+
+    fn cuCtxSetSharedMemConfig(config: CUsharedconfig) {
+        let mut st = lock()?;
+        let ctx = st.current_context()?;
+        if config > CUsharedconfig_enum_CU_SHARED_MEM_CONFIG_EIGHT_BYTE_BANK_SIZE {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        st.contexts.get_mut(&ctx).unwrap().shared_mem_config = config;
+
+        Ok(())
+    }
+
+    fn cuCtxGetSharedMemConfig(pconfig: *mut CUsharedconfig) {
+        let st = lock()?;
+        let ctx = st.current_context()?;
+
+        write(pconfig, st.contexts[&ctx].shared_mem_config)
+    }
+
+    fn cuCtxGetStreamPriorityRange(least_priority: *mut c_int, greatest_priority: *mut c_int) {
+        let st = lock()?;
+        st.current_context()?;
+        // Either pointer may be NULL.
+        if !least_priority.is_null() {
+            *least_priority = STREAM_PRIORITIES.0;
+        }
+        if !greatest_priority.is_null() {
+            *greatest_priority = STREAM_PRIORITIES.1;
+        }
+
+        Ok(())
+    }
+
+    fn cuCtxGetApiVersion(ctx: CUcontext, version: *mut c_uint) {
+        let st = lock()?;
+        let key = match ctx as usize {
+            0 => st.current_context()?,
+            key => key,
+        };
+        if !st.contexts.contains_key(&key) {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT);
+        }
+
+        write(version, 3020)
+    }
+
+    fn cuCtxGetDevice(device: *mut CUdevice) {
+        let st = lock()?;
+        let ctx = st.current_context()?;
+
+        write(device, st.contexts[&ctx].device)
+    }
+
+    fn cuDeviceCanAccessPeer(can_access_peer: *mut c_int, dev: CUdevice, peer_dev: CUdevice) {
+        let st = lock()?;
+        st.check_device(dev)?;
+        st.check_device(peer_dev)?;
+
+        write(can_access_peer, (dev != peer_dev) as c_int)
+    }
+
+    fn cuDeviceGetP2PAttribute(
+        value: *mut c_int,
+        attrib: CUdevice_P2PAttribute,
+        src_device: CUdevice,
+        dst_device: CUdevice,
+    ) {
+        let st = lock()?;
+        st.check_device(src_device)?;
+        st.check_device(dst_device)?;
+        if src_device == dst_device {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_DEVICE);
+        }
+
+        let v = match attrib {
+            CUdevice_P2PAttribute_enum_CU_DEVICE_P2P_ATTRIBUTE_PERFORMANCE_RANK => 0,
+            CUdevice_P2PAttribute_enum_CU_DEVICE_P2P_ATTRIBUTE_ACCESS_SUPPORTED => 1,
+            CUdevice_P2PAttribute_enum_CU_DEVICE_P2P_ATTRIBUTE_NATIVE_ATOMIC_SUPPORTED => 1,
+            CUdevice_P2PAttribute_enum_CU_DEVICE_P2P_ATTRIBUTE_CUDA_ARRAY_ACCESS_SUPPORTED => 0,
+            _ => return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        };
+        write(value, v)
+    }
+
+    fn cuCtxEnablePeerAccess(peer_context: CUcontext, flags: c_uint) {
+        let mut st = lock()?;
+        let ctx = st.current_context()?;
+        let peer = peer_context as usize;
+        if flags != 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        let peer_device = st
+            .contexts
+            .get(&peer)
+            .ok_or(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT)?
+            .device;
+        if peer_device == st.contexts[&ctx].device {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_DEVICE);
+        }
+
+        if st.contexts.get_mut(&ctx).unwrap().peers.insert(peer) {
+            Ok(())
+        } else {
+            Err(cudaError_enum_CUDA_ERROR_PEER_ACCESS_ALREADY_ENABLED)
+        }
+    }
+
+    fn cuCtxDisablePeerAccess(peer_context: CUcontext) {
+        let mut st = lock()?;
+        let ctx = st.current_context()?;
+
+        if st.contexts.get_mut(&ctx).unwrap().peers.remove(&(peer_context as usize)) {
+            Ok(())
+        } else {
+            Err(cudaError_enum_CUDA_ERROR_PEER_ACCESS_NOT_ENABLED)
+        }
+    }
+
+    fn cuCtxSynchronize() {
+        let mut st = lock()?;
+        let ctx = st.current_context()?;
+        st.check_capture_safe()?;
+
+        let streams: Vec<_> = st
+            .streams
+            .iter()
+            .filter(|(&key, s)| s.ctx == ctx || key == LEGACY_STREAM || key == PER_THREAD_STREAM)
+            .map(|(&key, _)| key)
+            .collect();
+        for stream in streams {
+            st.drain(stream);
+        }
+
+        Ok(())
+    }
+
+    fn cuStreamCreate(stream: *mut CUstream, flags: c_uint) {
+        create_stream(stream, flags, 0)
+    }
+
+    fn cuStreamCreateWithPriority(stream: *mut CUstream, flags: c_uint, priority: c_int) {
+        // Out of range priorities are clamped, as with the real driver.
+        let (least, greatest) = STREAM_PRIORITIES;
+        create_stream(stream, flags, priority.clamp(greatest, least))
+    }
+
+    fn cuStreamGetFlags(stream: CUstream, flags: *mut c_uint) {
+        let st = lock()?;
+        let key = st.stream_key(stream)?;
+
+        write(flags, st.streams[&key].flags)
+    }
+
+    fn cuStreamGetPriority(stream: CUstream, priority: *mut c_int) {
+        let st = lock()?;
+        let key = st.stream_key(stream)?;
+
+        write(priority, st.streams[&key].priority)
+    }
+
+    #[cfg(not(cuda = "11080"))]
+    fn cuStreamGetId(stream: CUstream, stream_id: *mut std::os::raw::c_ulonglong) {
+        let st = lock()?;
+        let key = st.stream_key(stream)?;
+
+        write(stream_id, key as _)
+    }
+
+    fn cuStreamGetAttribute(stream: CUstream, attr: CUstreamAttrID, value_out: *mut CUstreamAttrValue) {
+        let st = lock()?;
+        let key = st.stream_key(stream)?;
+        let s = &st.streams[&key];
+
+        let mut value: CUstreamAttrValue = std::mem::zeroed();
+        match attr {
+            CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_ACCESS_POLICY_WINDOW => {
+                let policy = s.access_policy;
+                value.accessPolicyWindow = CUaccessPolicyWindow {
+                    base_ptr: policy.base as *mut c_void,
+                    num_bytes: policy.num_bytes,
+                    hitRatio: policy.hit_ratio,
+                    hitProp: policy.hit_prop,
+                    missProp: policy.miss_prop,
+                };
+            }
+            CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_SYNCHRONIZATION_POLICY => {
+                value.syncPolicy = s.sync_policy;
+            }
+            CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_PRIORITY => value.priority = s.priority,
+            _ => return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        }
+
+        write(value_out, value)
+    }
+
+    fn cuStreamSetAttribute(stream: CUstream, attr: CUstreamAttrID, value: *const CUstreamAttrValue) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        let device = st.contexts[&st.streams[&key].ctx].device;
+        let value = value.as_ref().ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        let s = st.streams.get_mut(&key).unwrap();
+
+        match attr {
+            CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_ACCESS_POLICY_WINDOW => {
+                let window = value.accessPolicyWindow;
+                let max_bytes = device_attribute(
+                    CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_ACCESS_POLICY_WINDOW_SIZE,
+                    device,
+                ) as usize;
+                let max_prop = CUaccessProperty_enum_CU_ACCESS_PROPERTY_PERSISTING;
+                if window.num_bytes > max_bytes
+                    || !(0.0..=1.0).contains(&window.hitRatio)
+                    || window.hitProp > max_prop
+                    || window.missProp > max_prop
+                {
+                    return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+                }
+
+                s.access_policy = AccessPolicy {
+                    base: window.base_ptr as usize,
+                    num_bytes: window.num_bytes,
+                    hit_ratio: window.hitRatio,
+                    hit_prop: window.hitProp,
+                    miss_prop: window.missProp,
+                };
+            }
+            CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_SYNCHRONIZATION_POLICY => {
+                let policy = value.syncPolicy;
+                if !(CUsynchronizationPolicy_enum_CU_SYNC_POLICY_AUTO
+                    ..=CUsynchronizationPolicy_enum_CU_SYNC_POLICY_BLOCKING_SYNC)
+                    .contains(&policy)
+                {
+                    return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+                }
+                s.sync_policy = policy;
+            }
+            CUlaunchAttributeID_enum_CU_LAUNCH_ATTRIBUTE_PRIORITY => {
+                let (least, greatest) = STREAM_PRIORITIES;
+                s.priority = value.priority.clamp(greatest, least);
+            }
+            _ => return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        }
+
+        Ok(())
+    }
+
+    fn cuStreamCopyAttributes(dst: CUstream, src: CUstream) {
+        let mut st = lock()?;
+        let src = &st.streams[&st.stream_key(src)?];
+        let (access_policy, sync_policy, priority) = (src.access_policy, src.sync_policy, src.priority);
+        let key = st.stream_key(dst)?;
+        let dst = st.streams.get_mut(&key).unwrap();
+
+        dst.access_policy = access_policy;
+        dst.sync_policy = sync_policy;
+        dst.priority = priority;
+
+        Ok(())
+    }
+
+    fn cuStreamDestroy_v2(stream: CUstream) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        if key == LEGACY_STREAM || key == PER_THREAD_STREAM {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_HANDLE);
+        }
+
+        // Pending work still completes, as with the real driver.
+        st.drain(key);
+        st.streams.remove(&key);
+
+        Ok(())
+    }
+
+    fn cuStreamSynchronize(stream: CUstream) {
+        // A value wait can only be satisfied by another thread, so wait for
+        // it with the state unlocked. It blocks forever if that never
+        // happens, as on real hardware.
+        loop {
+            let mut st = lock()?;
+            let key = st.stream_key(stream)?;
+            check_not_capturing(&mut st, key)?;
+            if st.drain(key) {
+                return Ok(());
+            }
+            drop(st);
+            std::thread::yield_now();
+        }
+    }
+
+    fn cuStreamQuery(stream: CUstream) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        check_not_capturing(&mut st, key)?;
+        let target = st.streams[&key].enqueued;
+        if poll(&mut st, key, target) {
+            Ok(())
+        } else {
+            Err(cudaError_enum_CUDA_ERROR_NOT_READY)
+        }
+    }
+
+    fn cuStreamBeginCapture_v2(stream: CUstream, mode: CUstreamCaptureMode) {
+        let mut st = lock()?;
+        let key = current_stream(&st, stream)?;
+
+        st.begin_capture(key, mode)
+    }
+
+    fn cuStreamEndCapture(stream: CUstream, graph: *mut CUgraph) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        if graph.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        // The graph is NULL if the capture failed.
+        let res = st.end_capture(key);
+        write(graph, graph_handle(*res.as_ref().unwrap_or(&0)))?;
+
+        res.map(|_| ())
+    }
+
+    fn cuStreamIsCapturing(stream: CUstream, status: *mut CUstreamCaptureStatus) {
+        let st = lock()?;
+        let key = st.stream_key(stream)?;
+
+        write(status, capture_status(&st, key))
+    }
+
+    fn cuStreamGetCaptureInfo_v2(
+        stream: CUstream,
+        status_out: *mut CUstreamCaptureStatus,
+        id_out: *mut cuuint64_t,
+        graph_out: *mut CUgraph,
+        dependencies_out: *mut *const CUgraphNode,
+        num_dependencies_out: *mut usize,
+    ) {
+        let st = lock()?;
+        let key = st.stream_key(stream)?;
+
+        write(status_out, capture_status(&st, key))?;
+        // The other outputs are optional, and only set while capturing.
+        let Some(ref capture) = st.streams[&key].capture else {
+            return Ok(());
+        };
+        if !id_out.is_null() {
+            *id_out = capture.id;
+        }
+        if !graph_out.is_null() {
+            *graph_out = graph_handle(st.captures[&capture.id].graph);
+        }
+        if !dependencies_out.is_null() {
+            // Valid until the dependencies of the stream change, as with
+            // the real driver.
+            *dependencies_out = capture.deps.as_ptr() as *const CUgraphNode;
+        }
+        if !num_dependencies_out.is_null() {
+            *num_dependencies_out = capture.deps.len();
+        }
+
+        Ok(())
+    }
+
+    fn cuGraphDestroy(graph: CUgraph) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        st.destroy_graph(key);
+
+        Ok(())
+    }
+
+    fn cuGraphGetNodes(graph: CUgraph, nodes: *mut CUgraphNode, num_nodes: *mut usize) {
+        let st = lock()?;
+        let key = graph_key(&st, graph)?;
+
+        write_nodes(&st.graphs[&key].nodes, nodes, num_nodes)
+    }
+
+    fn cuGraphGetEdges(graph: CUgraph, from: *mut CUgraphNode, to: *mut CUgraphNode, num_edges: *mut usize) {
+        let st = lock()?;
+        let key = graph_key(&st, graph)?;
+        if num_edges.is_null() || from.is_null() != to.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        let edges: Vec<_> = st.graphs[&key]
+            .nodes
+            .iter()
+            .flat_map(|&node| st.nodes[&node].deps.iter().map(move |&dep| (dep, node)))
+            .collect();
+        if !from.is_null() {
+            let count = edges.len().min(*num_edges);
+            for (i, &(a, b)) in edges.iter().take(count).enumerate() {
+                *from.add(i) = node_handle(a);
+                *to.add(i) = node_handle(b);
+            }
+            *num_edges = count;
+        } else {
+            *num_edges = edges.len();
+        }
+
+        Ok(())
+    }
+
+    fn cuGraphNodeGetType(node: CUgraphNode, type_: *mut CUgraphNodeType) {
+        let st = lock()?;
+        let key = node_key(&st, node)?;
+
+        write(type_, node_type(&st.nodes[&key].kind))
+    }
+
+    fn cuGraphNodeGetDependencies(node: CUgraphNode, deps: *mut CUgraphNode, num_deps: *mut usize) {
+        let st = lock()?;
+        let key = node_key(&st, node)?;
+
+        write_nodes(&st.nodes[&key].deps, deps, num_deps)
+    }
+
+    fn cuGraphCreate(graph: *mut CUgraph, flags: c_uint) {
+        let mut st = lock()?;
+        st.current_context()?;
+        if flags != 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        let key = st.create_graph();
+        write(graph, graph_handle(key))
+    }
+
+    fn cuGraphClone(clone: *mut CUgraph, original: CUgraph) {
+        let mut st = lock()?;
+        let key = graph_key(&st, original)?;
+        if clone.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        if st.has_mem_nodes(key) {
+            return Err(cudaError_enum_CUDA_ERROR_NOT_SUPPORTED);
+        }
+
+        let key = st.clone_graph(key);
+        write(clone, graph_handle(key))
+    }
+
+    fn cuGraphAddEmptyNode(node: *mut CUgraphNode, graph: CUgraph, deps: *const CUgraphNode, num_deps: usize) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        let deps = node_deps(&st, key, deps, num_deps)?;
+
+        add_node(&mut st, node, key, deps, NodeKind::Empty)
+    }
+
+    #[cfg(cuda = "11080")]
+    fn cuGraphAddKernelNode(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        params: *const CUDA_KERNEL_NODE_PARAMS,
+    ) {
+        add_kernel_node(node, graph, deps, num_deps, params)
+    }
+
+    #[cfg(not(cuda = "11080"))]
+    fn cuGraphAddKernelNode_v2(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        params: *const CUDA_KERNEL_NODE_PARAMS,
+    ) {
+        add_kernel_node(node, graph, deps, num_deps, params)
+    }
+
+    fn cuGraphAddMemcpyNode(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        params: *const CUDA_MEMCPY3D,
+        ctx: CUcontext,
+    ) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        let deps = node_deps(&st, key, deps, num_deps)?;
+        check_context(&st, ctx)?;
+        let p = params.as_ref().ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        // Only 2D copies are simulated.
+        if p.Depth != 1 || p.srcZ != 0 || p.dstZ != 0 {
+            return Err(cudaError_enum_CUDA_ERROR_NOT_SUPPORTED);
+        }
+
+        let p = CUDA_MEMCPY2D {
+            srcXInBytes: p.srcXInBytes,
+            srcY: p.srcY,
+            srcMemoryType: p.srcMemoryType,
+            srcHost: p.srcHost,
+            srcDevice: p.srcDevice,
+            srcArray: p.srcArray,
+            srcPitch: p.srcPitch,
+            dstXInBytes: p.dstXInBytes,
+            dstY: p.dstY,
+            dstMemoryType: p.dstMemoryType,
+            dstHost: p.dstHost,
+            dstDevice: p.dstDevice,
+            dstArray: p.dstArray,
+            dstPitch: p.dstPitch,
+            WidthInBytes: p.WidthInBytes,
+            Height: p.Height,
+        };
+        // Graphs can't copy pageable host memory.
+        match copy_2d_op(&st, &p)? {
+            Some((op, true)) => add_node(&mut st, node, key, deps, NodeKind::Op(op)),
+            _ => Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        }
+    }
+
+    fn cuGraphAddMemsetNode(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        params: *const CUDA_MEMSET_NODE_PARAMS,
+        ctx: CUcontext,
+    ) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        let deps = node_deps(&st, key, deps, num_deps)?;
+        check_context(&st, ctx)?;
+        let p = params.as_ref().ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        if !matches!(p.elementSize, 1 | 2 | 4) || p.width == 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        // Only 1D memsets are simulated.
+        if p.height != 1 {
+            return Err(cudaError_enum_CUDA_ERROR_NOT_SUPPORTED);
+        }
+
+        let op = memset_op(&st, p.dst, p.value, p.elementSize as usize, p.width)?;
+        add_node(&mut st, node, key, deps, NodeKind::Op(op))
+    }
+
+    fn cuGraphAddHostNode(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        params: *const CUDA_HOST_NODE_PARAMS,
+    ) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        let deps = node_deps(&st, key, deps, num_deps)?;
+        let p = params.as_ref().ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        let func = p.fn_.ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+
+        add_node(&mut st, node, key, deps, NodeKind::Op(Op::HostFn { func, data: p.userData as usize }))
+    }
+
+    fn cuGraphAddEventRecordNode(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        event: CUevent,
+    ) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        let deps = node_deps(&st, key, deps, num_deps)?;
+        let event = st.event_key(event)?;
+
+        add_node(&mut st, node, key, deps, NodeKind::EventRecord { event })
+    }
+
+    fn cuGraphAddEventWaitNode(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        event: CUevent,
+    ) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        let deps = node_deps(&st, key, deps, num_deps)?;
+        let event = st.event_key(event)?;
+
+        add_node(&mut st, node, key, deps, NodeKind::EventWait { event })
+    }
+
+    fn cuGraphAddChildGraphNode(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        child: CUgraph,
+    ) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        let deps = node_deps(&st, key, deps, num_deps)?;
+        let child = graph_key(&st, child)?;
+        if child == key || node.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        if st.has_mem_nodes(child) {
+            return Err(cudaError_enum_CUDA_ERROR_NOT_SUPPORTED);
+        }
+
+        let child = st.clone_graph(child);
+        add_node(&mut st, node, key, deps, NodeKind::Child { graph: child })
+    }
+
+    fn cuGraphAddMemAllocNode(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        params: *mut CUDA_MEM_ALLOC_NODE_PARAMS,
+    ) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        let deps = node_deps(&st, key, deps, num_deps)?;
+        let p = params.as_mut().ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        let props = &p.poolProps;
+        if node.is_null()
+            || props.allocType != CUmemAllocationType_enum_CU_MEM_ALLOCATION_TYPE_PINNED
+            || props.location.type_ != CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_DEVICE
+        {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        st.check_device(props.location.id)?;
+
+        // The memory is reserved as long as the graph, or a copy of it,
+        // exists.
+        let ptr = st.allocate(p.bytesize, true)?;
+        let object = st.create_user_object(Destructor::Free { ptr }, 1);
+        st.graphs.get_mut(&key).unwrap().user_objects.insert(object, 1);
+        p.dptr = ptr as CUdeviceptr;
+
+        let kind = NodeKind::MemAlloc { ptr, size: p.bytesize, device: p.poolProps.location.id };
+        add_node(&mut st, node, key, deps, kind)
+    }
+
+    fn cuGraphAddMemFreeNode(
+        node: *mut CUgraphNode,
+        graph: CUgraph,
+        deps: *const CUgraphNode,
+        num_deps: usize,
+        dptr: CUdeviceptr,
+    ) {
+        let mut st = lock()?;
+        let key = graph_key(&st, graph)?;
+        let deps = node_deps(&st, key, deps, num_deps)?;
+        if !st.is_graph_allocation(dptr as usize) {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        add_node(&mut st, node, key, deps, NodeKind::MemFree { ptr: dptr as usize })
+    }
+
+    #[cfg(cuda = "11080")]
+    fn cuGraphKernelNodeGetParams(node: CUgraphNode, params: *mut CUDA_KERNEL_NODE_PARAMS) {
+        kernel_node_params(node, params)
+    }
+
+    #[cfg(not(cuda = "11080"))]
+    fn cuGraphKernelNodeGetParams_v2(node: CUgraphNode, params: *mut CUDA_KERNEL_NODE_PARAMS) {
+        kernel_node_params(node, params)
+    }
+
+    fn cuGraphMemcpyNodeGetParams(node: CUgraphNode, params: *mut CUDA_MEMCPY3D) {
+        let st = lock()?;
+        let (dst, dst_pitch, src, src_pitch, width, height) =
+            match *node_kind(&st, node, CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEMCPY)? {
+                NodeKind::Op(Op::Copy { dst, src, len }) => (dst, len, src, len, len, 1),
+                NodeKind::Op(Op::Copy2D { dst, dst_pitch, src, src_pitch, width, height }) => {
+                    (dst, dst_pitch, src, src_pitch, width, height)
+                }
+                _ => unreachable!(),
+            };
+        if params.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        let mut p: CUDA_MEMCPY3D = std::mem::zeroed();
+        p.srcMemoryType = memory_type(&st, src, (height - 1) * src_pitch + width);
+        if p.srcMemoryType == CUmemorytype_enum_CU_MEMORYTYPE_HOST {
+            p.srcHost = src as *const c_void;
+        } else {
+            p.srcDevice = src as CUdeviceptr;
+        }
+        p.srcPitch = src_pitch;
+        p.dstMemoryType = memory_type(&st, dst, (height - 1) * dst_pitch + width);
+        if p.dstMemoryType == CUmemorytype_enum_CU_MEMORYTYPE_HOST {
+            p.dstHost = dst as *mut c_void;
+        } else {
+            p.dstDevice = dst as CUdeviceptr;
+        }
+        p.dstPitch = dst_pitch;
+        p.WidthInBytes = width;
+        p.Height = height;
+        p.Depth = 1;
+
+        write(params, p)
+    }
+
+    fn cuGraphMemsetNodeGetParams(node: CUgraphNode, params: *mut CUDA_MEMSET_NODE_PARAMS) {
+        let st = lock()?;
+        let &NodeKind::Op(Op::Memset { dst, value, elem_size, count }) =
+            node_kind(&st, node, CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEMSET)?
+        else {
+            unreachable!()
+        };
+
+        write(params, CUDA_MEMSET_NODE_PARAMS {
+            dst: dst as CUdeviceptr,
+            pitch: 0,
+            value,
+            elementSize: elem_size as c_uint,
+            width: count,
+            height: 1,
+        })
+    }
+
+    fn cuGraphHostNodeGetParams(node: CUgraphNode, params: *mut CUDA_HOST_NODE_PARAMS) {
+        let st = lock()?;
+        let &NodeKind::Op(Op::HostFn { func, data }) =
+            node_kind(&st, node, CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_HOST)?
+        else {
+            unreachable!()
+        };
+
+        write(params, CUDA_HOST_NODE_PARAMS { fn_: Some(func), userData: data as *mut c_void })
+    }
+
+    fn cuGraphChildGraphNodeGetGraph(node: CUgraphNode, graph: *mut CUgraph) {
+        let st = lock()?;
+        let &NodeKind::Child { graph: child } = node_kind(&st, node, CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_GRAPH)?
+        else {
+            unreachable!()
+        };
+
+        write(graph, graph_handle(child))
+    }
+
+    fn cuGraphEventRecordNodeGetEvent(node: CUgraphNode, event: *mut CUevent) {
+        let st = lock()?;
+        let &NodeKind::EventRecord { event: e } =
+            node_kind(&st, node, CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EVENT_RECORD)?
+        else {
+            unreachable!()
+        };
+
+        write(event, event_handle(e))
+    }
+
+    fn cuGraphEventWaitNodeGetEvent(node: CUgraphNode, event: *mut CUevent) {
+        let st = lock()?;
+        let &NodeKind::EventWait { event: e } = node_kind(&st, node, CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_WAIT_EVENT)?
+        else {
+            unreachable!()
+        };
+
+        write(event, event_handle(e))
+    }
+
+    fn cuGraphMemAllocNodeGetParams(node: CUgraphNode, params: *mut CUDA_MEM_ALLOC_NODE_PARAMS) {
+        let st = lock()?;
+        let &NodeKind::MemAlloc { ptr, size, device } =
+            node_kind(&st, node, CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEM_ALLOC)?
+        else {
+            unreachable!()
+        };
+        if params.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        let mut p: CUDA_MEM_ALLOC_NODE_PARAMS = std::mem::zeroed();
+        p.poolProps.allocType = CUmemAllocationType_enum_CU_MEM_ALLOCATION_TYPE_PINNED;
+        p.poolProps.location.type_ = CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_DEVICE;
+        p.poolProps.location.id = device;
+        p.bytesize = size;
+        p.dptr = ptr as CUdeviceptr;
+
+        write(params, p)
+    }
+
+    fn cuGraphMemFreeNodeGetParams(node: CUgraphNode, dptr: *mut CUdeviceptr) {
+        let st = lock()?;
+        let &NodeKind::MemFree { ptr } = node_kind(&st, node, CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEM_FREE)?
+        else {
+            unreachable!()
+        };
+
+        write(dptr, ptr as CUdeviceptr)
+    }
+
+    fn cuGraphInstantiateWithFlags(exec: *mut CUgraphExec, graph: CUgraph, flags: std::os::raw::c_ulonglong) {
+        let mut st = lock()?;
+        st.current_context()?;
+        let key = graph_key(&st, graph)?;
+        if exec.is_null() || flags != 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        let key = st.instantiate(key);
+        write(exec, key as CUgraphExec)
+    }
+
+    fn cuGraphLaunch(exec: CUgraphExec, stream: CUstream) {
+        let mut st = lock()?;
+        let key = exec_key(&st, exec)?;
+        let stream = current_stream(&st, stream)?;
+
+        st.launch(key, stream)
+    }
+
+    fn cuGraphExecDestroy(exec: CUgraphExec) {
+        let mut st = lock()?;
+        let key = exec_key(&st, exec)?;
+        st.destroy_exec(key);
+
+        Ok(())
+    }
+
+    #[cfg(cuda = "11080")]
+    fn cuGraphExecUpdate(
+        exec: CUgraphExec,
+        graph: CUgraph,
+        error_node: *mut CUgraphNode,
+        result: *mut CUgraphExecUpdateResult,
+    ) {
+        let mut st = lock()?;
+        let exec = exec_key(&st, exec)?;
+        let graph = graph_key(&st, graph)?;
+        if error_node.is_null() || result.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        let (res, node, _) = match st.update_exec(exec, graph) {
+            Ok(()) => (CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_SUCCESS, 0, 0),
+            Err(err) => err,
+        };
+        *error_node = node_handle(node);
+        *result = res;
+        if res != CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_SUCCESS {
+            return Err(cudaError_enum_CUDA_ERROR_GRAPH_EXEC_UPDATE_FAILURE);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(cuda = "11080"))]
+    fn cuGraphExecUpdate_v2(exec: CUgraphExec, graph: CUgraph, result_info: *mut CUgraphExecUpdateResultInfo) {
+        let mut st = lock()?;
+        let exec = exec_key(&st, exec)?;
+        let graph = graph_key(&st, graph)?;
+
+        let (result, node, from) = match st.update_exec(exec, graph) {
+            Ok(()) => (CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_SUCCESS, 0, 0),
+            Err(err) => err,
+        };
+        write(result_info, CUgraphExecUpdateResultInfo {
+            result,
+            errorNode: node_handle(node),
+            errorFromNode: node_handle(from),
+        })?;
+        if result != CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_SUCCESS {
+            return Err(cudaError_enum_CUDA_ERROR_GRAPH_EXEC_UPDATE_FAILURE);
+        }
+
+        Ok(())
+    }
+
+    fn cuUserObjectCreate(
+        object: *mut CUuserObject,
+        ptr: *mut c_void,
+        destroy: CUhostFn,
+        initial_refcount: c_uint,
+        flags: c_uint,
+    ) {
+        let mut st = lock()?;
+        let func = destroy.ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        if object.is_null()
+            || initial_refcount == 0
+            || flags != CUuserObject_flags_enum_CU_USER_OBJECT_NO_DESTRUCTOR_SYNC
+        {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        let key = st.create_user_object(Destructor::HostFn { func, data: ptr as usize }, initial_refcount);
+        write(object, key as CUuserObject)
+    }
+
+    fn cuUserObjectRetain(object: CUuserObject, count: c_uint) {
+        let mut st = lock()?;
+        let key = user_object_key(&st, object)?;
+        if count == 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        st.user_objects.get_mut(&key).unwrap().refs += count;
+
+        Ok(())
+    }
+
+    fn cuUserObjectRelease(object: CUuserObject, count: c_uint) {
+        let mut st = lock()?;
+        let key = user_object_key(&st, object)?;
+        if count == 0 || count > st.user_objects[&key].refs {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        st.release_user_object(key, count);
+
+        Ok(())
+    }
+
+    fn cuGraphRetainUserObject(graph: CUgraph, object: CUuserObject, count: c_uint, flags: c_uint) {
+        let mut st = lock()?;
+        let graph = graph_key(&st, graph)?;
+        let key = user_object_key(&st, object)?;
+        let moved = match flags {
+            0 => false,
+            CUuserObjectRetain_flags_enum_CU_GRAPH_USER_OBJECT_MOVE => true,
+            _ => return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        };
+        if count == 0 || (moved && count > st.user_objects[&key].refs) {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        // Moved references are taken from the caller.
+        if !moved {
+            st.user_objects.get_mut(&key).unwrap().refs += count;
+        }
+        *st.graphs.get_mut(&graph).unwrap().user_objects.entry(key).or_default() += count;
+
+        Ok(())
+    }
+
+    fn cuGraphReleaseUserObject(graph: CUgraph, object: CUuserObject, count: c_uint) {
+        let mut st = lock()?;
+        let graph = graph_key(&st, graph)?;
+        let key = user_object_key(&st, object)?;
+        let user_objects = &mut st.graphs.get_mut(&graph).unwrap().user_objects;
+        let owned = user_objects.get(&key).copied().unwrap_or(0);
+        if count == 0 || count > owned {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        if count == owned {
+            user_objects.remove(&key);
+        } else {
+            user_objects.insert(key, owned - count);
+        }
+        st.release_user_object(key, count);
+
+        Ok(())
+    }
+
+    fn cuStreamGetCtx(stream: CUstream, pctx: *mut CUcontext) {
+        let st = lock()?;
+        let key = st.stream_key(stream)?;
+        let ctx = match st.streams[&key].ctx {
+            0 => st.current_context()?,
+            ctx => ctx,
+        };
+
+        write(pctx, context_handle(ctx))
+    }
+
+    fn cuLaunchHostFunc(stream: CUstream, func: CUhostFn, data: *mut c_void) {
+        let mut st = lock()?;
+        let key = current_stream(&st, stream)?;
+        let func = func.ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        st.enqueue(key, Op::HostFn { func, data: data as usize }, false)?;
+
+        Ok(())
+    }
+
+    fn cuStreamAddCallback(
+        stream: CUstream,
+        callback: CUstreamCallback,
+        data: *mut c_void,
+        flags: c_uint,
+    ) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        let func = callback.ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        if flags != 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        if st.streams[&key].capture.is_some() {
+            return Err(st.invalidate_capture(key, cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNSUPPORTED));
+        }
+        st.enqueue(key, Op::Callback { func, data: data as usize }, false)?;
+
+        Ok(())
+    }
+
+    fn cuStreamWaitValue32_v2(stream: CUstream, addr: CUdeviceptr, value: cuuint32_t, flags: c_uint) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        let op = wait_value_op(&st, addr, value as u64, false, flags)?;
+        st.enqueue(key, op, false)?;
+
+        Ok(())
+    }
+
+    fn cuStreamWaitValue64_v2(stream: CUstream, addr: CUdeviceptr, value: cuuint64_t, flags: c_uint) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        let op = wait_value_op(&st, addr, value, true, flags)?;
+        st.enqueue(key, op, false)?;
+
+        Ok(())
+    }
+
+    fn cuStreamWriteValue32_v2(stream: CUstream, addr: CUdeviceptr, value: cuuint32_t, flags: c_uint) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        let op = write_value_op(&st, addr, value as u64, false, flags)?;
+        st.enqueue(key, op, false)?;
+
+        Ok(())
+    }
+
+    fn cuStreamWriteValue64_v2(stream: CUstream, addr: CUdeviceptr, value: cuuint64_t, flags: c_uint) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        let op = write_value_op(&st, addr, value, true, flags)?;
+        st.enqueue(key, op, false)?;
+
+        Ok(())
+    }
+
+    fn cuStreamBatchMemOp_v2(
+        stream: CUstream,
+        count: c_uint,
+        params: *mut CUstreamBatchMemOpParams,
+        flags: c_uint,
+    ) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        if flags != 0 || count == 0 || params.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        // Validate everything before enqueuing anything.
+        let mut ops = Vec::new();
+        for param in std::slice::from_raw_parts(params, count as usize) {
+            match param.operation {
+                CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WAIT_VALUE_32 => {
+                    let p = param.waitValue;
+                    ops.push(wait_value_op(&st, p.address, p.__bindgen_anon_1.value as u64, false, p.flags)?);
+                }
+                CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WAIT_VALUE_64 => {
+                    let p = param.waitValue;
+                    ops.push(wait_value_op(&st, p.address, p.__bindgen_anon_1.value64, true, p.flags)?);
+                }
+                CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WRITE_VALUE_32 => {
+                    let p = param.writeValue;
+                    ops.push(write_value_op(&st, p.address, p.__bindgen_anon_1.value as u64, false, p.flags)?);
+                }
+                CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WRITE_VALUE_64 => {
+                    let p = param.writeValue;
+                    ops.push(write_value_op(&st, p.address, p.__bindgen_anon_1.value64, true, p.flags)?);
+                }
+                // Memory is coherent in the simulation.
+                CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_FLUSH_REMOTE_WRITES
+                | CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_BARRIER => {}
+                _ => return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+            }
+        }
+        for op in ops {
+            st.enqueue(key, op, false)?;
+        }
+
+        Ok(())
+    }
+
+    fn cuMemHostGetDevicePointer_v2(pdptr: *mut CUdeviceptr, p: *mut c_void, flags: c_uint) {
+        let st = lock()?;
+        st.current_context()?;
+        if flags != 0 || !st.check_range(p as usize, 1)? {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        // Host and device share one address space in the simulation.
+        write(pdptr, p as CUdeviceptr)
+    }
+
+    fn cuStreamWaitEvent(stream: CUstream, event: CUevent, flags: c_uint) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        let event = st.event_key(event)?;
+        if flags != 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        match st.events[&event].state {
+            EventState::Captured { capture } => st.capture_wait(key, event, capture)?,
+            // Captured work can only depend on other captured work.
+            EventState::Pending { .. } | EventState::Complete { .. } if st.streams[&key].capture.is_some() => {
+                return Err(st.invalidate_capture(key, cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_ISOLATION));
+            }
+            EventState::Pending { stream: other, seq } if other != key => {
+                st.enqueue(key, Op::Wait { stream: other, seq }, false)?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn cuEventCreate(event: *mut CUevent, flags: c_uint) {
+        let mut st = lock()?;
+        st.current_context()?;
+        let known = CUevent_flags_enum_CU_EVENT_BLOCKING_SYNC
+            | CUevent_flags_enum_CU_EVENT_DISABLE_TIMING
+            | CUevent_flags_enum_CU_EVENT_INTERPROCESS;
+        let interprocess = flags & CUevent_flags_enum_CU_EVENT_INTERPROCESS != 0;
+        let timed = flags & CUevent_flags_enum_CU_EVENT_DISABLE_TIMING == 0;
+        // Interprocess events can't record timestamps.
+        if flags & !known != 0 || (interprocess && timed) {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        let key = st.new_handle();
+        st.events.insert(key, Event { flags, state: EventState::Unrecorded, captured: Vec::new(), handles: 1 });
+
+        write(event, event_handle(key))
+    }
+
+    fn cuEventDestroy_v2(event: CUevent) {
+        let mut st = lock()?;
+        let key = st.event_key(event)?;
+        st.event_aliases.remove(&(event as usize));
+        let e = st.events.get_mut(&key).unwrap();
+        e.handles -= 1;
+        if e.handles == 0 {
+            st.events.remove(&key);
+        }
+
+        Ok(())
+    }
+
+    fn cuIpcGetEventHandle(handle: *mut CUipcEventHandle, event: CUevent) {
+        let st = lock()?;
+        let key = st.event_key(event)?;
+        if st.events[&key].flags & CUevent_flags_enum_CU_EVENT_INTERPROCESS == 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        // The handle is the key of the event, since the mock is a single
+        // process.
+        let mut reserved = [0; 64];
+        for (dst, src) in reserved.iter_mut().zip(key.to_le_bytes()) {
+            *dst = src as c_char;
+        }
+        write(handle, CUipcEventHandle { reserved })
+    }
+
+    fn cuIpcOpenEventHandle(event: *mut CUevent, handle: CUipcEventHandle) {
+        let mut st = lock()?;
+        st.current_context()?;
+        if event.is_null() {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        let mut bytes = [0; std::mem::size_of::<usize>()];
+        for (dst, &src) in bytes.iter_mut().zip(&handle.reserved) {
+            *dst = src as u8;
+        }
+        let key = usize::from_le_bytes(bytes);
+        match st.events.get_mut(&key) {
+            Some(e) if e.flags & CUevent_flags_enum_CU_EVENT_INTERPROCESS != 0 => e.handles += 1,
+            _ => return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        }
+
+        let alias = st.new_handle();
+        st.event_aliases.insert(alias, key);
+        write(event, event_handle(alias))
+    }
+
+    fn cuEventRecord(event: CUevent, stream: CUstream) {
+        let mut st = lock()?;
+        let event = st.event_key(event)?;
+        let stream = st.stream_key(stream)?;
+        st.record(event, stream)
+    }
+
+    fn cuEventQuery(event: CUevent) {
+        let mut st = lock()?;
+        let key = st.event_key(event)?;
+        match poll_event(&mut st, key) {
+            EventState::Pending { .. } => Err(cudaError_enum_CUDA_ERROR_NOT_READY),
+            EventState::Captured { .. } => Err(cudaError_enum_CUDA_ERROR_CAPTURED_EVENT),
+            _ => Ok(()),
+        }
+    }
+
+    fn cuEventSynchronize(event: CUevent) {
+        let mut st = lock()?;
+        let key = st.event_key(event)?;
+        match st.events[&key].state {
+            EventState::Pending { stream, seq } => {
+                st.advance(stream, seq, true);
+            }
+            EventState::Captured { .. } => return Err(cudaError_enum_CUDA_ERROR_CAPTURED_EVENT),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn cuEventElapsedTime(ms: *mut f32, start: CUevent, end: CUevent) {
+        let mut st = lock()?;
+        let start = st.event_key(start)?;
+        let end = st.event_key(end)?;
+        for event in [start, end] {
+            if st.events[&event].flags & CUevent_flags_enum_CU_EVENT_DISABLE_TIMING != 0 {
+                return Err(cudaError_enum_CUDA_ERROR_INVALID_HANDLE);
+            }
+        }
+
+        match (poll_event(&mut st, start), poll_event(&mut st, end)) {
+            (EventState::Complete { time_ms: t0 }, EventState::Complete { time_ms: t1 }) => {
+                write(ms, (t1 - t0) as f32)
+            }
+            (EventState::Unrecorded, _) | (_, EventState::Unrecorded) => {
+                Err(cudaError_enum_CUDA_ERROR_INVALID_HANDLE)
+            }
+            (EventState::Captured { .. }, _) | (_, EventState::Captured { .. }) => {
+                Err(cudaError_enum_CUDA_ERROR_CAPTURED_EVENT)
+            }
+            _ => Err(cudaError_enum_CUDA_ERROR_NOT_READY),
+        }
+    }
+
+    fn cuMemGetInfo_v2(free: *mut usize, total: *mut usize) {
+        let st = lock()?;
+        st.current_context()?;
+
+        write(free, st.config.total_memory - st.device_bytes)?;
+        write(total, st.config.total_memory)
+    }
+
+    fn cuMemAlloc_v2(dptr: *mut CUdeviceptr, size: usize) {
+        let mut st = lock()?;
+        st.current_context()?;
+        st.check_capture_safe()?;
+        let ptr = st.allocate(size, true)?;
+
+        write(dptr, ptr as CUdeviceptr)
+    }
+
+    fn cuMemFree_v2(dptr: CUdeviceptr) {
+        let mut st = lock()?;
+        st.check_capture_safe()?;
+        st.begin_free(dptr as usize)?;
+        st.enqueue(LEGACY_STREAM, Op::Free { ptr: dptr as usize }, true)?;
+
+        Ok(())
+    }
+
+    fn cuMemAllocAsync(dptr: *mut CUdeviceptr, size: usize, stream: CUstream) {
+        let mut st = lock()?;
+        let key = current_stream(&st, stream)?;
+        // Allocations are not simulated in captures.
+        check_not_capturing(&mut st, key)?;
+        let ptr = st.allocate(size, true)?;
+
+        write(dptr, ptr as CUdeviceptr)
+    }
+
+    fn cuMemFreeAsync(dptr: CUdeviceptr, stream: CUstream) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        // Allocations are not simulated in captures.
+        check_not_capturing(&mut st, key)?;
+        st.begin_free(dptr as usize)?;
+        st.enqueue(key, Op::Free { ptr: dptr as usize }, false)?;
+
+        Ok(())
+    }
+
+    fn cuMemAllocHost_v2(pp: *mut *mut c_void, size: usize) {
+        let mut st = lock()?;
+        st.current_context()?;
+        st.check_capture_safe()?;
+        let ptr = st.allocate(size, false)?;
+
+        write(pp, ptr as *mut c_void)
+    }
+
+    fn cuMemFreeHost(p: *mut c_void) {
+        let mut st = lock()?;
+        st.check_capture_safe()?;
+        st.drain_all();
+
+        st.free_host(p as usize)
+    }
+
+    fn cuMemcpy(dst: CUdeviceptr, src: CUdeviceptr, len: usize) {
+        let mut st = lock()?;
+        copy(&mut st, dst as usize, src as usize, len, DEFAULT_STREAM)
+    }
+
+    fn cuMemcpyHtoD_v2(dst: CUdeviceptr, src: *const c_void, len: usize) {
+        let mut st = lock()?;
+        st.check_device_range(dst as usize, len)?;
+        copy(&mut st, dst as usize, src as usize, len, DEFAULT_STREAM)
+    }
+
+    fn cuMemcpyDtoH_v2(dst: *mut c_void, src: CUdeviceptr, len: usize) {
+        let mut st = lock()?;
+        st.check_device_range(src as usize, len)?;
+        copy(&mut st, dst as usize, src as usize, len, DEFAULT_STREAM)
+    }
+
+    fn cuMemcpyAsync(dst: CUdeviceptr, src: CUdeviceptr, len: usize, stream: CUstream) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        copy(&mut st, dst as usize, src as usize, len, key)
+    }
+
+    fn cuMemcpyPeerAsync(
+        dst: CUdeviceptr,
+        dst_context: CUcontext,
+        src: CUdeviceptr,
+        src_context: CUcontext,
+        len: usize,
+        stream: CUstream,
+    ) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        for ctx in [dst_context, src_context] {
+            if !st.contexts.contains_key(&(ctx as usize)) {
+                return Err(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT);
+            }
+        }
+        st.check_device_range(dst as usize, len)?;
+        st.check_device_range(src as usize, len)?;
+
+        copy(&mut st, dst as usize, src as usize, len, key)
+    }
+
+    fn cuMemcpy2D_v2(params: *const CUDA_MEMCPY2D) {
+        let mut st = lock()?;
+        let params = params.as_ref().ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        copy_2d(&mut st, params, DEFAULT_STREAM)
+    }
+
+    fn cuMemcpy2DAsync_v2(params: *const CUDA_MEMCPY2D, stream: CUstream) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        let params = params.as_ref().ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        copy_2d(&mut st, params, key)
+    }
+
+    fn cuMemsetD8_v2(dst: CUdeviceptr, value: c_uchar, count: usize) {
+        let mut st = lock()?;
+        memset(&mut st, dst, value as u32, 1, count, DEFAULT_STREAM)
+    }
+
+    fn cuMemsetD32_v2(dst: CUdeviceptr, value: c_uint, count: usize) {
+        let mut st = lock()?;
+        memset(&mut st, dst, value, 4, count, DEFAULT_STREAM)
+    }
+
+    fn cuMemsetD8Async(dst: CUdeviceptr, value: c_uchar, count: usize, stream: CUstream) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        memset(&mut st, dst, value as u32, 1, count, key)
+    }
+
+    fn cuMemsetD32Async(dst: CUdeviceptr, value: c_uint, count: usize, stream: CUstream) {
+        let mut st = lock()?;
+        let key = st.stream_key(stream)?;
+        memset(&mut st, dst, value, 4, count, key)
+    }
+}
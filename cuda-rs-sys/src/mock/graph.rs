@@ -0,0 +1,483 @@
+//! Graphs and stream capture: captured work becomes graph nodes instead of
+//! running. Instantiated graphs enqueue their nodes on the launch stream,
+//! in the order they were added.
+
+use super::state::{EventState, HostFn, Op, Res, State};
+use crate::*;
+use std::collections::{HashMap, HashSet};
+use std::thread::ThreadId;
+
+pub(super) struct Graph {
+    /// The nodes of the graph, in the order they were added. Dependencies
+    /// must exist when a node is added, so this is a topological order.
+    pub nodes: Vec<usize>,
+    /// References the graph owns, by user object.
+    pub user_objects: HashMap<usize, u32>,
+}
+
+#[derive(Clone)]
+pub(super) enum NodeKind {
+    /// Work captured from a stream, or a memcpy, memset or host node.
+    Op(Op),
+    Empty,
+    /// Kernels can't run in the mock, so they only take time.
+    Kernel { func: usize, grid: [u32; 3], block: [u32; 3], shared_mem: u32 },
+    EventRecord { event: usize },
+    EventWait { event: usize },
+    /// A child graph node, which owns a copy of the child graph.
+    Child { graph: usize },
+    MemAlloc { ptr: usize, size: usize, device: CUdevice },
+    MemFree { ptr: usize },
+}
+
+pub(super) struct Node {
+    pub graph: usize,
+    pub kind: NodeKind,
+    pub deps: Vec<usize>,
+}
+
+/// What happens when the last reference to a user object is released.
+pub(super) enum Destructor {
+    HostFn { func: HostFn, data: usize },
+    /// Frees the memory of a mem alloc node.
+    Free { ptr: usize },
+}
+
+pub(super) struct UserObject {
+    pub refs: u32,
+    pub destructor: Destructor,
+}
+
+pub(super) struct GraphExec {
+    /// The copy of the graph the exec was instantiated from, or last
+    /// updated with.
+    pub graph: usize,
+}
+
+/// Why an exec can't be updated, as the update result and the node at fault
+/// along with the dependency responsible, if any.
+pub(super) type UpdateError = (CUgraphExecUpdateResult, usize, usize);
+
+/// The `CU_GRAPH_NODE_TYPE_*` of a node.
+pub(super) fn node_type(kind: &NodeKind) -> CUgraphNodeType {
+    match kind {
+        NodeKind::Op(Op::Copy { .. } | Op::Copy2D { .. }) => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEMCPY,
+        NodeKind::Op(Op::Memset { .. }) => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEMSET,
+        NodeKind::Op(Op::HostFn { .. }) => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_HOST,
+        NodeKind::Op(Op::WaitValue { .. } | Op::WriteValue { .. }) => {
+            CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_BATCH_MEM_OP
+        }
+        NodeKind::Op(_) => unreachable!("never captured"),
+        NodeKind::Empty => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EMPTY,
+        NodeKind::Kernel { .. } => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_KERNEL,
+        NodeKind::EventRecord { .. } => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EVENT_RECORD,
+        NodeKind::EventWait { .. } => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_WAIT_EVENT,
+        NodeKind::Child { .. } => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_GRAPH,
+        NodeKind::MemAlloc { .. } => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEM_ALLOC,
+        NodeKind::MemFree { .. } => CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEM_FREE,
+    }
+}
+
+/// A capture sequence, begun on its origin stream and joined by the streams
+/// that wait on events recorded in it.
+pub(super) struct Capture {
+    pub graph: usize,
+    pub mode: CUstreamCaptureMode,
+    pub origin: usize,
+    pub thread: ThreadId,
+    pub invalidated: bool,
+}
+
+/// How a stream takes part in a capture sequence.
+pub(super) struct StreamCapture {
+    pub id: u64,
+    /// The nodes that the next work captured from the stream depends on.
+    pub deps: Vec<usize>,
+}
+
+impl State {
+    pub fn create_graph(&mut self) -> usize {
+        let graph = self.new_handle();
+        self.graphs.insert(graph, Graph { nodes: Vec::new(), user_objects: HashMap::new() });
+        graph
+    }
+
+    /// Destroys `graph` along with its child graphs, releasing the user
+    /// objects they own.
+    pub fn destroy_graph(&mut self, graph: usize) {
+        let Some(graph) = self.graphs.remove(&graph) else {
+            return;
+        };
+        for node in graph.nodes {
+            if let Some(Node { kind: NodeKind::Child { graph }, .. }) = self.nodes.remove(&node) {
+                self.destroy_graph(graph);
+            }
+        }
+        for (object, count) in graph.user_objects {
+            self.release_user_object(object, count);
+        }
+    }
+
+    /// Copies `graph`, child graphs and user object references included.
+    pub fn clone_graph(&mut self, graph: usize) -> usize {
+        let clone = self.create_graph();
+        let mut cloned = HashMap::new();
+        for node in self.graphs[&graph].nodes.clone() {
+            let mut kind = self.nodes[&node].kind.clone();
+            if let NodeKind::Child { graph } = kind {
+                kind = NodeKind::Child { graph: self.clone_graph(graph) };
+            }
+            let deps = self.nodes[&node].deps.iter().map(|dep| cloned[dep]).collect();
+            cloned.insert(node, self.add_node(clone, kind, deps));
+        }
+
+        let user_objects = self.graphs[&graph].user_objects.clone();
+        for (object, &count) in &user_objects {
+            self.user_objects.get_mut(object).unwrap().refs += count;
+        }
+        self.graphs.get_mut(&clone).unwrap().user_objects = user_objects;
+
+        clone
+    }
+
+    /// Whether `graph` has mem alloc or mem free nodes, which child graphs
+    /// can't have.
+    pub fn has_mem_nodes(&self, graph: usize) -> bool {
+        self.graphs[&graph].nodes.iter().any(|node| {
+            matches!(self.nodes[node].kind, NodeKind::MemAlloc { .. } | NodeKind::MemFree { .. })
+        })
+    }
+
+    pub fn add_node(&mut self, graph: usize, kind: NodeKind, deps: Vec<usize>) -> usize {
+        let node = self.new_handle();
+        self.nodes.insert(node, Node { graph, kind, deps });
+        self.graphs.get_mut(&graph).unwrap().nodes.push(node);
+        node
+    }
+
+    pub fn begin_capture(&mut self, stream: usize, mode: CUstreamCaptureMode) -> Res {
+        if mode > CUstreamCaptureMode_enum_CU_STREAM_CAPTURE_MODE_RELAXED {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        if stream == super::state::LEGACY_STREAM {
+            return Err(cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNSUPPORTED);
+        }
+        if self.streams[&stream].capture.is_some() {
+            return Err(cudaError_enum_CUDA_ERROR_ILLEGAL_STATE);
+        }
+
+        let graph = self.create_graph();
+        self.next_capture_id += 1;
+        let id = self.next_capture_id;
+        self.captures.insert(id, Capture {
+            graph,
+            mode,
+            origin: stream,
+            thread: std::thread::current().id(),
+            invalidated: false,
+        });
+        self.streams.get_mut(&stream).unwrap().capture = Some(StreamCapture { id, deps: Vec::new() });
+
+        Ok(())
+    }
+
+    pub fn create_user_object(&mut self, destructor: Destructor, refs: u32) -> usize {
+        let object = self.new_handle();
+        self.user_objects.insert(object, UserObject { refs, destructor });
+        object
+    }
+
+    /// Drops `count` references to `object`, running its destructor once
+    /// none are left.
+    pub fn release_user_object(&mut self, object: usize, count: u32) {
+        let o = self.user_objects.get_mut(&object).unwrap();
+        o.refs -= count;
+        if o.refs > 0 {
+            return;
+        }
+
+        match self.user_objects.remove(&object).unwrap().destructor {
+            Destructor::HostFn { func, data } => self.defer(move || unsafe { func(data as _) }),
+            Destructor::Free { ptr } => self.release(ptr),
+        }
+    }
+
+    /// Whether `ptr` was allocated by a mem alloc node.
+    pub fn is_graph_allocation(&self, ptr: usize) -> bool {
+        self.user_objects
+            .values()
+            .any(|o| matches!(o.destructor, Destructor::Free { ptr: p } if p == ptr))
+    }
+
+    pub fn instantiate(&mut self, graph: usize) -> usize {
+        let exec = self.new_handle();
+        let graph = self.clone_graph(graph);
+        self.execs.insert(exec, GraphExec { graph });
+        exec
+    }
+
+    pub fn destroy_exec(&mut self, exec: usize) {
+        if let Some(exec) = self.execs.remove(&exec) {
+            self.destroy_graph(exec.graph);
+        }
+    }
+
+    /// Enqueues the nodes of `exec` on `stream`. The launch holds a
+    /// reference to every user object of the graph until it completes. On a
+    /// capturing stream, the graph is captured as a child graph node.
+    pub fn launch(&mut self, exec: usize, stream: usize) -> Res {
+        let graph = self.execs[&exec].graph;
+        if self.streams[&stream].capture.is_some() {
+            let child = self.clone_graph(graph);
+            self.capture_node(stream, NodeKind::Child { graph: child });
+            return Ok(());
+        }
+
+        let mut user_objects = Vec::new();
+        self.collect_user_objects(graph, &mut user_objects);
+        for object in &user_objects {
+            self.user_objects.get_mut(object).unwrap().refs += 1;
+        }
+        let launch = self.new_handle();
+        self.launches.insert(launch, user_objects);
+
+        self.enqueue_graph(graph, stream)?;
+        self.enqueue(stream, Op::EndLaunch { launch }, false)?;
+
+        Ok(())
+    }
+
+    fn collect_user_objects(&self, graph: usize, user_objects: &mut Vec<usize>) {
+        user_objects.extend(self.graphs[&graph].user_objects.keys());
+        for node in &self.graphs[&graph].nodes {
+            if let NodeKind::Child { graph } = self.nodes[node].kind {
+                self.collect_user_objects(graph, user_objects);
+            }
+        }
+    }
+
+    fn enqueue_graph(&mut self, graph: usize, stream: usize) -> Res {
+        for node in self.graphs[&graph].nodes.clone() {
+            match self.nodes[&node].kind {
+                NodeKind::Op(op) => {
+                    self.enqueue(stream, op, false)?;
+                }
+                NodeKind::Kernel { .. } => {
+                    self.enqueue(stream, Op::Kernel, false)?;
+                }
+                NodeKind::EventRecord { event } if self.events.contains_key(&event) => {
+                    self.record(event, stream)?;
+                }
+                NodeKind::EventWait { event } => {
+                    if let Some(&EventState::Pending { stream: other, seq }) =
+                        self.events.get(&event).map(|e| &e.state)
+                    {
+                        if other != stream {
+                            self.enqueue(stream, Op::Wait { stream: other, seq }, false)?;
+                        }
+                    }
+                }
+                NodeKind::Child { graph } => self.enqueue_graph(graph, stream)?,
+                NodeKind::EventRecord { .. }
+                | NodeKind::Empty
+                | NodeKind::MemAlloc { .. }
+                | NodeKind::MemFree { .. } => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Updates `exec` to run `graph`, which must have the same topology and
+    /// node types as the graph it was instantiated from. The exec takes a
+    /// copy of `graph`, along with its user object references.
+    pub fn update_exec(&mut self, exec: usize, graph: usize) -> Result<(), UpdateError> {
+        let old = self.execs[&exec].graph;
+        self.compare_graphs(old, graph)?;
+
+        let graph = self.clone_graph(graph);
+        self.execs.get_mut(&exec).unwrap().graph = graph;
+        self.destroy_graph(old);
+
+        Ok(())
+    }
+
+    fn compare_graphs(&self, old: usize, new: usize) -> Result<(), UpdateError> {
+        let (old, new) = (&self.graphs[&old].nodes, &self.graphs[&new].nodes);
+        if old.len() != new.len() {
+            return Err((CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_TOPOLOGY_CHANGED, 0, 0));
+        }
+
+        let index = |nodes: &[usize]| -> HashMap<usize, usize> {
+            nodes.iter().enumerate().map(|(i, &node)| (node, i)).collect()
+        };
+        let (old_index, new_index) = (index(old), index(new));
+        for (a, &node) in old.iter().zip(new) {
+            let (a, b) = (&self.nodes[a], &self.nodes[&node]);
+            if node_type(&a.kind) != node_type(&b.kind) {
+                return Err((CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_NODE_TYPE_CHANGED, node, 0));
+            }
+            let old_deps: HashSet<_> = a.deps.iter().map(|dep| old_index[dep]).collect();
+            if let Some(&from) = b.deps.iter().find(|dep| !old_deps.contains(&new_index[dep])) {
+                return Err((CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_TOPOLOGY_CHANGED, node, from));
+            }
+            if a.deps.len() != b.deps.len() {
+                return Err((CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_TOPOLOGY_CHANGED, node, 0));
+            }
+
+            match (&a.kind, &b.kind) {
+                (NodeKind::Kernel { func: f, .. }, NodeKind::Kernel { func: g, .. }) if f != g => {
+                    return Err((CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_FUNCTION_CHANGED, node, 0));
+                }
+                (NodeKind::MemAlloc { .. } | NodeKind::MemFree { .. }, _) => {
+                    return Err((CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_NOT_SUPPORTED, node, 0));
+                }
+                (&NodeKind::Child { graph: a }, &NodeKind::Child { graph: b }) => {
+                    self.compare_graphs(a, b).map_err(|(result, _, _)| (result, node, 0))?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds a node to the capture sequence `stream` takes part in, after
+    /// the work captured from the stream so far.
+    pub fn capture_node(&mut self, stream: usize, kind: NodeKind) {
+        let capture = self.streams[&stream].capture.as_ref().unwrap();
+        let graph = self.captures[&capture.id].graph;
+        let deps = capture.deps.clone();
+
+        let node = self.add_node(graph, kind, deps);
+        self.streams.get_mut(&stream).unwrap().capture.as_mut().unwrap().deps = vec![node];
+    }
+
+    /// Records `event` in the capture sequence of `stream`, so that streams
+    /// waiting on it depend on the work captured so far.
+    pub fn capture_record(&mut self, event: usize, stream: usize) {
+        let capture = self.streams[&stream].capture.as_ref().unwrap();
+        let (id, deps) = (capture.id, capture.deps.clone());
+
+        let e = self.events.get_mut(&event).unwrap();
+        e.state = EventState::Captured { capture: id };
+        e.captured = deps;
+    }
+
+    /// Makes `stream` wait on `event`, recorded in capture sequence `id`.
+    /// A stream that is not capturing joins the sequence.
+    pub fn capture_wait(&mut self, stream: usize, event: usize, id: u64) -> Res {
+        if !self.captures.contains_key(&id) {
+            // The capture has ended, and the event stands for no work.
+            return Ok(());
+        }
+
+        let deps = self.events[&event].captured.clone();
+        let s = self.streams.get_mut(&stream).unwrap();
+        match s.capture {
+            Some(ref mut capture) if capture.id == id => {
+                for dep in deps {
+                    if !capture.deps.contains(&dep) {
+                        capture.deps.push(dep);
+                    }
+                }
+                Ok(())
+            }
+            Some(_) => Err(self.invalidate_capture(stream, cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_MERGE)),
+            None => {
+                s.capture = Some(StreamCapture { id, deps });
+                Ok(())
+            }
+        }
+    }
+
+    /// Invalidates the capture sequence of `stream`, returning `error` for
+    /// the call that broke it.
+    pub fn invalidate_capture(&mut self, stream: usize, error: CUresult) -> CUresult {
+        if let Some(ref capture) = self.streams[&stream].capture {
+            self.captures.get_mut(&capture.id).unwrap().invalidated = true;
+        }
+
+        error
+    }
+
+    /// Fails a call that is unsafe during capture, such as a synchronous
+    /// allocation, and invalidates the captures it would break. The mock
+    /// treats global mode like thread-local mode, since tests running on
+    /// other threads share it.
+    pub fn check_capture_safe(&mut self) -> Res {
+        let thread = std::thread::current().id();
+        let mut res = Ok(());
+        for capture in self.captures.values_mut() {
+            if capture.mode != CUstreamCaptureMode_enum_CU_STREAM_CAPTURE_MODE_RELAXED
+                && capture.thread == thread
+            {
+                capture.invalidated = true;
+                res = Err(cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNSUPPORTED);
+            }
+        }
+
+        res
+    }
+
+    /// Ends the capture sequence begun on `stream`, returning its graph.
+    /// Every stream that joined it must have been joined back into `stream`.
+    pub fn end_capture(&mut self, stream: usize) -> Result<usize, CUresult> {
+        let id = match self.streams[&stream].capture {
+            Some(ref capture) => capture.id,
+            None => return Err(cudaError_enum_CUDA_ERROR_ILLEGAL_STATE),
+        };
+        let capture = &self.captures[&id];
+        if capture.origin != stream {
+            return Err(self.invalidate_capture(stream, cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNMATCHED));
+        }
+        if capture.mode != CUstreamCaptureMode_enum_CU_STREAM_CAPTURE_MODE_RELAXED
+            && capture.thread != std::thread::current().id()
+        {
+            return Err(cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_WRONG_THREAD);
+        }
+
+        let capture = self.captures.remove(&id).unwrap();
+        let mut origin_deps = Vec::new();
+        let mut joined_deps = Vec::new();
+        for (&key, s) in self.streams.iter_mut() {
+            if s.capture.as_ref().is_some_and(|c| c.id == id) {
+                let deps = s.capture.take().unwrap().deps;
+                if key == stream {
+                    origin_deps = deps;
+                } else {
+                    joined_deps.extend(deps);
+                }
+            }
+        }
+
+        if capture.invalidated {
+            self.destroy_graph(capture.graph);
+            return Err(cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_INVALIDATED);
+        }
+        // Work captured from the other streams must be waited on by the
+        // origin stream.
+        if joined_deps.iter().any(|&dep| !self.reaches(&origin_deps, dep)) {
+            self.destroy_graph(capture.graph);
+            return Err(cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNJOINED);
+        }
+
+        Ok(capture.graph)
+    }
+
+    /// Whether `node` is one of `from` or one of their dependencies.
+    fn reaches(&self, from: &[usize], node: usize) -> bool {
+        let mut seen = HashSet::new();
+        let mut stack = from.to_vec();
+        while let Some(next) = stack.pop() {
+            if next == node {
+                return true;
+            }
+            if seen.insert(next) {
+                stack.extend(&self.nodes[&next].deps);
+            }
+        }
+
+        false
+    }
+}
@@ -0,0 +1,180 @@
+//! In-process mock of the CUDA driver API, for testing without a GPU.
+//!
+//! With the `mock` feature enabled, the `cu*` functions of this crate
+//! dispatch to a simulation instead of `libcuda`:
+//!
+//! - Device allocations and page-locked host allocations live in host memory.
+//! - Each stream keeps a queue of pending work that completes in order. Work
+//!   runs when the host observes it (`cuStreamQuery`, `cuEventQuery`, any
+//!   synchronization), unless the stream is held with [`set_stream_held`].
+//!   Work on the NULL/legacy stream first waits for all blocking streams, and
+//!   copies involving pageable host memory are synchronous, as on real
+//!   hardware.
+//! - Host functions (`cuLaunchHostFunc`) and callbacks
+//!   (`cuStreamAddCallback`) run in stream order like other work, on the
+//!   thread whose call completed them rather than on a driver thread. Host
+//!   functions are skipped in a poisoned context; callbacks get its error.
+//! - Stream memory operations (`cuStreamWaitValue*`, `cuStreamWriteValue*`,
+//!   `cuStreamBatchMemOp`) act on device or page-locked host memory, which
+//!   share one address space. `cuStreamSynchronize` on a stream blocked by a
+//!   value wait returns once another thread makes the condition hold.
+//! - Work enqueued on a capturing stream (`cuStreamBeginCapture`) becomes
+//!   graph nodes instead. Streams join a capture by waiting on an event
+//!   recorded in it. Calls that are unsafe during capture fail on the
+//!   capturing thread in global mode as in thread-local mode, since tests
+//!   share the process. Stream ordered allocations can't be captured.
+//! - Launching a graph (`cuGraphLaunch`) enqueues its nodes on the stream
+//!   one after another, in the order they were added. Kernel nodes accept
+//!   any function handle and only take time. Mem alloc nodes allocate when
+//!   they are added, and the memory lives as long as the graph or an exec
+//!   made from it. Exec updates need the same topology and node types, and
+//!   reject graphs with memory nodes.
+//! - Events get timestamps from a per-stream clock that advances by
+//!   [`set_op_duration`] for every completed operation. Blocking sync
+//!   events behave like the others. The IPC handle of an interprocess event
+//!   can be opened in the same process, and the opened handle refers to the
+//!   same event.
+//! - With the `per-thread-default-stream` feature, the NULL stream is
+//!   `CU_STREAM_PER_THREAD`. There is only one per-thread stream, shared by
+//!   all threads.
+//! - Failures can be injected per entry point with [`fail_next`] and
+//!   [`fail_always`].
+//!
+//! Entry points the mock does not simulate return `CUDA_ERROR_NOT_FOUND`.
+//! As with the real driver, everything except `cuInit`, `cuDriverGetVersion`,
+//! `cuGetErrorName` and `cuGetErrorString` fails with
+//! `CUDA_ERROR_NOT_INITIALIZED` until `cuInit` is called, and most calls need
+//! a current context.
+
+mod entry;
+mod graph;
+mod state;
+
+use crate::{CUcontext, CUresult, CUstream, DriverTable};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+struct Failure {
+    error: CUresult,
+    remaining: Option<usize>,
+}
+
+thread_local! {
+    static FAILURES: RefCell<HashMap<String, Failure>> = RefCell::new(HashMap::new());
+}
+
+pub(crate) fn table() -> &'static DriverTable {
+    static TABLE: OnceLock<DriverTable> = OnceLock::new();
+
+    TABLE.get_or_init(|| unsafe { DriverTable::load(lookup) })
+}
+
+/// Resolves the `_ptsz`/`_ptds` variants bound by the
+/// `per-thread-default-stream` feature to the plain entry points. The state
+/// maps the NULL stream to `CU_STREAM_PER_THREAD` instead.
+fn lookup(symbol: &[u8]) -> *const std::os::raw::c_void {
+    let name = symbol.strip_suffix(b"_ptsz\0")
+        .or_else(|| symbol.strip_suffix(b"_ptds\0"));
+    match name {
+        Some(name) => entry::lookup(&[name, b"\0"].concat()),
+        None => entry::lookup(symbol),
+    }
+}
+
+fn injected_failure(function: &str) -> Option<CUresult> {
+    FAILURES.with(|failures| {
+        let mut failures = failures.borrow_mut();
+        let failure = failures.get_mut(function)?;
+        let error = failure.error;
+        if let Some(remaining) = failure.remaining.as_mut() {
+            *remaining -= 1;
+            if *remaining == 0 {
+                failures.remove(function);
+            }
+        }
+
+        Some(error)
+    })
+}
+
+fn inject(function: &str, error: CUresult, remaining: Option<usize>) {
+    FAILURES.with(|failures| {
+        failures
+            .borrow_mut()
+            .insert(function.to_owned(), Failure { error, remaining });
+    });
+}
+
+/// Makes the next call to `function` (e.g. `"cuMemAllocAsync"`) on the
+/// current thread fail with `error`.
+pub fn fail_next(function: &str, error: CUresult) {
+    inject(function, error, Some(1));
+}
+
+/// Makes every call to `function` on the current thread fail with `error`,
+/// until [`clear_failures`] is called.
+pub fn fail_always(function: &str, error: CUresult) {
+    inject(function, error, None);
+}
+
+/// Removes all failures injected on the current thread.
+pub fn clear_failures() {
+    FAILURES.with(|failures| failures.borrow_mut().clear());
+}
+
+/// Sets the number of simulated devices. Defaults to 1.
+pub fn set_device_count(count: i32) {
+    state::lock_any().config.device_count = count;
+}
+
+/// Sets the memory size of each simulated device, beyond which allocations
+/// fail with `CUDA_ERROR_OUT_OF_MEMORY`. Defaults to 8 GiB.
+pub fn set_total_memory(bytes: usize) {
+    state::lock_any().config.total_memory = bytes;
+}
+
+/// Sets how much simulated time every completed operation takes, as seen by
+/// `cuEventElapsedTime`. Defaults to 1 µs.
+pub fn set_op_duration(ms: f32) {
+    state::lock_any().config.op_duration_ms = ms;
+}
+
+/// Holds or releases a stream. Work on a held stream only runs when it is
+/// explicitly synchronized, so queries keep reporting `CUDA_ERROR_NOT_READY`.
+pub fn set_stream_held(stream: CUstream, held: bool) -> Result<(), CUresult> {
+    let mut st = state::lock_any();
+    let key = st.stream_key(stream)?;
+    st.streams.get_mut(&key).unwrap().held = held;
+
+    Ok(())
+}
+
+/// Poisons `ctx` as a sticky error such as `CUDA_ERROR_ILLEGAL_ADDRESS`
+/// would: every later call that needs it current fails with `error`, until
+/// it is destroyed or, for a primary context, reset with
+/// `cuDevicePrimaryCtxReset`. Resetting also destroys the streams of the
+/// context, but leaves its allocations alone.
+pub fn poison_context(ctx: CUcontext, error: CUresult) -> Result<(), CUresult> {
+    let mut st = state::lock_any();
+    let context = st
+        .contexts
+        .get_mut(&(ctx as usize))
+        .ok_or(crate::cudaError_enum_CUDA_ERROR_INVALID_CONTEXT)?;
+    context.sticky = Some(error);
+
+    Ok(())
+}
+
+/// Number of operations enqueued on `stream` that have not completed yet.
+pub fn pending_operations(stream: CUstream) -> Result<usize, CUresult> {
+    let st = state::lock_any();
+    let key = st.stream_key(stream)?;
+
+    Ok(st.streams[&key].queue.len())
+}
+
+/// Total size of the live device allocations, in bytes.
+pub fn allocated_bytes() -> usize {
+    state::lock_any().device_bytes
+}
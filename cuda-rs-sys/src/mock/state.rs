@@ -0,0 +1,682 @@
+//! Bookkeeping behind the mock entry points: contexts, streams with their
+//! pending work, events and allocations.
+
+use super::graph::{Capture, Graph, GraphExec, Node, NodeKind, StreamCapture, UserObject};
+use crate::*;
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+
+pub(super) type Res = Result<(), CUresult>;
+
+/// Raw handle value of `CU_STREAM_LEGACY`.
+pub(super) const LEGACY_STREAM: usize = 0x1;
+/// Raw handle value of `CU_STREAM_PER_THREAD`.
+pub(super) const PER_THREAD_STREAM: usize = 0x2;
+
+/// Stream priorities of every context, as `(least, greatest)`.
+pub(super) const STREAM_PRIORITIES: (i32, i32) = (0, -5);
+
+/// The stream behind the NULL handle and synchronous copies.
+#[cfg(not(feature = "per-thread-default-stream"))]
+pub(super) const DEFAULT_STREAM: usize = LEGACY_STREAM;
+#[cfg(feature = "per-thread-default-stream")]
+pub(super) const DEFAULT_STREAM: usize = PER_THREAD_STREAM;
+
+const ALLOC_ALIGNMENT: usize = 256;
+
+pub(super) struct Config {
+    pub device_count: i32,
+    pub total_memory: usize,
+    pub op_duration_ms: f32,
+}
+
+pub(super) struct Context {
+    pub device: CUdevice,
+    pub flags: u32,
+    pub primary: bool,
+    pub refcount: u32,
+    /// Error returned by every call made with the context current, once it
+    /// has been poisoned.
+    pub sticky: Option<CUresult>,
+    /// Contexts this context has enabled peer access to.
+    pub peers: HashSet<usize>,
+    pub limits: HashMap<CUlimit, usize>,
+    pub cache_config: CUfunc_cache,
+    pub shared_mem_config: CUsharedconfig,
+}
+
+impl Context {
+    pub fn new(device: CUdevice, flags: u32, primary: bool, refcount: u32) -> Self {
+        let mut ctx = Context {
+            device,
+            flags,
+            primary,
+            refcount,
+            sticky: None,
+            peers: HashSet::new(),
+            limits: HashMap::new(),
+            cache_config: 0,
+            shared_mem_config: 0,
+        };
+        ctx.reset();
+        ctx
+    }
+
+    /// Drops all state except the flags, as destroying a primary context does.
+    pub fn reset(&mut self) {
+        self.sticky = None;
+        self.peers.clear();
+        self.limits = HashMap::from([
+            (CUlimit_enum_CU_LIMIT_STACK_SIZE, 1 << 10),
+            (CUlimit_enum_CU_LIMIT_PRINTF_FIFO_SIZE, 1 << 20),
+            (CUlimit_enum_CU_LIMIT_MALLOC_HEAP_SIZE, 8 << 20),
+            (CUlimit_enum_CU_LIMIT_DEV_RUNTIME_SYNC_DEPTH, 2),
+            (CUlimit_enum_CU_LIMIT_DEV_RUNTIME_PENDING_LAUNCH_COUNT, 2048),
+            (CUlimit_enum_CU_LIMIT_MAX_L2_FETCH_GRANULARITY, 64),
+            (CUlimit_enum_CU_LIMIT_PERSISTING_L2_CACHE_SIZE, 0),
+        ]);
+        self.cache_config = CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_NONE;
+        self.shared_mem_config = CUsharedconfig_enum_CU_SHARED_MEM_CONFIG_DEFAULT_BANK_SIZE;
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(super) enum Op {
+    Copy { dst: usize, src: usize, len: usize },
+    Copy2D { dst: usize, dst_pitch: usize, src: usize, src_pitch: usize, width: usize, height: usize },
+    Memset { dst: usize, value: u32, elem_size: usize, count: usize },
+    Free { ptr: usize },
+    Record { event: usize, seq: u64 },
+    Wait { stream: usize, seq: u64 },
+    /// Blocks the stream until the 4 byte (or 8 byte if `wide`) value at
+    /// `addr` satisfies the `CU_STREAM_WAIT_VALUE_*` condition `cond`.
+    WaitValue { addr: usize, cond: u32, value: u64, wide: bool },
+    WriteValue { addr: usize, value: u64, wide: bool },
+    HostFn { func: HostFn, data: usize },
+    Callback { func: Callback, data: usize },
+    /// A kernel node of a launched graph, which only takes time.
+    Kernel,
+    /// The end of a graph launch, which releases the user objects it holds.
+    EndLaunch { launch: usize },
+}
+
+pub(super) type HostFn = unsafe extern "C" fn(*mut std::os::raw::c_void);
+pub(super) type Callback = unsafe extern "C" fn(CUstream, CUresult, *mut std::os::raw::c_void);
+
+/// A host function or stream callback that is ready to run.
+type ReadyFn = Box<dyn FnOnce() + Send>;
+
+/// The L2 access policy window of a stream, which only matters to the
+/// cache and so is just stored.
+#[derive(Clone, Copy, Default)]
+pub(super) struct AccessPolicy {
+    pub base: usize,
+    pub num_bytes: usize,
+    pub hit_ratio: f32,
+    pub hit_prop: u32,
+    pub miss_prop: u32,
+}
+
+pub(super) struct Stream {
+    pub ctx: usize,
+    pub flags: u32,
+    pub priority: i32,
+    pub access_policy: AccessPolicy,
+    pub sync_policy: u32,
+    pub held: bool,
+    /// The capture sequence the stream takes part in, if any.
+    pub capture: Option<StreamCapture>,
+    pub queue: VecDeque<Op>,
+    /// Number of operations ever enqueued on the stream.
+    pub enqueued: u64,
+    /// Number of operations that have completed.
+    pub executed: u64,
+    /// Simulated time at which the last completed operation finished.
+    pub clock_ms: f64,
+}
+
+impl Stream {
+    fn new(ctx: usize, flags: u32, priority: i32) -> Self {
+        Stream {
+            ctx,
+            flags,
+            priority,
+            access_policy: AccessPolicy::default(),
+            sync_policy: CUsynchronizationPolicy_enum_CU_SYNC_POLICY_AUTO,
+            held: false,
+            capture: None,
+            queue: VecDeque::new(),
+            enqueued: 0,
+            executed: 0,
+            clock_ms: 0.0,
+        }
+    }
+
+    pub fn is_blocking(&self) -> bool {
+        self.flags & CUstream_flags_enum_CU_STREAM_NON_BLOCKING == 0
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(super) enum EventState {
+    Unrecorded,
+    Pending { stream: usize, seq: u64 },
+    Complete { time_ms: f64 },
+    /// Last recorded in the capture sequence `capture`.
+    Captured { capture: u64 },
+}
+
+pub(super) struct Event {
+    pub flags: u32,
+    pub state: EventState,
+    /// The graph nodes that waiting on a captured event depends on.
+    pub captured: Vec<usize>,
+    /// Number of handles to the event: the one it was created with, and
+    /// those opened from its IPC handle.
+    pub handles: u32,
+}
+
+pub(super) struct Allocation {
+    pub size: usize,
+    pub layout: Layout,
+    pub freeing: bool,
+}
+
+pub(super) struct State {
+    pub initialized: bool,
+    pub config: Config,
+    next_handle: usize,
+    pub contexts: HashMap<usize, Context>,
+    pub primary: HashMap<CUdevice, usize>,
+    pub streams: HashMap<usize, Stream>,
+    pub events: HashMap<usize, Event>,
+    /// Handles opened from IPC event handles, with the event they refer to.
+    pub event_aliases: HashMap<usize, usize>,
+    pub device_allocs: BTreeMap<usize, Allocation>,
+    pub host_allocs: BTreeMap<usize, Allocation>,
+    pub device_bytes: usize,
+    pub graphs: HashMap<usize, Graph>,
+    pub nodes: HashMap<usize, Node>,
+    pub captures: HashMap<u64, Capture>,
+    pub next_capture_id: u64,
+    pub user_objects: HashMap<usize, UserObject>,
+    pub execs: HashMap<usize, GraphExec>,
+    /// The user objects held by each pending graph launch.
+    pub launches: HashMap<usize, Vec<usize>>,
+    /// Host functions whose turn has come, run by [`run_host_fns`] once the
+    /// state is unlocked.
+    host_fns: Vec<ReadyFn>,
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+thread_local! {
+    static CONTEXT_STACK: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(super) struct Guard(MutexGuard<'static, Option<State>>);
+
+impl std::ops::Deref for Guard {
+    type Target = State;
+
+    fn deref(&self) -> &State {
+        self.0.as_ref().unwrap()
+    }
+}
+
+impl std::ops::DerefMut for Guard {
+    fn deref_mut(&mut self) -> &mut State {
+        self.0.as_mut().unwrap()
+    }
+}
+
+/// Locks the mock state, whether or not the driver has been initialized.
+pub(super) fn lock_any() -> Guard {
+    let mut guard = STATE.lock().unwrap_or_else(|err| err.into_inner());
+    guard.get_or_insert_with(State::new);
+    Guard(guard)
+}
+
+/// Locks the mock state, failing like the driver does before `cuInit`.
+pub(super) fn lock() -> Result<Guard, CUresult> {
+    let guard = lock_any();
+    if guard.initialized {
+        Ok(guard)
+    } else {
+        Err(cudaError_enum_CUDA_ERROR_NOT_INITIALIZED)
+    }
+}
+
+/// Runs the host functions that became ready during the last call. They run
+/// without the state locked, on the thread that made the call, so they can
+/// call back into the mock.
+pub(super) fn run_host_fns() {
+    loop {
+        let host_fns = std::mem::take(&mut lock_any().host_fns);
+        if host_fns.is_empty() {
+            return;
+        }
+
+        for host_fn in host_fns {
+            host_fn();
+        }
+    }
+}
+
+pub(super) fn context_stack<R>(f: impl FnOnce(&mut Vec<usize>) -> R) -> R {
+    CONTEXT_STACK.with(|stack| f(&mut stack.borrow_mut()))
+}
+
+impl State {
+    fn new() -> Self {
+        let mut streams = HashMap::new();
+        streams.insert(LEGACY_STREAM, Stream::new(0, 0, 0));
+        streams.insert(
+            PER_THREAD_STREAM,
+            Stream::new(0, CUstream_flags_enum_CU_STREAM_NON_BLOCKING, 0),
+        );
+
+        State {
+            initialized: false,
+            config: Config {
+                device_count: 1,
+                total_memory: 8 << 30,
+                op_duration_ms: 0.001,
+            },
+            next_handle: 0x1000,
+            contexts: HashMap::new(),
+            primary: HashMap::new(),
+            streams,
+            events: HashMap::new(),
+            event_aliases: HashMap::new(),
+            device_allocs: BTreeMap::new(),
+            host_allocs: BTreeMap::new(),
+            device_bytes: 0,
+            graphs: HashMap::new(),
+            nodes: HashMap::new(),
+            captures: HashMap::new(),
+            next_capture_id: 0,
+            user_objects: HashMap::new(),
+            execs: HashMap::new(),
+            launches: HashMap::new(),
+            host_fns: Vec::new(),
+        }
+    }
+
+    pub fn new_handle(&mut self) -> usize {
+        let handle = self.next_handle;
+        self.next_handle += 0x10;
+        handle
+    }
+
+    pub fn check_device(&self, device: CUdevice) -> Res {
+        if device < 0 || device >= self.config.device_count {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_DEVICE);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the primary context of `device`, creating it inactive if it
+    /// was never retained.
+    pub fn primary_context(&mut self, device: CUdevice) -> usize {
+        if let Some(&ctx) = self.primary.get(&device) {
+            return ctx;
+        }
+
+        let ctx = self.new_handle();
+        self.contexts.insert(ctx, Context::new(device, 0, true, 0));
+        self.primary.insert(device, ctx);
+        ctx
+    }
+
+    pub fn current_context(&self) -> Result<usize, CUresult> {
+        let ctx = context_stack(|stack| stack.last().copied())
+            .filter(|ctx| self.contexts.contains_key(ctx))
+            .ok_or(cudaError_enum_CUDA_ERROR_INVALID_CONTEXT)?;
+        match self.contexts[&ctx].sticky {
+            Some(err) => Err(err),
+            None => Ok(ctx),
+        }
+    }
+
+    pub fn create_stream(&mut self, ctx: usize, flags: u32, priority: i32) -> usize {
+        let handle = self.new_handle();
+        self.streams.insert(handle, Stream::new(ctx, flags, priority));
+        handle
+    }
+
+    /// Maps a raw stream handle to its key, folding the NULL stream into
+    /// `CU_STREAM_LEGACY`, or `CU_STREAM_PER_THREAD` with the
+    /// `per-thread-default-stream` feature.
+    pub fn stream_key(&self, stream: CUstream) -> Result<usize, CUresult> {
+        let key = match stream as usize {
+            0 => DEFAULT_STREAM,
+            key => key,
+        };
+
+        if self.streams.contains_key(&key) {
+            Ok(key)
+        } else {
+            Err(cudaError_enum_CUDA_ERROR_INVALID_HANDLE)
+        }
+    }
+
+    /// Resolves `event`, which may have been opened from an IPC handle.
+    pub fn event_key(&self, event: CUevent) -> Result<usize, CUresult> {
+        let key = event as usize;
+        let key = self.event_aliases.get(&key).copied().unwrap_or(key);
+        if self.events.contains_key(&key) {
+            Ok(key)
+        } else {
+            Err(cudaError_enum_CUDA_ERROR_INVALID_HANDLE)
+        }
+    }
+
+    pub fn allocate(&mut self, size: usize, device: bool) -> Result<usize, CUresult> {
+        if size == 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+        if device && self.device_bytes + size > self.config.total_memory {
+            return Err(cudaError_enum_CUDA_ERROR_OUT_OF_MEMORY);
+        }
+
+        let layout = Layout::from_size_align(size, ALLOC_ALIGNMENT)
+            .map_err(|_| cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        let ptr = unsafe { std::alloc::alloc_zeroed(layout) } as usize;
+        if ptr == 0 {
+            return Err(cudaError_enum_CUDA_ERROR_OUT_OF_MEMORY);
+        }
+
+        let allocation = Allocation { size, layout, freeing: false };
+        if device {
+            self.device_allocs.insert(ptr, allocation);
+            self.device_bytes += size;
+        } else {
+            self.host_allocs.insert(ptr, allocation);
+        }
+
+        Ok(ptr)
+    }
+
+    /// Marks a device allocation as being freed, rejecting unknown pointers
+    /// and double frees.
+    pub fn begin_free(&mut self, ptr: usize) -> Res {
+        match self.device_allocs.get_mut(&ptr) {
+            Some(alloc) if !alloc.freeing => {
+                alloc.freeing = true;
+                Ok(())
+            }
+            _ => Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        }
+    }
+
+    pub fn release(&mut self, ptr: usize) {
+        if let Some(alloc) = self.device_allocs.remove(&ptr) {
+            self.device_bytes -= alloc.size;
+            unsafe { std::alloc::dealloc(ptr as *mut u8, alloc.layout) };
+        }
+    }
+
+    pub fn free_host(&mut self, ptr: usize) -> Res {
+        let alloc = self
+            .host_allocs
+            .remove(&ptr)
+            .ok_or(cudaError_enum_CUDA_ERROR_INVALID_VALUE)?;
+        unsafe { std::alloc::dealloc(ptr as *mut u8, alloc.layout) };
+
+        Ok(())
+    }
+
+    /// Checks `[ptr, ptr + len)` against the known allocations. Returns
+    /// whether the range lies in mock-owned memory (device or page-locked
+    /// host memory), as opposed to ordinary pageable host memory.
+    pub fn check_range(&self, ptr: usize, len: usize) -> Result<bool, CUresult> {
+        if ptr == 0 {
+            return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        }
+
+        for allocs in [&self.device_allocs, &self.host_allocs] {
+            if let Some((&base, alloc)) = allocs.range(..=ptr).next_back() {
+                if ptr < base + alloc.size {
+                    if ptr + len > base + alloc.size {
+                        return Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+                    }
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    pub fn check_device_range(&self, ptr: usize, len: usize) -> Res {
+        match self.device_allocs.range(..=ptr).next_back() {
+            Some((&base, alloc)) if ptr + len <= base + alloc.size => Ok(()),
+            _ => Err(cudaError_enum_CUDA_ERROR_INVALID_VALUE),
+        }
+    }
+
+    /// Enqueues `op` on `stream`. Work on the legacy stream first waits for
+    /// all blocking streams, and work on either default stream, or touching
+    /// pageable host memory (`sync`), completes before returning. On a
+    /// capturing stream, `op` is captured instead.
+    pub fn enqueue(&mut self, stream: usize, op: Op, sync: bool) -> Result<u64, CUresult> {
+        if self.streams[&stream].capture.is_some() {
+            if sync {
+                return Err(self.invalidate_capture(stream, cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNSUPPORTED));
+            }
+            self.capture_node(stream, NodeKind::Op(op));
+            return Ok(self.streams[&stream].enqueued);
+        }
+
+        if stream == LEGACY_STREAM {
+            self.drain_blocking();
+        }
+
+        let s = self.streams.get_mut(&stream).unwrap();
+        s.queue.push_back(op);
+        s.enqueued += 1;
+        let seq = s.enqueued;
+
+        if sync || stream == LEGACY_STREAM || stream == PER_THREAD_STREAM {
+            self.advance(stream, seq, true);
+        }
+
+        Ok(seq)
+    }
+
+    /// Runs `f` once the state is unlocked, like a host function.
+    pub fn defer(&mut self, f: impl FnOnce() + Send + 'static) {
+        self.host_fns.push(Box::new(f));
+    }
+
+    pub fn record(&mut self, event: usize, stream: usize) -> Res {
+        if self.streams[&stream].capture.is_some() {
+            self.capture_record(event, stream);
+            return Ok(());
+        }
+
+        let seq = self.streams[&stream].enqueued + 1;
+        self.events.get_mut(&event).unwrap().state = EventState::Pending { stream, seq };
+        self.enqueue(stream, Op::Record { event, seq }, false)?;
+
+        Ok(())
+    }
+
+    /// Runs all work on `stream`, returning false if it is blocked on a
+    /// value wait that doesn't hold yet.
+    pub fn drain(&mut self, stream: usize) -> bool {
+        let target = self.streams[&stream].enqueued;
+        self.advance(stream, target, true)
+    }
+
+    pub fn drain_blocking(&mut self) {
+        let blocking: Vec<_> = self
+            .streams
+            .iter()
+            .filter(|(&key, s)| key != LEGACY_STREAM && s.is_blocking() && !s.queue.is_empty())
+            .map(|(&key, _)| key)
+            .collect();
+        for stream in blocking {
+            self.drain(stream);
+        }
+    }
+
+    pub fn drain_all(&mut self) {
+        let streams: Vec<_> = self.streams.keys().copied().collect();
+        for stream in streams {
+            self.drain(stream);
+        }
+    }
+
+    /// Runs the work on `stream` until its first `target` operations have
+    /// completed. Unless `force` is set, held streams make no progress.
+    /// Returns whether the target was reached.
+    pub fn advance(&mut self, stream: usize, target: u64, force: bool) -> bool {
+        loop {
+            let Some(s) = self.streams.get_mut(&stream) else {
+                return true;
+            };
+            if s.executed >= target {
+                return true;
+            }
+            if s.held && !force {
+                return false;
+            }
+
+            let op = s.queue.pop_front().unwrap();
+            if let Op::Wait { stream: other, seq } = op {
+                if !self.advance(other, seq, force) {
+                    self.streams.get_mut(&stream).unwrap().queue.push_front(op);
+                    return false;
+                }
+                let other_clock = self.streams.get(&other).map_or(0.0, |s| s.clock_ms);
+                let s = self.streams.get_mut(&stream).unwrap();
+                s.clock_ms = s.clock_ms.max(other_clock);
+                s.executed += 1;
+                continue;
+            }
+            if let Op::WaitValue { addr, cond, value, wide } = op {
+                if !wait_value_holds(addr, cond, value, wide) {
+                    self.streams.get_mut(&stream).unwrap().queue.push_front(op);
+                    return false;
+                }
+                self.streams.get_mut(&stream).unwrap().executed += 1;
+                continue;
+            }
+
+            self.execute(stream, op);
+        }
+    }
+
+    fn execute(&mut self, stream: usize, op: Op) {
+        let duration = self.config.op_duration_ms as f64;
+        let s = self.streams.get_mut(&stream).unwrap();
+        s.executed += 1;
+
+        match op {
+            Op::Copy { dst, src, len } => {
+                s.clock_ms += duration;
+                unsafe { std::ptr::copy(src as *const u8, dst as *mut u8, len) };
+            }
+            Op::Copy2D { dst, dst_pitch, src, src_pitch, width, height } => {
+                s.clock_ms += duration;
+                for row in 0..height {
+                    unsafe {
+                        std::ptr::copy(
+                            (src + row * src_pitch) as *const u8,
+                            (dst + row * dst_pitch) as *mut u8,
+                            width,
+                        )
+                    };
+                }
+            }
+            Op::Memset { dst, value, elem_size, count } => {
+                s.clock_ms += duration;
+                for i in 0..count {
+                    let dst = dst + i * elem_size;
+                    unsafe {
+                        match elem_size {
+                            1 => *(dst as *mut u8) = value as u8,
+                            2 => (dst as *mut u16).write_unaligned(value as u16),
+                            _ => (dst as *mut u32).write_unaligned(value),
+                        }
+                    };
+                }
+            }
+            Op::Free { ptr } => {
+                self.release(ptr);
+            }
+            Op::Record { event, seq } => {
+                let time_ms = s.clock_ms;
+                if let Some(e) = self.events.get_mut(&event) {
+                    if e.state == (EventState::Pending { stream, seq }) {
+                        e.state = EventState::Complete { time_ms };
+                    }
+                }
+            }
+            Op::HostFn { func, data } => {
+                // Unlike callbacks, host functions are skipped once the
+                // context has failed.
+                if self.contexts.get(&s.ctx).and_then(|c| c.sticky).is_none() {
+                    self.host_fns.push(Box::new(move || unsafe { func(data as _) }));
+                }
+            }
+            Op::Callback { func, data } => {
+                let status = self
+                    .contexts
+                    .get(&s.ctx)
+                    .and_then(|c| c.sticky)
+                    .unwrap_or(cudaError_enum_CUDA_SUCCESS);
+                self.host_fns.push(Box::new(move || unsafe {
+                    func(stream as CUstream, status, data as _)
+                }));
+            }
+            Op::Kernel => {
+                s.clock_ms += duration;
+            }
+            Op::EndLaunch { launch } => {
+                for object in self.launches.remove(&launch).unwrap_or_default() {
+                    self.release_user_object(object, 1);
+                }
+            }
+            Op::WriteValue { addr, value, wide } => unsafe {
+                if wide {
+                    std::ptr::write_volatile(addr as *mut u64, value);
+                } else {
+                    std::ptr::write_volatile(addr as *mut u32, value as u32);
+                }
+            },
+            Op::Wait { .. } | Op::WaitValue { .. } => {
+                unreachable!("waits are resolved in `advance`")
+            }
+        }
+    }
+}
+
+fn wait_value_holds(addr: usize, cond: u32, value: u64, wide: bool) -> bool {
+    let current = unsafe {
+        if wide {
+            std::ptr::read_volatile(addr as *const u64)
+        } else {
+            std::ptr::read_volatile(addr as *const u32) as u64
+        }
+    };
+
+    match cond {
+        // Compared cyclically, so the condition survives wrap-around.
+        CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_GEQ if wide => {
+            current.wrapping_sub(value) as i64 >= 0
+        }
+        CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_GEQ => {
+            (current as u32).wrapping_sub(value as u32) as i32 >= 0
+        }
+        CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_EQ => current == value,
+        CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_AND => current & value != 0,
+        _ if wide => !(current | value) != 0,
+        _ => !(current as u32 | value as u32) != 0,
+    }
+}
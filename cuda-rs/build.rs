@@ -0,0 +1,7 @@
+/// Uses the CUDA version selected by `cuda-rs-sys`, which can be newer than
+/// the `cuda-*` features of this crate if another dependent enables one.
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(cuda, values(\"11080\", \"12000\", \"12040\"))");
+    let version = std::env::var("DEP_CUDA_RS_SYS_VERSION").expect("cuda-rs-sys didn't report a CUDA version");
+    println!("cargo:rustc-cfg=cuda=\"{}\"", version);
+}
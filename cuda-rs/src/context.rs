@@ -1,9 +1,14 @@
 use crate::{
     device::CuDevice,
-    error::CuResult,
+    error::{raise, CuCallError, CuError, CuResult},
     ffi,
 };
-use std::sync::Arc;
+use std::{
+    marker::PhantomData,
+    ops::Deref,
+    sync::{Arc, RwLock},
+};
+use thiserror::Error;
 
 struct CUcontext(ffi::CUcontext);
 
@@ -13,36 +18,79 @@ impl Drop for CUcontext {
     }
 }
 
+/// A retained reference to the primary context of a device.
+struct CUprimaryContext {
+    ctx: ffi::CUcontext,
+    device: ffi::CUdevice,
+}
+
+impl Drop for CUprimaryContext {
+    fn drop(&mut self) {
+        unsafe { ffi::cuDevicePrimaryCtxRelease_v2(self.device) };
+    }
+}
+
+// Driver API calls are thread safe, and a context may be current on several
+// threads at once, so the handles can be shared freely.
+unsafe impl Send for CUcontext {}
+unsafe impl Sync for CUcontext {}
+unsafe impl Send for CUprimaryContext {}
+unsafe impl Sync for CUprimaryContext {}
+
 enum Inner {
     Owned(Arc<CUcontext>),
+    Primary(Arc<CUprimaryContext>),
     Borrowed(ffi::CUcontext),
 }
 
+/// A CUDA context.
+///
+/// # Thread safety
+///
+/// `CuContext` is `Send` and `Sync`. The driver API is thread safe, and a
+/// context may be current on any number of threads at the same time; each
+/// thread has its own context stack, which [`CuContext::push`] and
+/// [`CuContext::pop`] act on. The context is destroyed or released on
+/// whichever thread drops the last clone.
 pub struct CuContext(Inner);
 
+unsafe impl Send for CuContext {}
+unsafe impl Sync for CuContext {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CuContextHealth {
+    Healthy,
+    /// The context hit a sticky error and every call on it will keep failing
+    /// with it. It has to be destroyed, or reset with
+    /// `CuDevice::reset_primary_context` if it is a primary context.
+    Poisoned(CuError),
+}
+
 impl CuContext {
     pub fn new(device: &CuDevice) -> CuResult<Self> {
-        let mut ctx = std::ptr::null_mut();
-        let res = unsafe {
-            ffi::cuCtxCreate_v2(&mut ctx, 0, device.get_raw())
-        };
-        let ctx = CuContext(Inner::Owned(Arc::new(CUcontext(ctx))));
+        CuContextBuilder::new(device).build()
+    }
 
-        wrap!(ctx, res)
+    pub fn builder(device: &CuDevice) -> CuContextBuilder {
+        CuContextBuilder::new(device)
     }
 
     pub unsafe fn from_raw(ctx: ffi::CUcontext) -> Self {
         CuContext(Inner::Borrowed(ctx))
     }
 
+    /// Retains the primary context of `device`, the one the CUDA runtime
+    /// uses. It is released when the last clone of the returned context is
+    /// dropped.
     pub fn retain_primary_context(device: &CuDevice) -> CuResult<Self> {
         let mut ctx = std::ptr::null_mut();
         let res = unsafe {
             ffi::cuDevicePrimaryCtxRetain(&mut ctx, device.get_raw())
         };
-        let ctx = CuContext(Inner::Borrowed(ctx));
+        wrap!((), res, "cuDevicePrimaryCtxRetain", "device={}", device.get_raw())?;
 
-        wrap!(ctx, res)
+        let primary = CUprimaryContext { ctx, device: device.get_raw() };
+        Ok(CuContext(Inner::Primary(Arc::new(primary))))
     }
 
     pub fn current() -> CuResult<Self> {
@@ -50,23 +98,42 @@ impl CuContext {
         let res = unsafe { ffi::cuCtxGetCurrent(&mut ctx) };
         let ctx = CuContext(Inner::Borrowed(ctx));
 
-        wrap!(ctx, res)
+        wrap!(ctx, res, "cuCtxGetCurrent")
     }
 
     pub unsafe fn get_raw(&self) -> ffi::CUcontext {
         match self.0 {
             Inner::Owned(ref ctx) => ctx.0,
+            Inner::Primary(ref ctx) => ctx.ctx,
             Inner::Borrowed(ctx) => ctx,
         }
     }
 
+    /// Makes the context current while `f` runs, and restores the previous
+    /// one afterwards, also when `f` panics.
+    pub fn scoped<T>(&self, f: impl FnOnce() -> T) -> CuResult<T> {
+        let guard = CuContextGuard::new(self.clone())?;
+        let ret = f();
+        guard.pop()?;
+
+        Ok(ret)
+    }
+
+    /// The flags the context was created with.
+    pub fn flags(&self) -> CuResult<CuContextFlags> {
+        let mut flags = 0;
+        let res = self.scoped(|| unsafe { ffi::cuCtxGetFlags(&mut flags) })?;
+
+        wrap!(CuContextFlags::from_bits(flags), res, "cuCtxGetFlags")
+    }
+
     pub fn push(&self) -> CuResult<()> {
         let res = unsafe {
             let ctx = self.get_raw();
             ffi::cuCtxPushCurrent_v2(ctx)
         };
 
-        wrap!((), res)
+        wrap!((), res, "cuCtxPushCurrent_v2")
     }
 
     pub fn pop() -> CuResult<()> {
@@ -74,13 +141,129 @@ impl CuContext {
             ffi::cuCtxPopCurrent_v2(&mut std::ptr::null_mut())
         };
 
-        wrap!((), res)
+        wrap!((), res, "cuCtxPopCurrent_v2")
     }
 
     pub fn synchronize() -> CuResult<()> {
         let res = unsafe { ffi::cuCtxSynchronize() };
 
-        wrap!((), res)
+        wrap!((), res, "cuCtxSynchronize")
+    }
+
+    pub fn set_limit(&self, limit: CuLimit, value: usize) -> CuResult<()> {
+        let res = self.scoped(|| unsafe {
+            ffi::cuCtxSetLimit(limit as ffi::CUlimit, value)
+        })?;
+
+        wrap!((), res, "cuCtxSetLimit", "limit={:?}, value={}", limit, value)
+    }
+
+    pub fn get_limit(&self, limit: CuLimit) -> CuResult<usize> {
+        let mut value = 0;
+        let res = self.scoped(|| unsafe {
+            ffi::cuCtxGetLimit(&mut value, limit as ffi::CUlimit)
+        })?;
+
+        wrap!(value, res, "cuCtxGetLimit", "limit={:?}", limit)
+    }
+
+    /// Sets the preferred split between L1 cache and shared memory for
+    /// kernels launched in the context. The driver may not honor it.
+    pub fn set_cache_config(&self, config: CuCacheConfig) -> CuResult<()> {
+        let res = self.scoped(|| unsafe {
+            ffi::cuCtxSetCacheConfig(config as ffi::CUfunc_cache)
+        })?;
+
+        wrap!((), res, "cuCtxSetCacheConfig", "config={:?}", config)
+    }
+
+    pub fn cache_config(&self) -> CuResult<CuCacheConfig> {
+        let mut config = 0;
+        let res = self.scoped(|| unsafe { ffi::cuCtxGetCacheConfig(&mut config) })?;
+        wrap!((), res, "cuCtxGetCacheConfig")?;
+
+        CuCacheConfig::from_raw(config)
+    }
+
+    /// Sets the shared memory bank size. Devices of compute capability 5.0
+    /// and up have a fixed bank size and ignore it.
+    pub fn set_shared_mem_config(&self, config: CuSharedMemConfig) -> CuResult<()> {
+        let res = self.scoped(|| unsafe {
+            ffi::cuCtxSetSharedMemConfig(config as ffi::CUsharedconfig)
+        })?;
+
+        wrap!((), res, "cuCtxSetSharedMemConfig", "config={:?}", config)
+    }
+
+    pub fn shared_mem_config(&self) -> CuResult<CuSharedMemConfig> {
+        let mut config = 0;
+        let res = self.scoped(|| unsafe { ffi::cuCtxGetSharedMemConfig(&mut config) })?;
+        wrap!((), res, "cuCtxGetSharedMemConfig")?;
+
+        CuSharedMemConfig::from_raw(config)
+    }
+
+    /// The `(least, greatest)` stream priorities of the context. Greater
+    /// priorities are numerically lower, e.g. `(0, -5)`.
+    pub fn stream_priority_range(&self) -> CuResult<(i32, i32)> {
+        let mut least = 0;
+        let mut greatest = 0;
+        let res = self.scoped(|| unsafe {
+            ffi::cuCtxGetStreamPriorityRange(&mut least, &mut greatest)
+        })?;
+
+        wrap!((least, greatest), res, "cuCtxGetStreamPriorityRange")
+    }
+
+    /// The version of the driver API the context was created with, e.g.
+    /// `3020`.
+    pub fn api_version(&self) -> CuResult<u32> {
+        let mut version = 0;
+        let res = unsafe { ffi::cuCtxGetApiVersion(self.get_raw(), &mut version) };
+
+        wrap!(version, res, "cuCtxGetApiVersion")
+    }
+
+    pub fn device(&self) -> CuResult<CuDevice> {
+        let mut device = 0;
+        let res = self.scoped(|| unsafe { ffi::cuCtxGetDevice(&mut device) })?;
+
+        wrap!(CuDevice::from_raw(device), res, "cuCtxGetDevice")
+    }
+
+    /// Lets this context access memory allocated in `peer`, which lives on
+    /// another device. Access is one-way; `peer` needs its own call to access
+    /// memory of this context.
+    pub fn enable_peer_access(&self, peer: &CuContext) -> CuResult<()> {
+        let res = self.scoped(|| unsafe { ffi::cuCtxEnablePeerAccess(peer.get_raw(), 0) })?;
+
+        wrap!((), res, "cuCtxEnablePeerAccess", "peerContext={:?}", unsafe { peer.get_raw() })
+    }
+
+    pub fn disable_peer_access(&self, peer: &CuContext) -> CuResult<()> {
+        let res = self.scoped(|| unsafe { ffi::cuCtxDisablePeerAccess(peer.get_raw()) })?;
+
+        wrap!((), res, "cuCtxDisablePeerAccess", "peerContext={:?}", unsafe { peer.get_raw() })
+    }
+
+    /// Checks whether the context is still usable. This synchronizes the
+    /// context, so it waits for all outstanding work to finish.
+    ///
+    /// Errors that are not sticky are returned as errors, since they say
+    /// nothing about the state of the context.
+    pub fn health(&self) -> CuResult<CuContextHealth> {
+        let res = self.scoped(|| unsafe { ffi::cuCtxSynchronize() })?;
+
+        match wrap!((), res, "cuCtxSynchronize") {
+            Ok(()) => Ok(CuContextHealth::Healthy),
+            Err(err) if err.is_sticky() => Ok(CuContextHealth::Poisoned(err)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Whether the context hit a sticky error. See [`CuContext::health`].
+    pub fn is_poisoned(&self) -> bool {
+        matches!(self.health(), Ok(CuContextHealth::Poisoned(_)))
     }
 
     pub fn guard(self) -> CuResult<CuContextGuard> {
@@ -92,22 +275,508 @@ impl Clone for CuContext {
     fn clone(&self) -> Self {
         match self.0 {
             Inner::Owned(ref ctx) => CuContext(Inner::Owned(ctx.clone())),
+            Inner::Primary(ref ctx) => CuContext(Inner::Primary(ctx.clone())),
             Inner::Borrowed(ctx) => CuContext(Inner::Borrowed(ctx)),
         }
     }
 }
 
-pub struct CuContextGuard(pub CuContext);
+/// The limits of `CUlimit`.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuLimit {
+    /// Stack size in bytes of each GPU thread.
+    StackSize = 0,
+    /// Size in bytes of the FIFO used by device-side `printf`.
+    PrintfFifoSize = 1,
+    /// Size in bytes of the heap used by device-side `malloc`.
+    MallocHeapSize = 2,
+    /// Maximum nesting depth of device-side synchronization.
+    DevRuntimeSyncDepth = 3,
+    /// Maximum number of outstanding device-side launches.
+    DevRuntimePendingLaunchCount = 4,
+    /// L2 cache fetch granularity in bytes, a hint.
+    MaxL2FetchGranularity = 5,
+    /// Size in bytes of the L2 cache set aside for persisting accesses.
+    PersistingL2CacheSize = 6,
+}
+
+/// The preferred split between L1 cache and shared memory (`CUfunc_cache`).
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuCacheConfig {
+    #[default]
+    PreferNone = 0,
+    PreferShared = 1,
+    PreferL1 = 2,
+    PreferEqual = 3,
+}
+
+impl CuCacheConfig {
+    fn from_raw(config: ffi::CUfunc_cache) -> CuResult<Self> {
+        match config {
+            ffi::CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_NONE => Ok(CuCacheConfig::PreferNone),
+            ffi::CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_SHARED => Ok(CuCacheConfig::PreferShared),
+            ffi::CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_L1 => Ok(CuCacheConfig::PreferL1),
+            ffi::CUfunc_cache_enum_CU_FUNC_CACHE_PREFER_EQUAL => Ok(CuCacheConfig::PreferEqual),
+            _ => Err(raise(CuError::InvalidValue)),
+        }
+    }
+}
+
+/// The shared memory bank size (`CUsharedconfig`).
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuSharedMemConfig {
+    #[default]
+    DefaultBankSize = 0,
+    FourByteBankSize = 1,
+    EightByteBankSize = 2,
+}
+
+impl CuSharedMemConfig {
+    fn from_raw(config: ffi::CUsharedconfig) -> CuResult<Self> {
+        match config {
+            ffi::CUsharedconfig_enum_CU_SHARED_MEM_CONFIG_DEFAULT_BANK_SIZE => {
+                Ok(CuSharedMemConfig::DefaultBankSize)
+            }
+            ffi::CUsharedconfig_enum_CU_SHARED_MEM_CONFIG_FOUR_BYTE_BANK_SIZE => {
+                Ok(CuSharedMemConfig::FourByteBankSize)
+            }
+            ffi::CUsharedconfig_enum_CU_SHARED_MEM_CONFIG_EIGHT_BYTE_BANK_SIZE => {
+                Ok(CuSharedMemConfig::EightByteBankSize)
+            }
+            _ => Err(raise(CuError::InvalidValue)),
+        }
+    }
+}
+
+/// How the host thread waits for the GPU when synchronizing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuSchedPolicy {
+    /// Spin when the GPU has fewer active contexts than the host has cores,
+    /// and yield otherwise.
+    #[default]
+    Auto,
+    /// Busy-wait: lowest latency, but keeps a CPU core busy.
+    Spin,
+    /// Yield the thread while waiting: may add latency.
+    Yield,
+    /// Block the thread on a synchronization primitive: lowest CPU usage.
+    BlockingSync,
+}
+
+/// The `CU_CTX_*` flags of a context.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuContextFlags {
+    pub sched: CuSchedPolicy,
+    /// Allow mapping page-locked host memory into the device address space.
+    pub map_host: bool,
+    /// Keep local memory allocated after resizing it for a kernel, instead of
+    /// shrinking it back.
+    pub lmem_resize_to_max: bool,
+}
+
+impl CuContextFlags {
+    pub fn bits(&self) -> u32 {
+        let sched = match self.sched {
+            CuSchedPolicy::Auto => ffi::CUctx_flags_enum_CU_CTX_SCHED_AUTO,
+            CuSchedPolicy::Spin => ffi::CUctx_flags_enum_CU_CTX_SCHED_SPIN,
+            CuSchedPolicy::Yield => ffi::CUctx_flags_enum_CU_CTX_SCHED_YIELD,
+            CuSchedPolicy::BlockingSync => ffi::CUctx_flags_enum_CU_CTX_SCHED_BLOCKING_SYNC,
+        };
+        let mut bits = sched;
+        if self.map_host {
+            bits |= ffi::CUctx_flags_enum_CU_CTX_MAP_HOST;
+        }
+        if self.lmem_resize_to_max {
+            bits |= ffi::CUctx_flags_enum_CU_CTX_LMEM_RESIZE_TO_MAX;
+        }
+
+        bits
+    }
+
+    /// Decodes `CU_CTX_*` flags. Flags without a field here are ignored.
+    pub fn from_bits(bits: u32) -> Self {
+        let sched = match bits & ffi::CUctx_flags_enum_CU_CTX_SCHED_MASK {
+            ffi::CUctx_flags_enum_CU_CTX_SCHED_SPIN => CuSchedPolicy::Spin,
+            ffi::CUctx_flags_enum_CU_CTX_SCHED_YIELD => CuSchedPolicy::Yield,
+            ffi::CUctx_flags_enum_CU_CTX_SCHED_BLOCKING_SYNC => CuSchedPolicy::BlockingSync,
+            _ => CuSchedPolicy::Auto,
+        };
+
+        CuContextFlags {
+            sched,
+            map_host: bits & ffi::CUctx_flags_enum_CU_CTX_MAP_HOST != 0,
+            lmem_resize_to_max: bits & ffi::CUctx_flags_enum_CU_CTX_LMEM_RESIZE_TO_MAX != 0,
+        }
+    }
+}
+
+pub struct CuContextBuilder {
+    device: CuDevice,
+    flags: CuContextFlags,
+}
+
+impl CuContextBuilder {
+    pub fn new(device: &CuDevice) -> Self {
+        CuContextBuilder { device: *device, flags: CuContextFlags::default() }
+    }
+
+    pub fn flags(mut self, flags: CuContextFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn sched(mut self, sched: CuSchedPolicy) -> Self {
+        self.flags.sched = sched;
+        self
+    }
+
+    pub fn map_host(mut self, map_host: bool) -> Self {
+        self.flags.map_host = map_host;
+        self
+    }
+
+    pub fn lmem_resize_to_max(mut self, lmem_resize_to_max: bool) -> Self {
+        self.flags.lmem_resize_to_max = lmem_resize_to_max;
+        self
+    }
+
+    /// Creates the context and makes it current on the calling thread.
+    pub fn build(self) -> CuResult<CuContext> {
+        let mut ctx = std::ptr::null_mut();
+        let flags = self.flags.bits();
+        let res = unsafe {
+            ffi::cuCtxCreate_v2(&mut ctx, flags, self.device.get_raw())
+        };
+        let ctx = CuContext(Inner::Owned(Arc::new(CUcontext(ctx))));
+
+        wrap!(ctx, res, "cuCtxCreate_v2", "flags={:#x}, device={}", flags, self.device.get_raw())
+    }
+}
+
+/// Why a [`CuContextGuard`] could not restore the previous context.
+#[derive(Error, Debug, Clone)]
+pub enum CuContextGuardError {
+    /// Another context was current when the guard was dropped, so something
+    /// pushed a context without popping it. The stack is left alone.
+    /// The contexts are given by the addresses of their handles.
+    #[error("expected context {expected:#x} to be current, found {found:#x}")]
+    Mismatch {
+        expected: usize,
+        found: usize,
+    },
+    #[error(transparent)]
+    Call(#[from] CuError),
+}
+
+/// Also makes a mismatch what [`CuCallError::last`] returns.
+impl From<CuContextGuardError> for CuError {
+    fn from(err: CuContextGuardError) -> Self {
+        match err {
+            CuContextGuardError::Mismatch { expected, found } => {
+                CuCallError::new(CuError::InvalidContext, "cuCtxPopCurrent_v2")
+                    .with_args(format!("expected={:#x}, found={:#x}", expected, found))
+                    .record()
+            }
+            CuContextGuardError::Call(err) => err,
+        }
+    }
+}
+
+type GuardErrorHook = Box<dyn Fn(&CuContextGuardError) + Send + Sync>;
+
+static GUARD_ERROR_HOOK: RwLock<Option<GuardErrorHook>> = RwLock::new(None);
+
+/// Sets the function called when a dropped [`CuContextGuard`] fails to pop
+/// its context. By default the error is printed to stderr; pass a no-op
+/// closure to silence it.
+pub fn set_guard_error_hook(hook: impl Fn(&CuContextGuardError) + Send + Sync + 'static) {
+    let mut guard = GUARD_ERROR_HOOK.write().unwrap_or_else(|e| e.into_inner());
+    *guard = Some(Box::new(hook));
+}
+
+/// Restores the default [`set_guard_error_hook`], which prints to stderr.
+pub fn reset_guard_error_hook() {
+    let mut guard = GUARD_ERROR_HOOK.write().unwrap_or_else(|e| e.into_inner());
+    *guard = None;
+}
+
+fn report_guard_error(err: &CuContextGuardError) {
+    let hook = GUARD_ERROR_HOOK.read().unwrap_or_else(|e| e.into_inner());
+    match hook.as_ref() {
+        Some(hook) => hook(err),
+        None => eprintln!("cuda-rs: failed to pop context guard: {}", err),
+    }
+}
+
+/// Keeps a context current on this thread until dropped.
+///
+/// The guard only pops the context it pushed. If another context is on top
+/// of the stack when it is dropped, or the pop fails, the error goes to the
+/// hook set by [`set_guard_error_hook`] instead of panicking. Use
+/// [`CuContextGuard::pop`] to handle it directly.
+///
+/// The guard is `!Send`, since the context stack is per thread:
+///
+/// ```compile_fail
+/// fn assert_send<T: Send>() {}
+/// assert_send::<cuda_rs::context::CuContextGuard>();
+/// ```
+pub struct CuContextGuard {
+    ctx: CuContext,
+    popped: bool,
+    _not_send: PhantomData<*mut ()>,
+}
 
 impl CuContextGuard {
     pub fn new(ctx: CuContext) -> CuResult<Self> {
         ctx.push()?;
-        Ok(CuContextGuard(ctx))
+        Ok(CuContextGuard { ctx, popped: false, _not_send: PhantomData })
+    }
+
+    pub fn context(&self) -> &CuContext {
+        &self.ctx
+    }
+
+    /// Pops the context, returning an error instead of reporting it.
+    pub fn pop(mut self) -> Result<CuContext, CuContextGuardError> {
+        self.popped = true;
+        self.try_pop()?;
+
+        Ok(self.ctx.clone())
+    }
+
+    fn try_pop(&self) -> Result<(), CuContextGuardError> {
+        let expected = unsafe { self.ctx.get_raw() };
+        let found = unsafe { CuContext::current()?.get_raw() };
+        if found != expected {
+            return Err(CuContextGuardError::Mismatch { expected: expected as usize, found: found as usize });
+        }
+
+        Ok(CuContext::pop()?)
+    }
+}
+
+impl Deref for CuContextGuard {
+    type Target = CuContext;
+
+    fn deref(&self) -> &CuContext {
+        &self.ctx
     }
 }
 
 impl Drop for CuContextGuard {
     fn drop(&mut self) {
+        if self.popped {
+            return;
+        }
+
+        if let Err(err) = self.try_pop() {
+            report_guard_error(&err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{memory::DeviceMemory, stream::CuStream};
+    use std::sync::Mutex;
+
+    #[test]
+    fn guard() {
+        crate::init().unwrap();
+        let device = CuDevice::new(0).unwrap();
+        let outer = CuContext::new(&device).unwrap();
+        let inner = CuContext::new(&device).unwrap();
+        CuContext::pop().unwrap();
+        CuContext::pop().unwrap();
+
+        static REPORTED: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+        set_guard_error_hook(|err| {
+            if let CuContextGuardError::Mismatch { expected, .. } = err {
+                REPORTED.lock().unwrap().push(*expected);
+            }
+        });
+
+        // A context pushed inside the guard and never popped.
+        let guard = outer.clone().guard().unwrap();
+        inner.push().unwrap();
+        drop(guard);
+        reset_guard_error_hook();
+        assert!(REPORTED.lock().unwrap().contains(&(unsafe { outer.get_raw() } as usize)));
+        assert_eq!(unsafe { CuContext::current().unwrap().get_raw() }, unsafe { inner.get_raw() });
+        CuContext::pop().unwrap();
+        CuContext::pop().unwrap();
+
+        let guard = outer.clone().guard().unwrap();
+        ffi::mock::fail_next("cuCtxPopCurrent_v2", ffi::cudaError_enum_CUDA_ERROR_INVALID_CONTEXT);
+        let err = guard.pop().err().unwrap();
+        assert!(matches!(err, CuContextGuardError::Call(CuError::InvalidContext)));
+        assert_eq!(CuCallError::last().unwrap().function(), Some("cuCtxPopCurrent_v2"));
         CuContext::pop().unwrap();
     }
+
+    #[test]
+    fn scoped() {
+        crate::init().unwrap();
+        let device = CuDevice::new(0).unwrap();
+        let ctx = CuContext::new(&device).unwrap();
+        CuContext::pop().unwrap();
+        let before = unsafe { CuContext::current().unwrap().get_raw() };
+
+        let current = ctx.scoped(|| unsafe { CuContext::current().unwrap().get_raw() }).unwrap();
+        assert_eq!(current, unsafe { ctx.get_raw() });
+        assert_eq!(unsafe { CuContext::current().unwrap().get_raw() }, before);
+
+        let res = std::panic::catch_unwind(|| ctx.scoped(|| panic!("in scope")));
+        assert!(res.is_err());
+        assert_eq!(unsafe { CuContext::current().unwrap().get_raw() }, before);
+    }
+
+    #[test]
+    fn thread_safety() {
+        crate::assert_send_sync::<CuContext>();
+        crate::assert_send_sync::<CuContextGuardError>();
+
+        crate::init().unwrap();
+        let ctx = CuContext::new(&CuDevice::new(0).unwrap()).unwrap();
+        CuContext::pop().unwrap();
+        let raw = unsafe { ctx.get_raw() } as usize;
+
+        let current = std::thread::spawn(move || {
+            ctx.scoped(|| unsafe { CuContext::current().unwrap().get_raw() } as usize).unwrap()
+        }).join().unwrap();
+        assert_eq!(current, raw);
+    }
+
+    #[test]
+    fn poisoned_context() {
+        crate::init().unwrap();
+        let device = CuDevice::new(0).unwrap();
+        let ctx = CuContext::new(&device).unwrap();
+        assert_eq!(ctx.health().unwrap(), CuContextHealth::Healthy);
+        assert!(!ctx.is_poisoned());
+
+        let stream = CuStream::new().unwrap();
+        unsafe {
+            ffi::mock::poison_context(ctx.get_raw(), ffi::cudaError_enum_CUDA_ERROR_ILLEGAL_ADDRESS)
+        }.unwrap();
+        assert_eq!(
+            ctx.health().unwrap(),
+            CuContextHealth::Poisoned(CuError::IllegalAddress),
+        );
+        assert!(ctx.is_poisoned());
+        assert!(DeviceMemory::new(16, &stream).err().unwrap().is_sticky());
+    }
+
+    #[test]
+    fn limits_and_config() {
+        crate::init().unwrap();
+        let device = CuDevice::new(0).unwrap();
+        let ctx = CuContext::new(&device).unwrap();
+        CuContext::pop().unwrap();
+
+        ctx.set_limit(CuLimit::PrintfFifoSize, 4 << 20).unwrap();
+        assert_eq!(ctx.get_limit(CuLimit::PrintfFifoSize).unwrap(), 4 << 20);
+        assert_eq!(ctx.get_limit(CuLimit::StackSize).unwrap(), 1 << 10);
+
+        assert_eq!(ctx.cache_config().unwrap(), CuCacheConfig::PreferNone);
+        ctx.set_cache_config(CuCacheConfig::PreferShared).unwrap();
+        assert_eq!(ctx.cache_config().unwrap(), CuCacheConfig::PreferShared);
+        ctx.set_shared_mem_config(CuSharedMemConfig::EightByteBankSize).unwrap();
+        assert_eq!(ctx.shared_mem_config().unwrap(), CuSharedMemConfig::EightByteBankSize);
+
+        assert_eq!(ctx.stream_priority_range().unwrap(), (0, -5));
+        assert_eq!(ctx.api_version().unwrap(), 3020);
+        assert_eq!(ctx.device().unwrap(), device);
+    }
+
+    #[test]
+    fn builder() {
+        crate::init().unwrap();
+        let device = CuDevice::new(0).unwrap();
+        let ctx = CuContext::builder(&device)
+            .sched(CuSchedPolicy::BlockingSync)
+            .map_host(true)
+            .build()
+            .unwrap();
+        CuContext::pop().unwrap();
+
+        let flags = ctx.flags().unwrap();
+        assert_eq!(flags.sched, CuSchedPolicy::BlockingSync);
+        assert!(flags.map_host);
+        assert!(!flags.lmem_resize_to_max);
+        assert_eq!(CuContextFlags::from_bits(flags.bits()), flags);
+    }
+
+    #[test]
+    fn peer_access() {
+        let _ctx = crate::test_context();
+        ffi::mock::set_device_count(crate::TEST_DEVICE_COUNT);
+        let ctx0 = CuContext::current().unwrap();
+        let ctx1 = CuContext::new(&CuDevice::new(1).unwrap()).unwrap();
+        CuContext::pop().unwrap();
+
+        ctx0.enable_peer_access(&ctx1).unwrap();
+        assert_eq!(
+            ctx0.enable_peer_access(&ctx1).unwrap_err(),
+            CuError::PeerAccessAlreadyEnabled,
+        );
+        ctx0.disable_peer_access(&ctx1).unwrap();
+        assert_eq!(
+            ctx0.disable_peer_access(&ctx1).unwrap_err(),
+            CuError::PeerAccessNotEnabled,
+        );
+        assert_eq!(
+            ctx0.enable_peer_access(&ctx0).unwrap_err(),
+            CuError::InvalidDevice,
+        );
+    }
+
+    #[test]
+    fn primary_context_release() {
+        crate::init().unwrap();
+        ffi::mock::set_device_count(crate::TEST_DEVICE_COUNT);
+        // No other test uses this device's primary context.
+        let device = CuDevice::new(3).unwrap();
+        assert!(!device.primary_context_state().unwrap().1);
+
+        let ctx = device.retain_primary_context().unwrap();
+        let clone = ctx.clone();
+        assert!(device.primary_context_state().unwrap().1);
+        drop(ctx);
+        assert!(device.primary_context_state().unwrap().1);
+        drop(clone);
+        assert!(!device.primary_context_state().unwrap().1);
+
+        // A failed retain must not release anything.
+        let kept = device.retain_primary_context().unwrap();
+        ffi::mock::fail_next(
+            "cuDevicePrimaryCtxRetain",
+            ffi::cudaError_enum_CUDA_ERROR_OUT_OF_MEMORY,
+        );
+        assert!(device.retain_primary_context().is_err());
+        assert!(device.primary_context_state().unwrap().1);
+        drop(kept);
+        assert!(!device.primary_context_state().unwrap().1);
+    }
+
+    #[test]
+    fn reset_primary_context() {
+        crate::init().unwrap();
+        ffi::mock::set_device_count(crate::TEST_DEVICE_COUNT);
+        let device = CuDevice::new(1).unwrap();
+        let ctx = device.retain_primary_context().unwrap();
+
+        unsafe {
+            ffi::mock::poison_context(ctx.get_raw(), ffi::cudaError_enum_CUDA_ERROR_LAUNCH_FAILED)
+        }.unwrap();
+        assert!(ctx.is_poisoned());
+
+        device.reset_primary_context().unwrap();
+        assert_eq!(ctx.health().unwrap(), CuContextHealth::Healthy);
+    }
 }
@@ -1,11 +1,14 @@
 use crate::{
-    context::CuContext,
-    error::CuResult,
+    context::{CuContext, CuContextFlags},
+    error::{raise, CuError, CuResult},
     ffi,
 };
-use std::os::raw::c_int;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_char, c_int};
+use std::str::FromStr;
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct CuDevice(ffi::CUdevice);
 
 impl CuDevice {
@@ -15,30 +18,626 @@ impl CuDevice {
             ffi::cuDeviceGet(&mut d.0 as *mut i32, ordinal as c_int)
         };
 
-        wrap!(d, res)
+        wrap!(d, res, "cuDeviceGet", "ordinal={}", ordinal)
     }
 
     pub fn get_device_count() -> CuResult<i32> {
         let mut count = 0;
         let res = unsafe { ffi::cuDeviceGetCount(&mut count as *mut i32) };
 
-        wrap!(count, res)
+        wrap!(count, res, "cuDeviceGetCount")
+    }
+
+    /// Iterates over all devices visible to the driver, in ordinal order.
+    pub fn all() -> CuResult<impl ExactSizeIterator<Item = CuDevice>> {
+        let count = Self::get_device_count()?;
+
+        Ok((0..count).map(CuDevice))
+    }
+
+    /// Looks up a device by its PCI bus id, `[domain:]bus:device.function`
+    /// in hex as reported by `nvidia-smi`, e.g. `"0000:01:00.0"`.
+    pub fn from_pci_bus_id(pci_bus_id: &str) -> CuResult<Self> {
+        let id = CString::new(pci_bus_id).map_err(|_| CuError::InvalidValue)?;
+        let mut d = CuDevice(0);
+        let res = unsafe { ffi::cuDeviceGetByPCIBusId(&mut d.0, id.as_ptr()) };
+
+        wrap!(d, res, "cuDeviceGetByPCIBusId", "pciBusId={:?}", pci_bus_id)
+    }
+
+    /// Looks up a device by its UUID. Fails with `CuError::InvalidDevice` if
+    /// no device has it.
+    pub fn from_uuid(uuid: &CuUuid) -> CuResult<Self> {
+        for device in Self::all()? {
+            if device.uuid()? == *uuid {
+                return Ok(device);
+            }
+        }
+
+        Err(raise(CuError::InvalidDevice))
+    }
+
+    /// Resolves a `CUDA_VISIBLE_DEVICES`-style list into devices, in the
+    /// order given.
+    ///
+    /// Entries are comma-separated ordinals, UUIDs with the `GPU-` prefix
+    /// (or an unambiguous prefix of one) or, as an extension, PCI bus ids.
+    /// As with CUDA, the list ends at the first entry that is invalid,
+    /// repeated or names no device. Ordinals and UUIDs refer to the devices
+    /// the driver sees, which are already filtered if the process runs with
+    /// `CUDA_VISIBLE_DEVICES` set.
+    pub fn parse_visible_devices(spec: &str) -> CuResult<Vec<Self>> {
+        let devices: Vec<_> = Self::all()?
+            .map(|d| Ok((d, d.uuid()?.to_string().to_ascii_lowercase())))
+            .collect::<CuResult<_>>()?;
+        let mut visible = Vec::new();
+
+        for entry in spec.split(',').map(str::trim) {
+            let found = if let Ok(ordinal) = entry.parse::<usize>() {
+                devices.get(ordinal).map(|&(d, _)| d)
+            } else if entry.starts_with("GPU-") {
+                let entry = entry.to_ascii_lowercase();
+                let mut matches = devices.iter().filter(|(_, uuid)| uuid.starts_with(&entry));
+                match (matches.next(), matches.next()) {
+                    (Some(&(d, _)), None) => Some(d),
+                    _ => None,
+                }
+            } else if entry.contains(':') {
+                Self::from_pci_bus_id(entry).ok()
+            } else {
+                None
+            };
+
+            match found {
+                Some(d) if !visible.contains(&d) => visible.push(d),
+                _ => break,
+            }
+        }
+
+        Ok(visible)
     }
 
     pub fn retain_primary_context(&self) -> CuResult<CuContext> {
         CuContext::retain_primary_context(self)
     }
 
+    /// The flags of the primary context, and whether it is active (retained
+    /// at least once).
+    pub fn primary_context_state(&self) -> CuResult<(CuContextFlags, bool)> {
+        let mut flags = 0;
+        let mut active = 0;
+        let res = unsafe {
+            ffi::cuDevicePrimaryCtxGetState(self.0, &mut flags, &mut active)
+        };
+
+        wrap!(
+            (CuContextFlags::from_bits(flags), active != 0), res,
+            "cuDevicePrimaryCtxGetState", "device={}", self.0
+        )
+    }
+
+    /// Sets the flags of the primary context. Since CUDA 11 this also works
+    /// while the primary context is active.
+    pub fn set_primary_context_flags(&self, flags: CuContextFlags) -> CuResult<()> {
+        let bits = flags.bits();
+        let res = unsafe { ffi::cuDevicePrimaryCtxSetFlags_v2(self.0, bits) };
+
+        wrap!((), res, "cuDevicePrimaryCtxSetFlags_v2", "device={}, flags={:#x}", self.0, bits)
+    }
+
+    /// Destroys all allocations and resets all state of the primary context
+    /// of the device, e.g. to recover from a sticky error. Handles to the
+    /// primary context stay valid, but everything created in it is gone.
+    pub fn reset_primary_context(&self) -> CuResult<()> {
+        let res = unsafe { ffi::cuDevicePrimaryCtxReset_v2(self.0) };
+
+        wrap!((), res, "cuDevicePrimaryCtxReset_v2", "device={}", self.0)
+    }
+
     pub fn total_memory(&self) -> CuResult<usize> {
         let mut nbytes = 0;
         let res = unsafe {
             ffi::cuDeviceTotalMem_v2(&mut nbytes as *mut usize, self.0)
         };
 
-        wrap!(nbytes, res)
+        wrap!(nbytes, res, "cuDeviceTotalMem_v2", "device={}", self.0)
+    }
+
+    pub fn name(&self) -> CuResult<String> {
+        let mut name = [0 as c_char; 256];
+        let res = unsafe {
+            ffi::cuDeviceGetName(name.as_mut_ptr(), name.len() as c_int, self.0)
+        };
+
+        wrap!(c_str_to_string(&name), res, "cuDeviceGetName", "device={}", self.0)
+    }
+
+    pub fn uuid(&self) -> CuResult<CuUuid> {
+        let mut uuid = ffi::CUuuid::default();
+        let res = unsafe { ffi::cuDeviceGetUuid_v2(&mut uuid, self.0) };
+
+        wrap!(
+            CuUuid(uuid.bytes.map(|b| b as u8)), res,
+            "cuDeviceGetUuid_v2", "device={}", self.0
+        )
+    }
+
+    /// The PCI bus id of the device, as `domain:bus:device.function` in hex,
+    /// e.g. `"0000:01:00.0"`.
+    pub fn pci_bus_id(&self) -> CuResult<String> {
+        let mut id = [0 as c_char; 32];
+        let res = unsafe {
+            ffi::cuDeviceGetPCIBusId(id.as_mut_ptr(), id.len() as c_int, self.0)
+        };
+
+        wrap!(c_str_to_string(&id), res, "cuDeviceGetPCIBusId", "device={}", self.0)
+    }
+
+    /// The `(major, minor)` compute capability of the device.
+    pub fn compute_capability(&self) -> CuResult<(i32, i32)> {
+        let major = self.attribute(CuDeviceAttribute::ComputeCapabilityMajor)?;
+        let minor = self.attribute(CuDeviceAttribute::ComputeCapabilityMinor)?;
+
+        Ok((major, minor))
+    }
+
+    pub fn attribute(&self, attr: CuDeviceAttribute) -> CuResult<i32> {
+        let mut value = 0;
+        let res = unsafe {
+            ffi::cuDeviceGetAttribute(&mut value, attr as ffi::CUdevice_attribute, self.0)
+        };
+
+        wrap!(value, res, "cuDeviceGetAttribute", "attrib={:?}, device={}", attr, self.0)
+    }
+
+    /// Whether contexts on this device can access memory of `peer`.
+    pub fn can_access_peer(&self, peer: &CuDevice) -> CuResult<bool> {
+        let mut can_access = 0;
+        let res = unsafe {
+            ffi::cuDeviceCanAccessPeer(&mut can_access, self.0, peer.0)
+        };
+
+        wrap!(can_access != 0, res, "cuDeviceCanAccessPeer", "dev={}, peerDev={}", self.0, peer.0)
+    }
+
+    /// Queries an attribute of the link from this device to `peer`.
+    pub fn p2p_attribute(&self, attr: CuP2PAttribute, peer: &CuDevice) -> CuResult<i32> {
+        let mut value = 0;
+        let res = unsafe {
+            ffi::cuDeviceGetP2PAttribute(
+                &mut value, attr as ffi::CUdevice_P2PAttribute, self.0, peer.0
+            )
+        };
+
+        wrap!(
+            value, res,
+            "cuDeviceGetP2PAttribute", "attrib={:?}, srcDevice={}, dstDevice={}", attr, self.0, peer.0
+        )
+    }
+
+    pub fn properties(&self) -> CuResult<DeviceProperties> {
+        use CuDeviceAttribute::*;
+
+        let attr = |attr| self.attribute(attr);
+        let flag = |attr| self.attribute(attr).map(|value| value != 0);
+
+        Ok(DeviceProperties {
+            name: self.name()?,
+            uuid: self.uuid()?,
+            pci_bus_id: self.pci_bus_id()?,
+            compute_capability: self.compute_capability()?,
+            total_memory: self.total_memory()?,
+            multiprocessor_count: attr(MultiprocessorCount)?,
+            clock_rate_khz: attr(ClockRate)?,
+            memory_clock_rate_khz: attr(MemoryClockRate)?,
+            memory_bus_width: attr(GlobalMemoryBusWidth)?,
+            l2_cache_size: attr(L2CacheSize)?,
+            total_constant_memory: attr(TotalConstantMemory)?,
+            warp_size: attr(WarpSize)?,
+            max_threads_per_block: attr(MaxThreadsPerBlock)?,
+            max_threads_per_multiprocessor: attr(MaxThreadsPerMultiprocessor)?,
+            max_blocks_per_multiprocessor: attr(MaxBlocksPerMultiprocessor)?,
+            max_block_dim: [attr(MaxBlockDimX)?, attr(MaxBlockDimY)?, attr(MaxBlockDimZ)?],
+            max_grid_dim: [attr(MaxGridDimX)?, attr(MaxGridDimY)?, attr(MaxGridDimZ)?],
+            max_shared_memory_per_block: attr(MaxSharedMemoryPerBlock)?,
+            max_shared_memory_per_block_optin: attr(MaxSharedMemoryPerBlockOptin)?,
+            max_shared_memory_per_multiprocessor: attr(MaxSharedMemoryPerMultiprocessor)?,
+            max_registers_per_block: attr(MaxRegistersPerBlock)?,
+            max_registers_per_multiprocessor: attr(MaxRegistersPerMultiprocessor)?,
+            async_engine_count: attr(AsyncEngineCount)?,
+            integrated: flag(Integrated)?,
+            ecc_enabled: flag(EccEnabled)?,
+            concurrent_kernels: flag(ConcurrentKernels)?,
+            unified_addressing: flag(UnifiedAddressing)?,
+            managed_memory: flag(ManagedMemory)?,
+            concurrent_managed_access: flag(ConcurrentManagedAccess)?,
+            memory_pools_supported: flag(MemoryPoolsSupported)?,
+            stream_priorities_supported: flag(StreamPrioritiesSupported)?,
+            cooperative_launch: flag(CooperativeLaunch)?,
+        })
     }
 
     pub fn get_raw(&self) -> ffi::CUdevice {
         self.0
     }
+
+    pub(crate) fn from_raw(device: ffi::CUdevice) -> Self {
+        CuDevice(device)
+    }
+}
+
+fn c_str_to_string(buf: &[c_char]) -> String {
+    unsafe { CStr::from_ptr(buf.as_ptr()) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// The UUID of a device, formatted like `nvidia-smi -L` does, e.g.
+/// `GPU-8932f937-d72c-4106-c12f-20bd9faed9f6`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CuUuid(pub [u8; 16]);
+
+impl fmt::Display for CuUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("GPU")?;
+        for (i, b) in self.0.iter().enumerate() {
+            if matches!(i, 0 | 4 | 6 | 8 | 10) {
+                f.write_str("-")?;
+            }
+            write!(f, "{:02x}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for CuUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Parses a UUID with or without the `GPU-` prefix. Dashes are optional.
+impl FromStr for CuUuid {
+    type Err = CuError;
+
+    fn from_str(s: &str) -> Result<Self, CuError> {
+        let hex: Vec<u8> = s
+            .strip_prefix("GPU-")
+            .unwrap_or(s)
+            .bytes()
+            .filter(|&c| c != b'-')
+            .collect();
+        // `from_str_radix` alone would accept a sign, as in "+f".
+        if hex.len() != 32 || !hex.iter().all(u8::is_ascii_hexdigit) {
+            return Err(CuError::InvalidValue);
+        }
+
+        let mut bytes = [0; 16];
+        for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+            let pair = std::str::from_utf8(pair).map_err(|_| CuError::InvalidValue)?;
+            *byte = u8::from_str_radix(pair, 16).map_err(|_| CuError::InvalidValue)?;
+        }
+
+        Ok(CuUuid(bytes))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CuUuid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CuUuid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(|_| serde::de::Error::custom("invalid device UUID"))
+    }
+}
+
+/// A snapshot of the commonly used attributes of a device. Clock rates are in
+/// kHz, sizes in bytes and the memory bus width in bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceProperties {
+    pub name: String,
+    pub uuid: CuUuid,
+    pub pci_bus_id: String,
+    pub compute_capability: (i32, i32),
+    pub total_memory: usize,
+    pub multiprocessor_count: i32,
+    pub clock_rate_khz: i32,
+    pub memory_clock_rate_khz: i32,
+    pub memory_bus_width: i32,
+    pub l2_cache_size: i32,
+    pub total_constant_memory: i32,
+    pub warp_size: i32,
+    pub max_threads_per_block: i32,
+    pub max_threads_per_multiprocessor: i32,
+    pub max_blocks_per_multiprocessor: i32,
+    pub max_block_dim: [i32; 3],
+    pub max_grid_dim: [i32; 3],
+    pub max_shared_memory_per_block: i32,
+    pub max_shared_memory_per_block_optin: i32,
+    pub max_shared_memory_per_multiprocessor: i32,
+    pub max_registers_per_block: i32,
+    pub max_registers_per_multiprocessor: i32,
+    pub async_engine_count: i32,
+    pub integrated: bool,
+    pub ecc_enabled: bool,
+    pub concurrent_kernels: bool,
+    pub unified_addressing: bool,
+    pub managed_memory: bool,
+    pub concurrent_managed_access: bool,
+    pub memory_pools_supported: bool,
+    pub stream_priorities_supported: bool,
+    pub cooperative_launch: bool,
+}
+
+/// The attributes of `CUdevice_attribute`, as of CUDA 12.4. Attributes newer
+/// than the installed driver fail with `CuError::InvalidValue`.
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CuDeviceAttribute {
+    MaxThreadsPerBlock = 1,
+    MaxBlockDimX = 2,
+    MaxBlockDimY = 3,
+    MaxBlockDimZ = 4,
+    MaxGridDimX = 5,
+    MaxGridDimY = 6,
+    MaxGridDimZ = 7,
+    MaxSharedMemoryPerBlock = 8,
+    TotalConstantMemory = 9,
+    WarpSize = 10,
+    MaxPitch = 11,
+    MaxRegistersPerBlock = 12,
+    ClockRate = 13,
+    TextureAlignment = 14,
+    GpuOverlap = 15,
+    MultiprocessorCount = 16,
+    KernelExecTimeout = 17,
+    Integrated = 18,
+    CanMapHostMemory = 19,
+    ComputeMode = 20,
+    MaximumTexture1dWidth = 21,
+    MaximumTexture2dWidth = 22,
+    MaximumTexture2dHeight = 23,
+    MaximumTexture3dWidth = 24,
+    MaximumTexture3dHeight = 25,
+    MaximumTexture3dDepth = 26,
+    MaximumTexture2dLayeredWidth = 27,
+    MaximumTexture2dLayeredHeight = 28,
+    MaximumTexture2dLayeredLayers = 29,
+    SurfaceAlignment = 30,
+    ConcurrentKernels = 31,
+    EccEnabled = 32,
+    PciBusId = 33,
+    PciDeviceId = 34,
+    TccDriver = 35,
+    MemoryClockRate = 36,
+    GlobalMemoryBusWidth = 37,
+    L2CacheSize = 38,
+    MaxThreadsPerMultiprocessor = 39,
+    AsyncEngineCount = 40,
+    UnifiedAddressing = 41,
+    MaximumTexture1dLayeredWidth = 42,
+    MaximumTexture1dLayeredLayers = 43,
+    CanTex2dGather = 44,
+    MaximumTexture2dGatherWidth = 45,
+    MaximumTexture2dGatherHeight = 46,
+    MaximumTexture3dWidthAlternate = 47,
+    MaximumTexture3dHeightAlternate = 48,
+    MaximumTexture3dDepthAlternate = 49,
+    PciDomainId = 50,
+    TexturePitchAlignment = 51,
+    MaximumTexturecubemapWidth = 52,
+    MaximumTexturecubemapLayeredWidth = 53,
+    MaximumTexturecubemapLayeredLayers = 54,
+    MaximumSurface1dWidth = 55,
+    MaximumSurface2dWidth = 56,
+    MaximumSurface2dHeight = 57,
+    MaximumSurface3dWidth = 58,
+    MaximumSurface3dHeight = 59,
+    MaximumSurface3dDepth = 60,
+    MaximumSurface1dLayeredWidth = 61,
+    MaximumSurface1dLayeredLayers = 62,
+    MaximumSurface2dLayeredWidth = 63,
+    MaximumSurface2dLayeredHeight = 64,
+    MaximumSurface2dLayeredLayers = 65,
+    MaximumSurfacecubemapWidth = 66,
+    MaximumSurfacecubemapLayeredWidth = 67,
+    MaximumSurfacecubemapLayeredLayers = 68,
+    MaximumTexture1dLinearWidth = 69,
+    MaximumTexture2dLinearWidth = 70,
+    MaximumTexture2dLinearHeight = 71,
+    MaximumTexture2dLinearPitch = 72,
+    MaximumTexture2dMipmappedWidth = 73,
+    MaximumTexture2dMipmappedHeight = 74,
+    ComputeCapabilityMajor = 75,
+    ComputeCapabilityMinor = 76,
+    MaximumTexture1dMipmappedWidth = 77,
+    StreamPrioritiesSupported = 78,
+    GlobalL1CacheSupported = 79,
+    LocalL1CacheSupported = 80,
+    MaxSharedMemoryPerMultiprocessor = 81,
+    MaxRegistersPerMultiprocessor = 82,
+    ManagedMemory = 83,
+    MultiGpuBoard = 84,
+    MultiGpuBoardGroupId = 85,
+    HostNativeAtomicSupported = 86,
+    SingleToDoublePrecisionPerfRatio = 87,
+    PageableMemoryAccess = 88,
+    ConcurrentManagedAccess = 89,
+    ComputePreemptionSupported = 90,
+    CanUseHostPointerForRegisteredMem = 91,
+    CanUseStreamMemOpsV1 = 92,
+    CanUse64BitStreamMemOpsV1 = 93,
+    CanUseStreamWaitValueNorV1 = 94,
+    CooperativeLaunch = 95,
+    CooperativeMultiDeviceLaunch = 96,
+    MaxSharedMemoryPerBlockOptin = 97,
+    CanFlushRemoteWrites = 98,
+    HostRegisterSupported = 99,
+    PageableMemoryAccessUsesHostPageTables = 100,
+    DirectManagedMemAccessFromHost = 101,
+    VirtualMemoryManagementSupported = 102,
+    HandleTypePosixFileDescriptorSupported = 103,
+    HandleTypeWin32HandleSupported = 104,
+    HandleTypeWin32KmtHandleSupported = 105,
+    MaxBlocksPerMultiprocessor = 106,
+    GenericCompressionSupported = 107,
+    MaxPersistingL2CacheSize = 108,
+    MaxAccessPolicyWindowSize = 109,
+    GpuDirectRdmaWithCudaVmmSupported = 110,
+    ReservedSharedMemoryPerBlock = 111,
+    SparseCudaArraySupported = 112,
+    ReadOnlyHostRegisterSupported = 113,
+    TimelineSemaphoreInteropSupported = 114,
+    MemoryPoolsSupported = 115,
+    GpuDirectRdmaSupported = 116,
+    GpuDirectRdmaFlushWritesOptions = 117,
+    GpuDirectRdmaWritesOrdering = 118,
+    MempoolSupportedHandleTypes = 119,
+    ClusterLaunch = 120,
+    DeferredMappingCudaArraySupported = 121,
+    CanUse64BitStreamMemOps = 122,
+    CanUseStreamWaitValueNor = 123,
+    DmaBufSupported = 124,
+    IpcEventSupported = 125,
+    MemSyncDomainCount = 126,
+    TensorMapAccessSupported = 127,
+    HandleTypeFabricSupported = 128,
+    UnifiedFunctionPointers = 129,
+    NumaConfig = 130,
+    NumaId = 131,
+    MulticastSupported = 132,
+    MpsEnabled = 133,
+    HostNumaId = 134,
+}
+
+/// The attributes of `CUdevice_P2PAttribute`.
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CuP2PAttribute {
+    /// Relative performance of the link, lower is better.
+    PerformanceRank = 1,
+    AccessSupported = 2,
+    NativeAtomicSupported = 3,
+    CudaArrayAccessSupported = 4,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attributes() {
+        crate::init().unwrap();
+        let device = CuDevice::new(0).unwrap();
+
+        assert_eq!(device.name().unwrap(), "Mock CUDA Device 0");
+        assert_eq!(device.pci_bus_id().unwrap(), "0000:01:00.0");
+        assert_eq!(device.compute_capability().unwrap(), (8, 6));
+        assert_eq!(device.attribute(CuDeviceAttribute::WarpSize).unwrap(), 32);
+
+        let props = device.properties().unwrap();
+        assert_eq!(props.uuid, device.uuid().unwrap());
+        assert_eq!(props.multiprocessor_count, 84);
+        assert!(props.memory_pools_supported);
+    }
+
+    #[test]
+    fn lookup() {
+        crate::init().unwrap();
+        ffi::mock::set_device_count(crate::TEST_DEVICE_COUNT);
+        let devices: Vec<_> = CuDevice::all().unwrap().collect();
+        assert_eq!(devices.len(), crate::TEST_DEVICE_COUNT as usize);
+        let device = devices[1];
+
+        assert_eq!(CuDevice::from_pci_bus_id("0000:02:00.0").unwrap(), device);
+        assert_eq!(CuDevice::from_pci_bus_id("02:00.0").unwrap(), device);
+        assert!(CuDevice::from_pci_bus_id("0000:09:00.0").is_err());
+        assert_eq!(CuDevice::from_uuid(&device.uuid().unwrap()).unwrap(), device);
+        assert_eq!(
+            CuDevice::from_uuid(&CuUuid([0; 16])).err().unwrap(),
+            CuError::InvalidDevice,
+        );
+    }
+
+    #[test]
+    fn primary_context_flags() {
+        use crate::context::CuSchedPolicy;
+
+        let _ctx = crate::test_context();
+        let device = CuDevice::new(0).unwrap();
+        let flags = CuContextFlags { sched: CuSchedPolicy::Spin, ..Default::default() };
+
+        device.set_primary_context_flags(flags).unwrap();
+        assert_eq!(device.primary_context_state().unwrap(), (flags, true));
+        device.set_primary_context_flags(CuContextFlags::default()).unwrap();
+    }
+
+    #[test]
+    fn peer_attributes() {
+        crate::init().unwrap();
+        ffi::mock::set_device_count(crate::TEST_DEVICE_COUNT);
+        let [d0, d1] = [0, 1].map(|i| CuDevice::new(i).unwrap());
+
+        assert!(d0.can_access_peer(&d1).unwrap());
+        assert!(!d0.can_access_peer(&d0).unwrap());
+        assert_eq!(d0.p2p_attribute(CuP2PAttribute::AccessSupported, &d1).unwrap(), 1);
+        assert_eq!(
+            d0.p2p_attribute(CuP2PAttribute::AccessSupported, &d0).err().unwrap(),
+            CuError::InvalidDevice,
+        );
+    }
+
+    #[test]
+    fn visible_devices() {
+        crate::init().unwrap();
+        ffi::mock::set_device_count(crate::TEST_DEVICE_COUNT);
+        let [d0, d1] = [0, 1].map(|i| CuDevice::new(i).unwrap());
+        let uuid1 = d1.uuid().unwrap().to_string();
+
+        assert_eq!(CuDevice::parse_visible_devices("1,0").unwrap(), [d1, d0]);
+        assert_eq!(CuDevice::parse_visible_devices(&uuid1).unwrap(), [d1]);
+        assert_eq!(CuDevice::parse_visible_devices(&uuid1[..15]).unwrap(), [d1]);
+        assert_eq!(CuDevice::parse_visible_devices("0000:01:00.0, 1").unwrap(), [d0, d1]);
+        // Ambiguous prefix, out of range ordinal and repeated device.
+        assert!(CuDevice::parse_visible_devices("GPU-5a5a,0").unwrap().is_empty());
+        assert_eq!(CuDevice::parse_visible_devices("0,7,1").unwrap(), [d0]);
+        assert_eq!(CuDevice::parse_visible_devices("1,1,0").unwrap(), [d1]);
+        assert!(CuDevice::parse_visible_devices("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn uuid_round_trip() {
+        let uuid = CuUuid([0x5a, 0x5a, 0x5a, 0x5a, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0, 1, 2, 3]);
+        let s = uuid.to_string();
+
+        assert_eq!(s, "GPU-5a5a5a5a-1234-5678-9abc-def000010203");
+        assert_eq!(s.parse::<CuUuid>().unwrap(), uuid);
+        assert_eq!("5a5a5a5a123456789abcdef000010203".parse::<CuUuid>().unwrap(), uuid);
+        assert!("GPU-5a5a".parse::<CuUuid>().is_err());
+    }
+
+    #[test]
+    fn uuid_malformed() {
+        for s in [
+            "GPU-+f5a5a5a-1234-5678-9abc-def000010203",
+            "GPU-5a5a5a5a-1234-5678-9abc-def00001020+",
+            "GPU-5a5a5a5a-1234-5678-9abc-def00001020g",
+            "GPU-5a5a5a5a-1234-5678-9abc-def00001020 ",
+            "GPU-5a5a5a5a-1234-5678-9abc-def00001020é",
+            "GPU-5a5a5a5a-1234-5678-9abc-def000010203ff",
+            "",
+        ] {
+            assert_eq!(s.parse::<CuUuid>(), Err(CuError::InvalidValue), "{:?}", s);
+        }
+    }
 }
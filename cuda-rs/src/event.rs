@@ -1,8 +1,10 @@
 use crate::{
     error::CuResult,
     ffi,
-    stream::CuStream,
+    future::CuCompletion,
+    stream::{CuStream, CuStreamBuilder},
 };
+use std::fmt;
 use std::sync::Arc;
 
 struct CUevent(ffi::CUevent);
@@ -13,34 +15,67 @@ impl Drop for CUevent {
     }
 }
 
+unsafe impl Send for CUevent {}
+unsafe impl Sync for CUevent {}
+
 enum Inner {
     Owned(Arc<CUevent>),
     Borrowed(ffi::CUevent),
 }
 
+/// A CUDA event.
+///
+/// # Thread safety
+///
+/// `CuEvent` is `Send` and `Sync`: events can be recorded, queried and
+/// waited on from any thread. Recording an event from two threads at once is
+/// allowed, but which record wins is unspecified.
 pub struct CuEvent(Inner);
 
+unsafe impl Send for CuEvent {}
+unsafe impl Sync for CuEvent {}
+
 impl CuEvent {
+    /// Creates an event with timing enabled, in the current context.
     pub fn new() -> CuResult<Self> {
-        let mut e = std::ptr::null_mut();
-        let res = unsafe {
-            ffi::cuEventCreate(&mut e, ffi::CUevent_flags_enum_CU_EVENT_DEFAULT)
-        };
-        let e = CuEvent(Inner::Owned(Arc::new(CUevent(e))));
+        Self::builder().build()
+    }
 
-        wrap!(e, res)
+    pub fn builder() -> CuEventBuilder {
+        CuEventBuilder::new()
     }
 
     pub unsafe fn from_raw(e: ffi::CUevent) -> Self {
         CuEvent(Inner::Borrowed(e))
     }
 
+    /// Opens an interprocess event of another process, in the current
+    /// context. The event is closed when the last clone is dropped; the
+    /// other process keeps its own.
+    pub fn from_ipc_handle(handle: &CuIpcEventHandle) -> CuResult<Self> {
+        let mut e = std::ptr::null_mut();
+        let res = unsafe { ffi::cuIpcOpenEventHandle(&mut e, handle.0) };
+        wrap!((), res, "cuIpcOpenEventHandle")?;
+
+        Ok(CuEvent(Inner::Owned(Arc::new(CUevent(e)))))
+    }
+
+    /// The handle another process opens the event with, through
+    /// [`CuEvent::from_ipc_handle`]. The event must have been created with
+    /// [`CuEventBuilder::interprocess`].
+    pub fn ipc_handle(&self) -> CuResult<CuIpcEventHandle> {
+        let mut handle = ffi::CUipcEventHandle { reserved: [0; 64] };
+        let res = unsafe { ffi::cuIpcGetEventHandle(&mut handle, self.get_raw()) };
+
+        wrap!(CuIpcEventHandle(handle), res, "cuIpcGetEventHandle", "event={:?}", unsafe { self.get_raw() })
+    }
+
     pub fn record(&self, stream: &CuStream) -> CuResult<()> {
         let res = unsafe {
             ffi::cuEventRecord(self.get_raw(), stream.get_raw())
         };
 
-        wrap!((), res)
+        wrap!((), res, "cuEventRecord", "stream={:?}", unsafe { stream.get_raw() })
     }
 
     pub fn query(&self) -> CuResult<bool> {
@@ -49,14 +84,26 @@ impl CuEvent {
         if res == ffi::cudaError_enum_CUDA_SUCCESS || res == ffi::cudaError_enum_CUDA_ERROR_NOT_READY {
             Ok(res == ffi::cudaError_enum_CUDA_SUCCESS)
         } else {
-            wrap!(false, res)
+            wrap!(false, res, "cuEventQuery")
         }
     }
 
     pub fn synchronize(&self) -> CuResult<()> {
         let res = unsafe { ffi::cuEventSynchronize(self.get_raw()) };
 
-        wrap!((), res)
+        wrap!((), res, "cuEventSynchronize")
+    }
+
+    /// A future that resolves once the work captured by the last
+    /// [`CuEvent::record`] has completed.
+    ///
+    /// This creates a helper stream in the current context that waits for
+    /// the event; the event itself may belong to any context.
+    pub fn wait_async(&self) -> CuResult<CuCompletion> {
+        let stream = CuStreamBuilder::new().non_blocking(true).build()?;
+        stream.wait_on_event(self)?;
+
+        Ok(stream.completion().with_stream(stream))
     }
 
     pub fn elapsed_time(&self, start: &CuEvent) -> CuResult<f32> {
@@ -70,7 +117,7 @@ impl CuEvent {
             )
         };
 
-        wrap!(ms, res)
+        wrap!(ms, res, "cuEventElapsedTime")
     }
 
     pub unsafe fn get_raw(&self) -> ffi::CUevent {
@@ -89,3 +136,229 @@ impl Clone for CuEvent {
         }
     }
 }
+
+/// The `CU_EVENT_*` flags of an event.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuEventFlags {
+    /// Make [`CuEvent::synchronize`] block the thread instead of spinning.
+    pub blocking_sync: bool,
+    /// Don't record timestamps, which makes recording and waiting on the
+    /// event cheaper. Such events can't be passed to
+    /// [`CuEvent::elapsed_time`].
+    pub disable_timing: bool,
+    /// Allow sharing the event with other processes through
+    /// [`CuEvent::ipc_handle`]. Requires `disable_timing`.
+    pub interprocess: bool,
+}
+
+impl CuEventFlags {
+    pub fn bits(&self) -> u32 {
+        let mut bits = ffi::CUevent_flags_enum_CU_EVENT_DEFAULT;
+        if self.blocking_sync {
+            bits |= ffi::CUevent_flags_enum_CU_EVENT_BLOCKING_SYNC;
+        }
+        if self.disable_timing {
+            bits |= ffi::CUevent_flags_enum_CU_EVENT_DISABLE_TIMING;
+        }
+        if self.interprocess {
+            bits |= ffi::CUevent_flags_enum_CU_EVENT_INTERPROCESS;
+        }
+
+        bits
+    }
+}
+
+pub struct CuEventBuilder {
+    flags: CuEventFlags,
+}
+
+impl CuEventBuilder {
+    pub fn new() -> Self {
+        CuEventBuilder { flags: CuEventFlags::default() }
+    }
+
+    pub fn flags(mut self, flags: CuEventFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn blocking_sync(mut self, blocking_sync: bool) -> Self {
+        self.flags.blocking_sync = blocking_sync;
+        self
+    }
+
+    pub fn disable_timing(mut self, disable_timing: bool) -> Self {
+        self.flags.disable_timing = disable_timing;
+        self
+    }
+
+    /// Makes the event shareable with other processes. Interprocess events
+    /// must also disable timing.
+    pub fn interprocess(mut self, interprocess: bool) -> Self {
+        self.flags.interprocess = interprocess;
+        self
+    }
+
+    /// Creates the event in the current context.
+    pub fn build(self) -> CuResult<CuEvent> {
+        let mut e = std::ptr::null_mut();
+        let flags = self.flags.bits();
+        let res = unsafe { ffi::cuEventCreate(&mut e, flags) };
+        wrap!((), res, "cuEventCreate", "flags={:#x}", flags)?;
+
+        Ok(CuEvent(Inner::Owned(Arc::new(CUevent(e)))))
+    }
+}
+
+impl Default for CuEventBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Identifies an interprocess event to other processes. The bytes can be
+/// sent over any channel and turned back into a handle with
+/// [`CuIpcEventHandle::from_bytes`].
+#[derive(Clone, Copy)]
+pub struct CuIpcEventHandle(ffi::CUipcEventHandle);
+
+impl CuIpcEventHandle {
+    pub fn from_bytes(bytes: [u8; 64]) -> Self {
+        CuIpcEventHandle(ffi::CUipcEventHandle { reserved: bytes.map(|b| b as _) })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.0.reserved.map(|b| b as u8)
+    }
+}
+
+impl fmt::Debug for CuIpcEventHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CuIpcEventHandle").field(&self.to_bytes()).finish()
+    }
+}
+
+impl PartialEq for CuIpcEventHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl Eq for CuIpcEventHandle {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DeviceMemory;
+
+    #[test]
+    fn thread_safety() {
+        crate::assert_send_sync::<CuEvent>();
+
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let event = CuEvent::new().unwrap();
+        event.record(&stream).unwrap();
+
+        std::thread::spawn(move || event.synchronize().unwrap()).join().unwrap();
+    }
+
+    #[test]
+    fn wait_async() {
+        use crate::future::tests::{poll_once, CountingWaker};
+
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let event = CuEvent::new().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        let _copy = mem.try_clone().unwrap();
+        event.record(&stream).unwrap();
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), true) }.unwrap();
+
+        let waker = Arc::new(CountingWaker::default());
+        let mut wait = event.wait_async().unwrap();
+        assert!(poll_once(&mut wait, &waker).is_pending());
+
+        // The mock only runs the helper stream's callback once the context
+        // is synchronized.
+        crate::context::CuContext::synchronize().unwrap();
+        assert_eq!(waker.count(), 1);
+        assert!(poll_once(&mut wait, &waker).is_ready());
+    }
+
+    #[test]
+    fn elapsed_time() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let start = CuEvent::new().unwrap();
+        let end = CuEvent::new().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+
+        start.record(&stream).unwrap();
+        let _copies = (0..3)
+            .map(|_| mem.try_clone().unwrap())
+            .collect::<Vec<_>>();
+        end.record(&stream).unwrap();
+        end.synchronize().unwrap();
+
+        assert!(start.query().unwrap());
+        let ms = end.elapsed_time(&start).unwrap();
+        assert!(ms > 0.0);
+    }
+
+    #[test]
+    fn flags() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let start = CuEvent::new().unwrap();
+        let end = CuEvent::builder().disable_timing(true).blocking_sync(true).build().unwrap();
+
+        start.record(&stream).unwrap();
+        end.record(&stream).unwrap();
+        end.synchronize().unwrap();
+        assert!(end.query().unwrap());
+        let err = end.elapsed_time(&start).unwrap_err();
+        assert_eq!(err, crate::error::CuError::InvalidHandle);
+
+        let flags = CuEventFlags { interprocess: true, ..Default::default() };
+        assert_eq!(flags.bits(), ffi::CUevent_flags_enum_CU_EVENT_INTERPROCESS);
+        let Err(err) = CuEvent::builder().flags(flags).build() else {
+            panic!("created a timed interprocess event");
+        };
+        assert_eq!(err, crate::error::CuError::InvalidValue);
+    }
+
+    #[test]
+    fn ipc() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let waiter = CuStream::builder().non_blocking(true).build().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+
+        let err = CuEvent::new().unwrap().ipc_handle().unwrap_err();
+        assert_eq!(err, crate::error::CuError::InvalidValue);
+
+        let event = CuEvent::builder().disable_timing(true).interprocess(true).build().unwrap();
+        let handle = event.ipc_handle().unwrap();
+        assert_eq!(CuIpcEventHandle::from_bytes(handle.to_bytes()), handle);
+        let opened = CuEvent::from_ipc_handle(&handle).unwrap();
+
+        // The opened event sees records of the original.
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), true) }.unwrap();
+        let _copy = mem.try_clone().unwrap();
+        event.record(&stream).unwrap();
+        assert!(!opened.query().unwrap());
+        waiter.wait_on_event(&opened).unwrap();
+        assert!(!waiter.query().unwrap());
+
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), false) }.unwrap();
+        opened.synchronize().unwrap();
+        assert!(event.query().unwrap());
+        waiter.synchronize().unwrap();
+
+        // Either handle keeps the event alive.
+        drop(event);
+        opened.record(&stream).unwrap();
+        opened.synchronize().unwrap();
+    }
+}
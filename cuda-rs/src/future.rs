@@ -0,0 +1,203 @@
+//! Futures that resolve when work on a stream has completed.
+//!
+//! They are woken by a stream callback, so no thread blocks or polls the
+//! driver, and they work with any executor.
+
+use crate::{
+    error::{CuCallError, CuError, CuResult},
+    ffi,
+    stream::CuStream,
+};
+use std::{
+    ffi::c_void,
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+#[derive(Default)]
+struct Shared {
+    /// Kept with its details, which are recorded on the thread that polls.
+    result: Option<Result<(), CuCallError>>,
+    waker: Option<Waker>,
+}
+
+/// Resolves once the work enqueued on a stream before it was created has
+/// completed, with the error of the stream's context if it failed.
+///
+/// Returned by [`CuStream::completion`] and
+/// [`CuEvent::wait_async`](crate::event::CuEvent::wait_async). Dropping it
+/// does not cancel anything.
+#[must_use = "futures do nothing unless awaited"]
+pub struct CuCompletion {
+    shared: Arc<Mutex<Shared>>,
+    // Keeps a helper stream alive until the callback has run.
+    _stream: Option<CuStream>,
+}
+
+impl CuCompletion {
+    pub(crate) fn new(stream: &CuStream) -> Self {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let data = Arc::into_raw(shared.clone());
+        let res = unsafe {
+            ffi::cuStreamAddCallback(stream.get_raw(), Some(callback), data as *mut c_void, 0)
+        };
+        if res != ffi::cudaError_enum_CUDA_SUCCESS {
+            // The callback will never run, so its reference is still ours.
+            drop(unsafe { Arc::from_raw(data) });
+        }
+
+        let launched = wrap!((), res, "cuStreamAddCallback", "stream={:?}", unsafe { stream.get_raw() });
+        if launched.is_err() {
+            lock(&shared).result = CuCallError::last().map(Err);
+        }
+
+        CuCompletion { shared, _stream: None }
+    }
+
+    /// Keeps `stream` alive until the future resolves or is dropped.
+    pub(crate) fn with_stream(mut self, stream: CuStream) -> Self {
+        self._stream = Some(stream);
+        self
+    }
+
+    /// Whether the work has completed, without registering a waker.
+    pub fn is_complete(&self) -> bool {
+        lock(&self.shared).result.is_some()
+    }
+}
+
+impl Future for CuCompletion {
+    type Output = CuResult<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = lock(&self.shared);
+        match shared.result {
+            Some(ref result) => Poll::Ready(result.clone().map_err(CuCallError::record)),
+            None => {
+                match shared.waker {
+                    Some(ref waker) if waker.will_wake(cx.waker()) => {}
+                    _ => shared.waker = Some(cx.waker().clone()),
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+fn lock(shared: &Mutex<Shared>) -> std::sync::MutexGuard<'_, Shared> {
+    shared.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+unsafe extern "C" fn callback(stream: ffi::CUstream, status: ffi::CUresult, data: *mut c_void) {
+    let shared = Arc::from_raw(data as *const Mutex<Shared>);
+    let result = if status == ffi::cudaError_enum_CUDA_SUCCESS {
+        Ok(())
+    } else {
+        Err(CuCallError::new(CuError::from(status), "cuStreamAddCallback")
+            .with_args(format!("stream={:?}", stream)))
+    };
+
+    let waker = {
+        let mut shared = lock(&shared);
+        shared.result = Some(result);
+        shared.waker.take()
+    };
+    if let Some(waker) = waker {
+        // Unwinding into the driver would abort.
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| waker.wake()));
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        task::Wake,
+    };
+
+    /// Counts how often it is woken.
+    #[derive(Default)]
+    pub(crate) struct CountingWaker(AtomicUsize);
+
+    impl CountingWaker {
+        pub(crate) fn count(&self) -> usize {
+            self.0.load(Ordering::SeqCst)
+        }
+    }
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Polls `future` once with `waker`.
+    pub(crate) fn poll_once<F: Future + Unpin>(
+        future: &mut F,
+        waker: &Arc<CountingWaker>,
+    ) -> Poll<F::Output> {
+        let waker = Waker::from(waker.clone());
+        Pin::new(future).poll(&mut Context::from_waker(&waker))
+    }
+
+    #[test]
+    fn completion() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let mem = crate::memory::DeviceMemory::new(16, &stream).unwrap();
+        let _copy = mem.try_clone().unwrap();
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), true) }.unwrap();
+
+        let waker = Arc::new(CountingWaker::default());
+        let mut completion = stream.completion();
+        assert!(poll_once(&mut completion, &waker).is_pending());
+        assert!(!completion.is_complete());
+
+        stream.synchronize().unwrap();
+        assert_eq!(waker.count(), 1);
+        assert!(matches!(poll_once(&mut completion, &waker), Poll::Ready(Ok(()))));
+    }
+
+    #[test]
+    fn launch_failure() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+
+        ffi::mock::fail_next("cuStreamAddCallback", ffi::cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        let waker = Arc::new(CountingWaker::default());
+        let mut completion = stream.completion();
+        assert!(completion.is_complete());
+        let Poll::Ready(Err(err)) = poll_once(&mut completion, &waker) else {
+            panic!("completion should have failed");
+        };
+        assert_eq!(err, crate::error::CuError::InvalidValue);
+        assert_eq!(CuCallError::last().unwrap().function(), Some("cuStreamAddCallback"));
+    }
+
+    #[test]
+    fn failed_context() {
+        crate::init().unwrap();
+        let device = crate::device::CuDevice::new(0).unwrap();
+        let ctx = crate::context::CuContext::new(&device).unwrap();
+        let stream = CuStream::new().unwrap();
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), true) }.unwrap();
+
+        let waker = Arc::new(CountingWaker::default());
+        let mut completion = stream.completion();
+        assert!(poll_once(&mut completion, &waker).is_pending());
+
+        unsafe {
+            ffi::mock::poison_context(ctx.get_raw(), ffi::cudaError_enum_CUDA_ERROR_ILLEGAL_ADDRESS)
+        }.unwrap();
+        let _ = stream.synchronize();
+        let Poll::Ready(Err(err)) = poll_once(&mut completion, &waker) else {
+            panic!("completion should have failed");
+        };
+        assert_eq!(err, crate::error::CuError::IllegalAddress);
+        crate::context::CuContext::pop().unwrap();
+    }
+}
@@ -0,0 +1,1474 @@
+//! CUDA graphs: work and the dependencies between it, recorded once and
+//! launched as a whole.
+//!
+//! Graphs are captured from streams with
+//! [`CuStream::begin_capture`](crate::stream::CuStream::begin_capture), or
+//! built node by node with [`CuGraph::new`] and the `add_*_node` methods.
+//! Either way, [`CuGraph::instantiate`] turns a graph into a
+//! [`CuGraphExec`], which is what gets launched. [`CuGraph::nodes`] and
+//! [`CuGraph::edges`] show what a graph is made of,
+//! [`CuGraph::node_params`] what a node does, and [`CuGraph::to_dot`]
+//! draws it.
+
+use crate::{
+    context::CuContext,
+    error::{raise, CuCallError, CuError, CuResult},
+    event::CuEvent,
+    ffi,
+    memory::DeviceMemory,
+    stream::CuStream,
+};
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use thiserror::Error;
+
+struct CUgraph(ffi::CUgraph);
+
+impl Drop for CUgraph {
+    fn drop(&mut self) {
+        unsafe { ffi::cuGraphDestroy(self.0) };
+    }
+}
+
+enum Inner {
+    Owned(CUgraph),
+    Borrowed(ffi::CUgraph),
+}
+
+/// A CUDA graph.
+///
+/// # Thread safety
+///
+/// `CuGraph` is `Send` but not `Sync`. The driver doesn't synchronize calls
+/// on the same graph, and even the ones that only read it, such as
+/// [`CuGraph::instantiate`] or [`CuGraph::nodes`], must not run alongside
+/// others.
+pub struct CuGraph {
+    inner: Inner,
+    /// The events of event record and wait nodes, which must outlive the
+    /// graph and the execs made from it. Captures don't add such nodes; see
+    /// [`CuStream::end_capture`].
+    events: Vec<CuEvent>,
+}
+
+unsafe impl Send for CuGraph {}
+
+impl CuGraph {
+    pub fn new() -> CuResult<Self> {
+        let mut graph = std::ptr::null_mut();
+        let res = unsafe { ffi::cuGraphCreate(&mut graph, 0) };
+        wrap!((), res, "cuGraphCreate")?;
+
+        Ok(unsafe { Self::from_owned(graph) })
+    }
+
+    /// Takes ownership of `graph`, destroying it on drop.
+    pub(crate) unsafe fn from_owned(graph: ffi::CUgraph) -> Self {
+        CuGraph { inner: Inner::Owned(CUgraph(graph)), events: Vec::new() }
+    }
+
+    pub unsafe fn from_raw(graph: ffi::CUgraph) -> Self {
+        CuGraph { inner: Inner::Borrowed(graph), events: Vec::new() }
+    }
+
+    /// Copies the graph. Graphs with mem alloc or mem free nodes can't be
+    /// copied.
+    pub fn try_clone(&self) -> CuResult<Self> {
+        let mut graph = std::ptr::null_mut();
+        let res = unsafe { ffi::cuGraphClone(&mut graph, self.get_raw()) };
+        wrap!((), res, "cuGraphClone", "graph={:?}", unsafe { self.get_raw() })?;
+
+        let mut clone = unsafe { Self::from_owned(graph) };
+        clone.events = self.events.clone();
+
+        Ok(clone)
+    }
+
+    pub fn node_count(&self) -> CuResult<usize> {
+        let mut count = 0;
+        let res = unsafe {
+            ffi::cuGraphGetNodes(self.get_raw(), std::ptr::null_mut(), &mut count)
+        };
+
+        wrap!(count, res, "cuGraphGetNodes", "graph={:?}", unsafe { self.get_raw() })
+    }
+
+    pub fn nodes(&self) -> CuResult<Vec<CuGraphNode>> {
+        let mut count = self.node_count()?;
+        let mut nodes = vec![std::ptr::null_mut(); count];
+        let res = unsafe { ffi::cuGraphGetNodes(self.get_raw(), nodes.as_mut_ptr(), &mut count) };
+        wrap!((), res, "cuGraphGetNodes", "graph={:?}", unsafe { self.get_raw() })?;
+        nodes.truncate(count);
+
+        Ok(nodes.into_iter().map(CuGraphNode).collect())
+    }
+
+    /// The dependencies of the graph, as `(from, to)` pairs where `to`
+    /// depends on `from`.
+    pub fn edges(&self) -> CuResult<Vec<(CuGraphNode, CuGraphNode)>> {
+        let graph = unsafe { self.get_raw() };
+        let mut count = 0;
+        let res = unsafe {
+            ffi::cuGraphGetEdges(graph, std::ptr::null_mut(), std::ptr::null_mut(), &mut count)
+        };
+        wrap!((), res, "cuGraphGetEdges", "graph={:?}", graph)?;
+
+        let mut from = vec![std::ptr::null_mut(); count];
+        let mut to = vec![std::ptr::null_mut(); count];
+        let res = unsafe { ffi::cuGraphGetEdges(graph, from.as_mut_ptr(), to.as_mut_ptr(), &mut count) };
+        wrap!((), res, "cuGraphGetEdges", "graph={:?}", graph)?;
+        from.truncate(count);
+
+        Ok(from.into_iter().zip(to).map(|(a, b)| (CuGraphNode(a), CuGraphNode(b))).collect())
+    }
+
+    /// The type of `node`, which must be a node of this graph.
+    pub fn node_type(&self, node: CuGraphNode) -> CuResult<CuGraphNodeType> {
+        self.check_node(node)?;
+        unsafe { node.node_type() }
+    }
+
+    /// The nodes `node` depends on. `node` must be a node of this graph.
+    pub fn node_dependencies(&self, node: CuGraphNode) -> CuResult<Vec<CuGraphNode>> {
+        self.check_node(node)?;
+        unsafe { node.dependencies() }
+    }
+
+    /// The type of `node` along with its parameters. `node` must be a node
+    /// of this graph.
+    pub fn node_params(&self, node: CuGraphNode) -> CuResult<CuGraphNodeParams> {
+        self.check_node(node)?;
+        unsafe { node.params() }
+    }
+
+    /// Node handles don't keep their graph alive, so only query the driver
+    /// about ones that are known to be in this graph.
+    fn check_node(&self, node: CuGraphNode) -> CuResult<()> {
+        if !self.nodes()?.contains(&node) {
+            return Err(raise(CuError::InvalidValue));
+        }
+
+        Ok(())
+    }
+
+    /// Renders the graph in the Graphviz DOT language, e.g. to look at with
+    /// `dot -Tsvg`. Each node is labeled with its type and parameters, and
+    /// child graphs are shown as a single node.
+    pub fn to_dot(&self) -> CuResult<String> {
+        let nodes = self.nodes()?;
+        let index: HashMap<_, _> = nodes.iter().enumerate().map(|(i, &node)| (node, i)).collect();
+
+        let mut dot = String::from("digraph {\n    node [shape=box];\n");
+        for (i, node) in nodes.iter().enumerate() {
+            let label = dot_label(&unsafe { node.params() }?)?.join("\\n");
+            dot.push_str(&format!("    n{} [label=\"{}\"];\n", i, label));
+        }
+        for (from, to) in self.edges()? {
+            dot.push_str(&format!("    n{} -> n{};\n", index[&from], index[&to]));
+        }
+        dot.push_str("}\n");
+
+        Ok(dot)
+    }
+
+    /// Adds a node that does nothing, to depend on several nodes at once.
+    pub fn add_empty_node(&mut self, deps: &[CuGraphNode]) -> CuResult<CuEmptyNode> {
+        let mut node = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuGraphAddEmptyNode(&mut node, self.get_raw(), raw_deps(deps), deps.len())
+        };
+
+        wrap!(CuEmptyNode(CuGraphNode(node)), res, "cuGraphAddEmptyNode", "graph={:?}", unsafe { self.get_raw() })
+    }
+
+    /// Adds a node launching the kernel `params.func`.
+    ///
+    /// # Safety
+    ///
+    /// `params.func` must be a kernel whose parameters are pointed to by
+    /// `params.kernel_params`, as for `cuLaunchKernel`. The parameters are
+    /// copied into the node.
+    pub unsafe fn add_kernel_node(
+        &mut self,
+        deps: &[CuGraphNode],
+        params: &CuKernelNodeParams,
+    ) -> CuResult<CuKernelNode> {
+        // Zero-initialized, so the fields of newer versions are unset.
+        let mut raw: ffi::CUDA_KERNEL_NODE_PARAMS = std::mem::zeroed();
+        raw.func = params.func;
+        (raw.gridDimX, raw.gridDimY, raw.gridDimZ) = params.grid_dim;
+        (raw.blockDimX, raw.blockDimY, raw.blockDimZ) = params.block_dim;
+        raw.sharedMemBytes = params.shared_mem_bytes;
+        raw.kernelParams = params.kernel_params.as_ptr() as *mut *mut c_void;
+
+        let mut node = std::ptr::null_mut();
+        add_kernel_node(&mut node, self.get_raw(), deps, &raw)?;
+
+        Ok(CuKernelNode(CuGraphNode(node)))
+    }
+
+    /// Adds a node copying `src` into `dst`, which must not be larger.
+    ///
+    /// # Safety
+    ///
+    /// The node only records the addresses, so `src` and `dst` must not be
+    /// freed as long as the graph or an exec made from it can be launched,
+    /// and until the launches have completed. Dropping `DeviceMemory` only
+    /// orders the free after the work of its own stream.
+    pub unsafe fn add_memcpy_node(
+        &mut self,
+        deps: &[CuGraphNode],
+        src: &DeviceMemory,
+        dst: &DeviceMemory,
+    ) -> CuResult<CuMemcpyNode> {
+        if dst.size > src.size {
+            return Err(raise(CuError::InvalidValue));
+        }
+
+        self.add_memcpy_node_raw(deps, dst.get_raw(), src.get_raw(), dst.size)
+    }
+
+    /// Adds a node copying `size` bytes of device memory from `src` to
+    /// `dst`, e.g. memory allocated by a [`CuMemAllocNode`], in the current
+    /// context.
+    ///
+    /// # Safety
+    ///
+    /// `src` and `dst` must be valid for `size` bytes as long as the graph
+    /// or an exec made from it can be launched, and until the launches have
+    /// completed.
+    pub unsafe fn add_memcpy_node_raw(
+        &mut self,
+        deps: &[CuGraphNode],
+        dst: ffi::CUdeviceptr,
+        src: ffi::CUdeviceptr,
+        size: usize,
+    ) -> CuResult<CuMemcpyNode> {
+        let ctx = CuContext::current()?;
+        // All-zero is a valid value of every field.
+        let mut params: ffi::CUDA_MEMCPY3D = unsafe { std::mem::zeroed() };
+        params.srcMemoryType = ffi::CUmemorytype_enum_CU_MEMORYTYPE_DEVICE;
+        params.srcDevice = src;
+        params.dstMemoryType = ffi::CUmemorytype_enum_CU_MEMORYTYPE_DEVICE;
+        params.dstDevice = dst;
+        params.WidthInBytes = size;
+        params.Height = 1;
+        params.Depth = 1;
+
+        let mut node = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuGraphAddMemcpyNode(
+                &mut node,
+                self.get_raw(),
+                raw_deps(deps),
+                deps.len(),
+                &params,
+                ctx.get_raw(),
+            )
+        };
+
+        wrap!(
+            CuMemcpyNode(CuGraphNode(node)), res,
+            "cuGraphAddMemcpyNode", "dst={:#x}, src={:#x}, bytes={}", dst, src, size
+        )
+    }
+
+    /// Adds a node filling `dst` with `value`. Bytes past the last whole
+    /// element are left alone.
+    ///
+    /// # Safety
+    ///
+    /// As for [`CuGraph::add_memcpy_node`], `dst` must not be freed while
+    /// the graph or an exec made from it can still write it.
+    pub unsafe fn add_memset_node(
+        &mut self,
+        deps: &[CuGraphNode],
+        dst: &DeviceMemory,
+        value: CuMemsetValue,
+    ) -> CuResult<CuMemsetNode> {
+        let count = dst.size / value.element_size();
+        self.add_memset_node_raw(deps, dst.get_raw(), value, count)
+    }
+
+    /// Adds a node writing `count` elements of `value` to the device memory
+    /// at `dst`, in the current context.
+    ///
+    /// # Safety
+    ///
+    /// `dst` must be valid for `count` elements as long as the graph or an
+    /// exec made from it can be launched, and until the launches have
+    /// completed.
+    pub unsafe fn add_memset_node_raw(
+        &mut self,
+        deps: &[CuGraphNode],
+        dst: ffi::CUdeviceptr,
+        value: CuMemsetValue,
+        count: usize,
+    ) -> CuResult<CuMemsetNode> {
+        let ctx = CuContext::current()?;
+        let params = ffi::CUDA_MEMSET_NODE_PARAMS {
+            dst,
+            pitch: 0,
+            value: value.bits(),
+            elementSize: value.element_size() as u32,
+            width: count,
+            height: 1,
+        };
+
+        let mut node = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuGraphAddMemsetNode(
+                &mut node,
+                self.get_raw(),
+                raw_deps(deps),
+                deps.len(),
+                &params,
+                ctx.get_raw(),
+            )
+        };
+
+        wrap!(
+            CuMemsetNode(CuGraphNode(node)), res,
+            "cuGraphAddMemsetNode", "dst={:#x}, value={:?}, count={}", dst, value, count
+        )
+    }
+
+    /// Adds a node that calls `f` on a driver thread, once per launch.
+    /// Launches on different streams may call it concurrently, and like any
+    /// host function it must not call into CUDA.
+    ///
+    /// The graph owns `f`. It is dropped, also on a driver thread, once the
+    /// graph and every exec made from it are gone and their launches have
+    /// completed.
+    pub fn add_host_node<F>(&mut self, deps: &[CuGraphNode], f: F) -> CuResult<CuHostNode>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let data = self.retain(f)?;
+        let params = ffi::CUDA_HOST_NODE_PARAMS {
+            fn_: Some(graph_host_fn::<F>),
+            userData: data as *mut c_void,
+        };
+
+        let mut node = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuGraphAddHostNode(&mut node, self.get_raw(), raw_deps(deps), deps.len(), &params)
+        };
+
+        wrap!(CuHostNode(CuGraphNode(node)), res, "cuGraphAddHostNode", "graph={:?}", unsafe { self.get_raw() })
+    }
+
+    /// Adds a node recording `event`, like [`CuEvent::record`] on the launch
+    /// stream. The graph keeps the event alive.
+    pub fn add_event_record_node(
+        &mut self,
+        deps: &[CuGraphNode],
+        event: &CuEvent,
+    ) -> CuResult<CuEventRecordNode> {
+        let mut node = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuGraphAddEventRecordNode(
+                &mut node,
+                self.get_raw(),
+                raw_deps(deps),
+                deps.len(),
+                event.get_raw(),
+            )
+        };
+        wrap!((), res, "cuGraphAddEventRecordNode", "event={:?}", unsafe { event.get_raw() })?;
+        self.events.push(event.clone());
+
+        Ok(CuEventRecordNode(CuGraphNode(node)))
+    }
+
+    /// Adds a node making the rest of the graph wait for the work captured
+    /// by the last record of `event` at launch time. The graph keeps the
+    /// event alive.
+    pub fn add_event_wait_node(
+        &mut self,
+        deps: &[CuGraphNode],
+        event: &CuEvent,
+    ) -> CuResult<CuEventWaitNode> {
+        let mut node = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuGraphAddEventWaitNode(
+                &mut node,
+                self.get_raw(),
+                raw_deps(deps),
+                deps.len(),
+                event.get_raw(),
+            )
+        };
+        wrap!((), res, "cuGraphAddEventWaitNode", "event={:?}", unsafe { event.get_raw() })?;
+        self.events.push(event.clone());
+
+        Ok(CuEventWaitNode(CuGraphNode(node)))
+    }
+
+    /// Adds a node running a copy of `child`. Later changes to `child` don't
+    /// affect the node. Graphs with mem alloc or mem free nodes can't be
+    /// children.
+    pub fn add_child_graph_node(
+        &mut self,
+        deps: &[CuGraphNode],
+        child: &CuGraph,
+    ) -> CuResult<CuChildGraphNode> {
+        let mut node = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuGraphAddChildGraphNode(
+                &mut node,
+                self.get_raw(),
+                raw_deps(deps),
+                deps.len(),
+                child.get_raw(),
+            )
+        };
+        wrap!((), res, "cuGraphAddChildGraphNode", "child={:?}", unsafe { child.get_raw() })?;
+        self.events.extend(child.events.iter().cloned());
+
+        Ok(CuChildGraphNode(CuGraphNode(node)))
+    }
+
+    /// Adds a node allocating `size` bytes on the device of the current
+    /// context. The memory can be used by the nodes that depend on this one
+    /// until it is freed by a [`CuGraph::add_mem_free_node`] node, or after
+    /// the launch with `cuMemFreeAsync`.
+    pub fn add_mem_alloc_node(&mut self, deps: &[CuGraphNode], size: usize) -> CuResult<CuMemAllocNode> {
+        let device = CuContext::current()?.device()?;
+        // All-zero is a valid value of every field.
+        let mut params: ffi::CUDA_MEM_ALLOC_NODE_PARAMS = unsafe { std::mem::zeroed() };
+        params.poolProps.allocType = ffi::CUmemAllocationType_enum_CU_MEM_ALLOCATION_TYPE_PINNED;
+        params.poolProps.location.type_ = ffi::CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_DEVICE;
+        params.poolProps.location.id = device.get_raw();
+        params.bytesize = size;
+
+        let mut node = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuGraphAddMemAllocNode(&mut node, self.get_raw(), raw_deps(deps), deps.len(), &mut params)
+        };
+
+        wrap!(
+            CuMemAllocNode { node: CuGraphNode(node), ptr: params.dptr, size }, res,
+            "cuGraphAddMemAllocNode", "bytesize={}, device={}", size, device.get_raw()
+        )
+    }
+
+    /// Adds a node freeing the memory of `alloc`, which may belong to
+    /// another graph.
+    pub fn add_mem_free_node(
+        &mut self,
+        deps: &[CuGraphNode],
+        alloc: &CuMemAllocNode,
+    ) -> CuResult<CuMemFreeNode> {
+        let mut node = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuGraphAddMemFreeNode(&mut node, self.get_raw(), raw_deps(deps), deps.len(), alloc.ptr)
+        };
+
+        wrap!(CuMemFreeNode(CuGraphNode(node)), res, "cuGraphAddMemFreeNode", "dptr={:#x}", alloc.ptr)
+    }
+
+    /// Instantiates the graph. The exec is independent of the graph, which
+    /// can be changed or dropped afterwards.
+    pub fn instantiate(&self) -> CuResult<CuGraphExec> {
+        let mut exec = std::ptr::null_mut();
+        let res = unsafe { ffi::cuGraphInstantiateWithFlags(&mut exec, self.get_raw(), 0) };
+        wrap!((), res, "cuGraphInstantiateWithFlags", "graph={:?}", unsafe { self.get_raw() })?;
+
+        Ok(CuGraphExec { exec, events: self.events.clone() })
+    }
+
+    pub unsafe fn get_raw(&self) -> ffi::CUgraph {
+        match self.inner {
+            Inner::Owned(ref g) => g.0,
+            Inner::Borrowed(g) => g,
+        }
+    }
+
+    /// Hands `value` to the graph through a user object, so that the driver
+    /// drops it once the graph, its execs and their launches are done with
+    /// it. Returns where the value lives.
+    fn retain<T: Send + 'static>(&mut self, value: T) -> CuResult<*mut T> {
+        let data = Box::into_raw(Box::new(value));
+        let mut object = std::ptr::null_mut();
+        let res = unsafe {
+            ffi::cuUserObjectCreate(
+                &mut object,
+                data as *mut c_void,
+                Some(drop_boxed::<T>),
+                1,
+                ffi::CUuserObject_flags_enum_CU_USER_OBJECT_NO_DESTRUCTOR_SYNC,
+            )
+        };
+        if res != ffi::cudaError_enum_CUDA_SUCCESS {
+            // The driver never took it, so the value is still ours.
+            drop(unsafe { Box::from_raw(data) });
+        }
+        wrap!((), res, "cuUserObjectCreate")?;
+
+        let res = unsafe {
+            ffi::cuGraphRetainUserObject(
+                self.get_raw(),
+                object,
+                1,
+                ffi::CUuserObjectRetain_flags_enum_CU_GRAPH_USER_OBJECT_MOVE,
+            )
+        };
+        if res != ffi::cudaError_enum_CUDA_SUCCESS {
+            // Drops the value.
+            unsafe { ffi::cuUserObjectRelease(object, 1) };
+        }
+
+        wrap!(data, res, "cuGraphRetainUserObject", "graph={:?}", unsafe { self.get_raw() })
+    }
+}
+
+impl fmt::Debug for CuGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CuGraph").field(&unsafe { self.get_raw() }).finish()
+    }
+}
+
+/// The lines of the DOT label of a node.
+fn dot_label(params: &CuGraphNodeParams) -> CuResult<Vec<String>> {
+    let lines = match params {
+        CuGraphNodeParams::Kernel { func, grid_dim, block_dim, shared_mem_bytes } => vec![
+            "kernel".into(),
+            format!("func={:?}", func),
+            format!("grid={:?}, block={:?}, shared_mem={}", grid_dim, block_dim, shared_mem_bytes),
+        ],
+        CuGraphNodeParams::Memcpy(p) => vec![
+            "memcpy".into(),
+            format!("{:?} {:#x} -> {:?} {:#x}", p.src_type, p.src, p.dst_type, p.dst),
+            format!("{} x {} x {} bytes", p.width_in_bytes, p.height, p.depth),
+        ],
+        CuGraphNodeParams::Memset(p) => vec![
+            "memset".into(),
+            format!("{:#x}", p.dst),
+            format!("{} x {} of {:?}", p.width, p.height, p.value),
+        ],
+        CuGraphNodeParams::Host => vec!["host".into()],
+        CuGraphNodeParams::Graph(graph) => vec!["graph".into(), format!("nodes={}", graph.node_count()?)],
+        CuGraphNodeParams::Empty => vec!["empty".into()],
+        CuGraphNodeParams::EventWait(event) => vec!["event wait".into(), format!("{:?}", event)],
+        CuGraphNodeParams::EventRecord(event) => vec!["event record".into(), format!("{:?}", event)],
+        CuGraphNodeParams::MemAlloc { ptr, size } => vec!["mem alloc".into(), format!("{} bytes at {:#x}", size, ptr)],
+        CuGraphNodeParams::MemFree { ptr } => vec!["mem free".into(), format!("{:#x}", ptr)],
+        CuGraphNodeParams::Other(node_type) => vec![format!("{:?}", node_type)],
+    };
+
+    Ok(lines)
+}
+
+fn raw_deps(deps: &[CuGraphNode]) -> *const ffi::CUgraphNode {
+    deps.as_ptr() as *const ffi::CUgraphNode
+}
+
+#[cfg(cuda = "11080")]
+unsafe fn add_kernel_node(
+    node: &mut ffi::CUgraphNode,
+    graph: ffi::CUgraph,
+    deps: &[CuGraphNode],
+    params: &ffi::CUDA_KERNEL_NODE_PARAMS,
+) -> CuResult<()> {
+    let res = ffi::cuGraphAddKernelNode(node, graph, raw_deps(deps), deps.len(), params);
+
+    wrap!((), res, "cuGraphAddKernelNode", "func={:?}", params.func)
+}
+
+#[cfg(not(cuda = "11080"))]
+unsafe fn add_kernel_node(
+    node: &mut ffi::CUgraphNode,
+    graph: ffi::CUgraph,
+    deps: &[CuGraphNode],
+    params: &ffi::CUDA_KERNEL_NODE_PARAMS,
+) -> CuResult<()> {
+    let res = ffi::cuGraphAddKernelNode_v2(node, graph, raw_deps(deps), deps.len(), params);
+
+    wrap!((), res, "cuGraphAddKernelNode_v2", "func={:?}", params.func)
+}
+
+#[cfg(cuda = "11080")]
+unsafe fn kernel_node_params(node: ffi::CUgraphNode) -> CuResult<ffi::CUDA_KERNEL_NODE_PARAMS> {
+    let mut params = std::mem::zeroed();
+    let res = ffi::cuGraphKernelNodeGetParams(node, &mut params);
+
+    wrap!(params, res, "cuGraphKernelNodeGetParams", "node={:?}", node)
+}
+
+#[cfg(not(cuda = "11080"))]
+unsafe fn kernel_node_params(node: ffi::CUgraphNode) -> CuResult<ffi::CUDA_KERNEL_NODE_PARAMS> {
+    let mut params = std::mem::zeroed();
+    let res = ffi::cuGraphKernelNodeGetParams_v2(node, &mut params);
+
+    wrap!(params, res, "cuGraphKernelNodeGetParams_v2", "node={:?}", node)
+}
+
+/// Calls the closure of a host node. Unwinding into the driver would abort,
+/// so panics stop here.
+unsafe extern "C" fn graph_host_fn<F: Fn()>(data: *mut c_void) {
+    let f = &*(data as *const F);
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(f)) {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(payload)));
+    }
+}
+
+/// The destructor of the user objects made by [`CuGraph::retain`].
+unsafe extern "C" fn drop_boxed<T>(data: *mut c_void) {
+    let value = Box::from_raw(data as *mut T);
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| drop(value))) {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(payload)));
+    }
+}
+
+/// A node of a [`CuGraph`], used to express dependencies. The `add_*_node`
+/// methods return typed handles that convert into it.
+///
+/// Nodes belong to their graph: the handle means nothing once the graph is
+/// dropped, and nodes of one graph can't be dependencies in another. It
+/// only identifies the node; the graph answers questions about it, e.g.
+/// [`CuGraph::node_params`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuGraphNode(ffi::CUgraphNode);
+
+unsafe impl Send for CuGraphNode {}
+unsafe impl Sync for CuGraphNode {}
+
+impl CuGraphNode {
+    pub unsafe fn from_raw(node: ffi::CUgraphNode) -> Self {
+        CuGraphNode(node)
+    }
+
+    fn from_raw_opt(node: ffi::CUgraphNode) -> Option<Self> {
+        (!node.is_null()).then_some(CuGraphNode(node))
+    }
+
+    pub fn get_raw(&self) -> ffi::CUgraphNode {
+        self.0
+    }
+
+    // The queries below need the graph of the node to be alive, so they
+    // go through the graph.
+    unsafe fn node_type(&self) -> CuResult<CuGraphNodeType> {
+        let mut node_type = 0;
+        let res = unsafe { ffi::cuGraphNodeGetType(self.0, &mut node_type) };
+        wrap!((), res, "cuGraphNodeGetType", "node={:?}", self.0)?;
+
+        CuGraphNodeType::from_raw(node_type)
+    }
+
+    unsafe fn dependencies(&self) -> CuResult<Vec<CuGraphNode>> {
+        let mut count = 0;
+        let res = unsafe { ffi::cuGraphNodeGetDependencies(self.0, std::ptr::null_mut(), &mut count) };
+        wrap!((), res, "cuGraphNodeGetDependencies", "node={:?}", self.0)?;
+
+        let mut deps = vec![std::ptr::null_mut(); count];
+        let res = unsafe { ffi::cuGraphNodeGetDependencies(self.0, deps.as_mut_ptr(), &mut count) };
+        wrap!((), res, "cuGraphNodeGetDependencies", "node={:?}", self.0)?;
+        deps.truncate(count);
+
+        Ok(deps.into_iter().map(CuGraphNode).collect())
+    }
+
+    unsafe fn params(&self) -> CuResult<CuGraphNodeParams> {
+        let node = self.0;
+        let params = match self.node_type()? {
+            CuGraphNodeType::Kernel => {
+                let p = unsafe { kernel_node_params(node)? };
+                CuGraphNodeParams::Kernel {
+                    func: p.func,
+                    grid_dim: (p.gridDimX, p.gridDimY, p.gridDimZ),
+                    block_dim: (p.blockDimX, p.blockDimY, p.blockDimZ),
+                    shared_mem_bytes: p.sharedMemBytes,
+                }
+            }
+            CuGraphNodeType::Memcpy => {
+                let mut p = unsafe { std::mem::zeroed() };
+                let res = unsafe { ffi::cuGraphMemcpyNodeGetParams(node, &mut p) };
+                wrap!((), res, "cuGraphMemcpyNodeGetParams", "node={:?}", node)?;
+                CuGraphNodeParams::Memcpy(CuMemcpyNodeParams::from_raw(&p)?)
+            }
+            CuGraphNodeType::Memset => {
+                let mut p = unsafe { std::mem::zeroed() };
+                let res = unsafe { ffi::cuGraphMemsetNodeGetParams(node, &mut p) };
+                wrap!((), res, "cuGraphMemsetNodeGetParams", "node={:?}", node)?;
+                CuGraphNodeParams::Memset(CuMemsetNodeParams::from_raw(&p)?)
+            }
+            CuGraphNodeType::Host => CuGraphNodeParams::Host,
+            CuGraphNodeType::Graph => {
+                let mut graph = std::ptr::null_mut();
+                let res = unsafe { ffi::cuGraphChildGraphNodeGetGraph(node, &mut graph) };
+                wrap!((), res, "cuGraphChildGraphNodeGetGraph", "node={:?}", node)?;
+                // The child belongs to the node, so hand out a copy.
+                CuGraphNodeParams::Graph(unsafe { CuGraph::from_raw(graph) }.try_clone()?)
+            }
+            CuGraphNodeType::Empty => CuGraphNodeParams::Empty,
+            CuGraphNodeType::WaitEvent => {
+                let mut event = std::ptr::null_mut();
+                let res = unsafe { ffi::cuGraphEventWaitNodeGetEvent(node, &mut event) };
+                wrap!((), res, "cuGraphEventWaitNodeGetEvent", "node={:?}", node)?;
+                CuGraphNodeParams::EventWait(event)
+            }
+            CuGraphNodeType::EventRecord => {
+                let mut event = std::ptr::null_mut();
+                let res = unsafe { ffi::cuGraphEventRecordNodeGetEvent(node, &mut event) };
+                wrap!((), res, "cuGraphEventRecordNodeGetEvent", "node={:?}", node)?;
+                CuGraphNodeParams::EventRecord(event)
+            }
+            CuGraphNodeType::MemAlloc => {
+                let mut p: ffi::CUDA_MEM_ALLOC_NODE_PARAMS = unsafe { std::mem::zeroed() };
+                let res = unsafe { ffi::cuGraphMemAllocNodeGetParams(node, &mut p) };
+                wrap!((), res, "cuGraphMemAllocNodeGetParams", "node={:?}", node)?;
+                CuGraphNodeParams::MemAlloc { ptr: p.dptr, size: p.bytesize }
+            }
+            CuGraphNodeType::MemFree => {
+                let mut ptr = 0;
+                let res = unsafe { ffi::cuGraphMemFreeNodeGetParams(node, &mut ptr) };
+                wrap!((), res, "cuGraphMemFreeNodeGetParams", "node={:?}", node)?;
+                CuGraphNodeParams::MemFree { ptr }
+            }
+            node_type => CuGraphNodeParams::Other(node_type),
+        };
+
+        Ok(params)
+    }
+}
+
+macro_rules! typed_nodes {
+    ($($(#[$attr:meta])* $name:ident,)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub struct $name(CuGraphNode);
+
+            impl $name {
+                pub fn node(&self) -> CuGraphNode {
+                    self.0
+                }
+            }
+
+            impl From<$name> for CuGraphNode {
+                fn from(node: $name) -> Self {
+                    node.0
+                }
+            }
+        )*
+    };
+}
+
+typed_nodes! {
+    /// Returned by [`CuGraph::add_empty_node`].
+    CuEmptyNode,
+    /// Returned by [`CuGraph::add_kernel_node`].
+    CuKernelNode,
+    /// Returned by [`CuGraph::add_memcpy_node`].
+    CuMemcpyNode,
+    /// Returned by [`CuGraph::add_memset_node`].
+    CuMemsetNode,
+    /// Returned by [`CuGraph::add_host_node`].
+    CuHostNode,
+    /// Returned by [`CuGraph::add_event_record_node`].
+    CuEventRecordNode,
+    /// Returned by [`CuGraph::add_event_wait_node`].
+    CuEventWaitNode,
+    /// Returned by [`CuGraph::add_child_graph_node`].
+    CuChildGraphNode,
+    /// Returned by [`CuGraph::add_mem_free_node`].
+    CuMemFreeNode,
+}
+
+/// Returned by [`CuGraph::add_mem_alloc_node`], along with the address of
+/// the memory it allocates. The address is fixed when the node is added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuMemAllocNode {
+    node: CuGraphNode,
+    ptr: ffi::CUdeviceptr,
+    size: usize,
+}
+
+impl CuMemAllocNode {
+    pub fn node(&self) -> CuGraphNode {
+        self.node
+    }
+
+    pub fn ptr(&self) -> ffi::CUdeviceptr {
+        self.ptr
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl From<CuMemAllocNode> for CuGraphNode {
+    fn from(node: CuMemAllocNode) -> Self {
+        node.node
+    }
+}
+
+/// The launch of a kernel node.
+#[derive(Debug, Clone, Copy)]
+pub struct CuKernelNodeParams<'a> {
+    pub func: ffi::CUfunction,
+    pub grid_dim: (u32, u32, u32),
+    pub block_dim: (u32, u32, u32),
+    pub shared_mem_bytes: u32,
+    /// Pointers to each kernel parameter.
+    pub kernel_params: &'a [*mut c_void],
+}
+
+/// The value written by a memset node. Its width is the element size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuMemsetValue {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+}
+
+impl CuMemsetValue {
+    pub fn element_size(&self) -> usize {
+        match self {
+            CuMemsetValue::U8(_) => 1,
+            CuMemsetValue::U16(_) => 2,
+            CuMemsetValue::U32(_) => 4,
+        }
+    }
+
+    fn bits(&self) -> u32 {
+        match *self {
+            CuMemsetValue::U8(v) => v as u32,
+            CuMemsetValue::U16(v) => v as u32,
+            CuMemsetValue::U32(v) => v,
+        }
+    }
+
+    fn from_bits(bits: u32, element_size: u32) -> CuResult<Self> {
+        match element_size {
+            1 => Ok(CuMemsetValue::U8(bits as u8)),
+            2 => Ok(CuMemsetValue::U16(bits as u16)),
+            4 => Ok(CuMemsetValue::U32(bits)),
+            _ => Err(raise(CuError::InvalidValue)),
+        }
+    }
+}
+
+/// The type of a graph node (`CUgraphNodeType`).
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuGraphNodeType {
+    Kernel = 0,
+    Memcpy = 1,
+    Memset = 2,
+    Host = 3,
+    /// A child graph node.
+    Graph = 4,
+    Empty = 5,
+    WaitEvent = 6,
+    EventRecord = 7,
+    ExtSemasSignal = 8,
+    ExtSemasWait = 9,
+    MemAlloc = 10,
+    MemFree = 11,
+    /// Stream memory operations, e.g. from
+    /// [`CuStream::write_value_32`] during a capture.
+    BatchMemOp = 12,
+}
+
+impl CuGraphNodeType {
+    fn from_raw(node_type: ffi::CUgraphNodeType) -> CuResult<Self> {
+        match node_type {
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_KERNEL => Ok(CuGraphNodeType::Kernel),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEMCPY => Ok(CuGraphNodeType::Memcpy),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEMSET => Ok(CuGraphNodeType::Memset),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_HOST => Ok(CuGraphNodeType::Host),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_GRAPH => Ok(CuGraphNodeType::Graph),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EMPTY => Ok(CuGraphNodeType::Empty),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_WAIT_EVENT => Ok(CuGraphNodeType::WaitEvent),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EVENT_RECORD => Ok(CuGraphNodeType::EventRecord),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EXT_SEMAS_SIGNAL => Ok(CuGraphNodeType::ExtSemasSignal),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_EXT_SEMAS_WAIT => Ok(CuGraphNodeType::ExtSemasWait),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEM_ALLOC => Ok(CuGraphNodeType::MemAlloc),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_MEM_FREE => Ok(CuGraphNodeType::MemFree),
+            ffi::CUgraphNodeType_enum_CU_GRAPH_NODE_TYPE_BATCH_MEM_OP => Ok(CuGraphNodeType::BatchMemOp),
+            _ => Err(raise(CuError::InvalidValue)),
+        }
+    }
+}
+
+/// The type of a graph node along with its parameters, as returned by
+/// [`CuGraph::node_params`].
+#[derive(Debug)]
+pub enum CuGraphNodeParams {
+    Kernel {
+        func: ffi::CUfunction,
+        grid_dim: (u32, u32, u32),
+        block_dim: (u32, u32, u32),
+        shared_mem_bytes: u32,
+    },
+    Memcpy(CuMemcpyNodeParams),
+    Memset(CuMemsetNodeParams),
+    Host,
+    /// A copy of the child graph.
+    Graph(CuGraph),
+    Empty,
+    EventWait(ffi::CUevent),
+    EventRecord(ffi::CUevent),
+    MemAlloc {
+        ptr: ffi::CUdeviceptr,
+        size: usize,
+    },
+    MemFree {
+        ptr: ffi::CUdeviceptr,
+    },
+    /// A node whose parameters aren't exposed.
+    Other(CuGraphNodeType),
+}
+
+/// Where memory copied by a memcpy node lives (`CUmemorytype`).
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuMemoryType {
+    Host = 1,
+    Device = 2,
+    Array = 3,
+    /// Memory accessible from the host and every device, addressed as
+    /// device memory.
+    Unified = 4,
+}
+
+impl CuMemoryType {
+    fn from_raw(memory_type: ffi::CUmemorytype) -> CuResult<Self> {
+        match memory_type {
+            ffi::CUmemorytype_enum_CU_MEMORYTYPE_HOST => Ok(CuMemoryType::Host),
+            ffi::CUmemorytype_enum_CU_MEMORYTYPE_DEVICE => Ok(CuMemoryType::Device),
+            ffi::CUmemorytype_enum_CU_MEMORYTYPE_ARRAY => Ok(CuMemoryType::Array),
+            ffi::CUmemorytype_enum_CU_MEMORYTYPE_UNIFIED => Ok(CuMemoryType::Unified),
+            _ => Err(raise(CuError::InvalidValue)),
+        }
+    }
+}
+
+/// The copy made by a memcpy node. `src` and `dst` are the addresses of the
+/// first byte copied, on the host or the device depending on the memory
+/// type, or 0 for arrays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuMemcpyNodeParams {
+    pub src_type: CuMemoryType,
+    pub src: u64,
+    pub src_pitch: usize,
+    pub dst_type: CuMemoryType,
+    pub dst: u64,
+    pub dst_pitch: usize,
+    pub width_in_bytes: usize,
+    pub height: usize,
+    pub depth: usize,
+}
+
+impl CuMemcpyNodeParams {
+    fn from_raw(p: &ffi::CUDA_MEMCPY3D) -> CuResult<Self> {
+        let src_type = CuMemoryType::from_raw(p.srcMemoryType)?;
+        let dst_type = CuMemoryType::from_raw(p.dstMemoryType)?;
+        let address = |memory_type, host: u64, device: u64, x: usize, y: usize, z: usize, pitch: usize, height| {
+            let base = match memory_type {
+                CuMemoryType::Host => host,
+                CuMemoryType::Device | CuMemoryType::Unified => device,
+                CuMemoryType::Array => return 0,
+            };
+            base + (x + y * pitch + z * pitch * height) as u64
+        };
+
+        Ok(CuMemcpyNodeParams {
+            src_type,
+            src: address(
+                src_type, p.srcHost as u64, p.srcDevice, p.srcXInBytes, p.srcY, p.srcZ, p.srcPitch, p.srcHeight,
+            ),
+            src_pitch: p.srcPitch,
+            dst_type,
+            dst: address(
+                dst_type, p.dstHost as u64, p.dstDevice, p.dstXInBytes, p.dstY, p.dstZ, p.dstPitch, p.dstHeight,
+            ),
+            dst_pitch: p.dstPitch,
+            width_in_bytes: p.WidthInBytes,
+            height: p.Height,
+            depth: p.Depth,
+        })
+    }
+}
+
+/// The fill made by a memset node: `height` rows of `width` elements,
+/// `pitch` bytes apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuMemsetNodeParams {
+    pub dst: ffi::CUdeviceptr,
+    pub value: CuMemsetValue,
+    pub width: usize,
+    pub height: usize,
+    pub pitch: usize,
+}
+
+impl CuMemsetNodeParams {
+    fn from_raw(p: &ffi::CUDA_MEMSET_NODE_PARAMS) -> CuResult<Self> {
+        Ok(CuMemsetNodeParams {
+            dst: p.dst,
+            value: CuMemsetValue::from_bits(p.value, p.elementSize)?,
+            width: p.width,
+            height: p.height,
+            pitch: p.pitch,
+        })
+    }
+}
+
+/// An instantiated graph, ready to launch.
+///
+/// # Thread safety
+///
+/// `CuGraphExec` is `Send` but not `Sync`: the driver requires calls on the
+/// same exec, launches included, to be serialized. Wrap it in a `Mutex` to
+/// launch it from several threads.
+pub struct CuGraphExec {
+    exec: ffi::CUgraphExec,
+    events: Vec<CuEvent>,
+}
+
+unsafe impl Send for CuGraphExec {}
+
+impl CuGraphExec {
+    /// Enqueues the whole graph on `stream`. Launches of the same exec run
+    /// one after the other, even on different streams.
+    pub fn launch(&self, stream: &CuStream) -> CuResult<()> {
+        let res = unsafe { ffi::cuGraphLaunch(self.exec, stream.get_raw()) };
+
+        wrap!((), res, "cuGraphLaunch", "exec={:?}, stream={:?}", self.exec, unsafe { stream.get_raw() })
+    }
+
+    /// Makes later launches run `graph` instead, without instantiating it
+    /// again. `graph` must have the same topology as the graph the exec was
+    /// made from; what else may differ is up to the driver. Launches already
+    /// enqueued are unaffected, and on failure the exec is left unchanged.
+    pub fn update(&mut self, graph: &CuGraph) -> Result<(), CuGraphExecUpdateError> {
+        unsafe { update_exec(self.exec, graph.get_raw())? };
+        // Pending launches keep their events alive in the driver.
+        self.events = graph.events.clone();
+
+        Ok(())
+    }
+
+    pub unsafe fn get_raw(&self) -> ffi::CUgraphExec {
+        self.exec
+    }
+}
+
+impl Drop for CuGraphExec {
+    fn drop(&mut self) {
+        unsafe { ffi::cuGraphExecDestroy(self.exec) };
+    }
+}
+
+#[cfg(cuda = "11080")]
+const EXEC_UPDATE: &str = "cuGraphExecUpdate";
+#[cfg(not(cuda = "11080"))]
+const EXEC_UPDATE: &str = "cuGraphExecUpdate_v2";
+
+#[cfg(cuda = "11080")]
+unsafe fn update_exec(exec: ffi::CUgraphExec, graph: ffi::CUgraph) -> Result<(), CuGraphExecUpdateError> {
+    let mut node = std::ptr::null_mut();
+    let mut result = ffi::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_SUCCESS;
+    let res = ffi::cuGraphExecUpdate(exec, graph, &mut node, &mut result);
+    if res == ffi::cudaError_enum_CUDA_ERROR_GRAPH_EXEC_UPDATE_FAILURE {
+        return Err(CuGraphExecUpdateError::Failed {
+            reason: CuGraphExecUpdateFailure::from_raw(result)?,
+            node: CuGraphNode::from_raw_opt(node),
+            from_node: None,
+        });
+    }
+
+    Ok(wrap!((), res, "cuGraphExecUpdate", "exec={:?}, graph={:?}", exec, graph)?)
+}
+
+#[cfg(not(cuda = "11080"))]
+unsafe fn update_exec(exec: ffi::CUgraphExec, graph: ffi::CUgraph) -> Result<(), CuGraphExecUpdateError> {
+    let mut info: ffi::CUgraphExecUpdateResultInfo = std::mem::zeroed();
+    let res = ffi::cuGraphExecUpdate_v2(exec, graph, &mut info);
+    if res == ffi::cudaError_enum_CUDA_ERROR_GRAPH_EXEC_UPDATE_FAILURE {
+        return Err(CuGraphExecUpdateError::Failed {
+            reason: CuGraphExecUpdateFailure::from_raw(info.result)?,
+            node: CuGraphNode::from_raw_opt(info.errorNode),
+            from_node: CuGraphNode::from_raw_opt(info.errorFromNode),
+        });
+    }
+
+    Ok(wrap!((), res, "cuGraphExecUpdate_v2", "exec={:?}, graph={:?}", exec, graph)?)
+}
+
+/// Why a graph can't be applied to an exec (`CUgraphExecUpdateResult`).
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuGraphExecUpdateFailure {
+    /// A reason not covered by the other variants.
+    Error = 1,
+    /// Nodes or dependencies were added or removed.
+    TopologyChanged = 2,
+    /// A node has a different type.
+    NodeTypeChanged = 3,
+    /// A kernel node launches a different function.
+    FunctionChanged = 4,
+    /// Node parameters changed in a way that can't be updated.
+    ParametersChanged = 5,
+    /// The graph uses something that can't be updated, such as memory
+    /// nodes.
+    NotSupported = 6,
+    /// A kernel node launches a different function, which the driver can't
+    /// switch to.
+    UnsupportedFunctionChange = 7,
+    /// Node attributes changed in a way that can't be updated.
+    AttributesChanged = 8,
+}
+
+impl CuGraphExecUpdateFailure {
+    fn from_raw(result: ffi::CUgraphExecUpdateResult) -> CuResult<Self> {
+        match result {
+            ffi::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR => Ok(CuGraphExecUpdateFailure::Error),
+            ffi::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_TOPOLOGY_CHANGED => {
+                Ok(CuGraphExecUpdateFailure::TopologyChanged)
+            }
+            ffi::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_NODE_TYPE_CHANGED => {
+                Ok(CuGraphExecUpdateFailure::NodeTypeChanged)
+            }
+            ffi::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_FUNCTION_CHANGED => {
+                Ok(CuGraphExecUpdateFailure::FunctionChanged)
+            }
+            ffi::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_PARAMETERS_CHANGED => {
+                Ok(CuGraphExecUpdateFailure::ParametersChanged)
+            }
+            ffi::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_NOT_SUPPORTED => {
+                Ok(CuGraphExecUpdateFailure::NotSupported)
+            }
+            ffi::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_UNSUPPORTED_FUNCTION_CHANGE => {
+                Ok(CuGraphExecUpdateFailure::UnsupportedFunctionChange)
+            }
+            ffi::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_ATTRIBUTES_CHANGED => {
+                Ok(CuGraphExecUpdateFailure::AttributesChanged)
+            }
+            _ => Err(raise(CuError::InvalidValue)),
+        }
+    }
+}
+
+/// Why [`CuGraphExec::update`] failed.
+#[derive(Error, Debug, Clone)]
+pub enum CuGraphExecUpdateError {
+    /// The driver rejected the graph (`CUDA_ERROR_GRAPH_EXEC_UPDATE_FAILURE`).
+    #[error("graph can't update the exec: {reason:?} at node {node:?}")]
+    Failed {
+        reason: CuGraphExecUpdateFailure,
+        /// The node of the new graph at fault, if there is one. Query it
+        /// through that graph, e.g. with [`CuGraph::node_params`].
+        node: Option<CuGraphNode>,
+        /// For a topology change, the dependency of `node` at fault. Only
+        /// reported from CUDA 12.0.
+        from_node: Option<CuGraphNode>,
+    },
+    #[error(transparent)]
+    Call(#[from] CuError),
+}
+
+/// Also makes the failure what [`CuCallError::last`] returns.
+impl From<CuGraphExecUpdateError> for CuError {
+    fn from(err: CuGraphExecUpdateError) -> Self {
+        match err {
+            CuGraphExecUpdateError::Failed { reason, node, from_node } => {
+                CuCallError::new(CuError::GraphExecUpdateFailure, EXEC_UPDATE)
+                    .with_args(format!("reason={:?}, node={:?}, from_node={:?}", reason, node, from_node))
+                    .record()
+            }
+            CuGraphExecUpdateError::Call(err) => err,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem_op::{CuMemOpTarget, CuWriteValueFlags};
+    use crate::stream::CuStreamCaptureMode;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn read_u32(mem: &DeviceMemory) -> Vec<u32> {
+        let host = mem.to_host().unwrap();
+        mem.stream.synchronize().unwrap();
+        host.as_slice::<u32>().to_vec()
+    }
+
+    #[test]
+    fn thread_safety() {
+        crate::assert_send::<CuGraph>();
+        crate::assert_send::<CuGraphExec>();
+        crate::assert_send_sync::<CuGraphNode>();
+    }
+
+    #[test]
+    fn build_and_launch() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let src = DeviceMemory::new(16, &stream).unwrap();
+        let dst = DeviceMemory::new(16, &stream).unwrap();
+        let other = DeviceMemory::new(8, &stream).unwrap();
+        let event = CuEvent::new().unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let mut child = CuGraph::new().unwrap();
+        unsafe { child.add_memset_node(&[], &other, CuMemsetValue::U16(0x0303)) }.unwrap();
+
+        let mut graph = CuGraph::new().unwrap();
+        let memset = unsafe { graph.add_memset_node(&[], &src, CuMemsetValue::U8(1)) }.unwrap();
+        let memcpy = unsafe { graph.add_memcpy_node(&[memset.into()], &src, &dst) }.unwrap();
+        let host = {
+            let calls = calls.clone();
+            graph
+                .add_host_node(&[memcpy.into()], move || {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                })
+                .unwrap()
+        };
+        let child = graph.add_child_graph_node(&[], &child).unwrap();
+        let join = graph.add_empty_node(&[host.into(), child.into()]).unwrap();
+        graph.add_event_record_node(&[join.into()], &event).unwrap();
+        assert_eq!(graph.node_count().unwrap(), 6);
+
+        // A node of another graph can't be a dependency.
+        let mut unrelated = CuGraph::new().unwrap();
+        let err = unrelated.add_empty_node(&[join.into()]).unwrap_err();
+        assert_eq!(err, CuError::InvalidValue);
+
+        let exec = graph.instantiate().unwrap();
+        drop(graph);
+        exec.launch(&stream).unwrap();
+        exec.launch(&stream).unwrap();
+        stream.synchronize().unwrap();
+        assert!(event.query().unwrap());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(read_u32(&dst), [0x01010101; 4]);
+        assert_eq!(read_u32(&other), [0x03030303; 2]);
+
+        // The closure is dropped with the last exec.
+        drop(exec);
+        assert_eq!(Arc::strong_count(&calls), 1);
+    }
+
+    #[test]
+    fn launch_captured() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let src = DeviceMemory::new(16, &stream).unwrap();
+        let mut dst = DeviceMemory::new(16, &stream).unwrap();
+        let other = DeviceMemory::new(16, &stream).unwrap();
+        let mut init = CuGraph::new().unwrap();
+        unsafe { init.add_memset_node(&[], &src, CuMemsetValue::U32(7)) }.unwrap();
+        init.instantiate().unwrap().launch(&stream).unwrap();
+
+        let capture = stream.capture(CuStreamCaptureMode::ThreadLocal).unwrap();
+        src.copy_to(&mut dst, Some(&stream)).unwrap();
+        let mut graph = capture.end().unwrap();
+        unsafe { graph.add_memset_node(&[], &other, CuMemsetValue::U32(9)) }.unwrap();
+        assert_eq!(read_u32(&dst), [0; 4]);
+
+        let exec = graph.instantiate().unwrap();
+        exec.launch(&stream).unwrap();
+        assert_eq!(read_u32(&dst), [7; 4]);
+        assert_eq!(read_u32(&other), [9; 4]);
+    }
+
+    #[test]
+    fn mem_nodes() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let dst = DeviceMemory::new(16, &stream).unwrap();
+
+        let mut graph = CuGraph::new().unwrap();
+        let alloc = graph.add_mem_alloc_node(&[], 16).unwrap();
+        assert_eq!(alloc.size(), 16);
+        let memcpy = unsafe {
+            let memset = graph
+                .add_memset_node_raw(&[alloc.into()], alloc.ptr(), CuMemsetValue::U32(5), 4)
+                .unwrap();
+            graph.add_memcpy_node_raw(&[memset.into()], dst.get_raw(), alloc.ptr(), 16).unwrap()
+        };
+        graph.add_mem_free_node(&[memcpy.into()], &alloc).unwrap();
+
+        let exec = graph.instantiate().unwrap();
+        exec.launch(&stream).unwrap();
+        assert_eq!(read_u32(&dst), [5; 4]);
+
+        // Graphs with memory nodes can't be copied or nested.
+        let Err(err) = graph.try_clone() else {
+            panic!("cloned a graph with memory nodes");
+        };
+        assert_eq!(err, CuError::NotSupported);
+        let mut parent = CuGraph::new().unwrap();
+        let err = parent.add_child_graph_node(&[], &graph).unwrap_err();
+        assert_eq!(err, CuError::NotSupported);
+    }
+
+    #[test]
+    fn update() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let dst = DeviceMemory::new(16, &stream).unwrap();
+        let (func, other_func) = (0x10 as ffi::CUfunction, 0x20 as ffi::CUfunction);
+        let build = |func: ffi::CUfunction, value: u32| {
+            let mut graph = CuGraph::new().unwrap();
+            let memset = unsafe { graph.add_memset_node(&[], &dst, CuMemsetValue::U32(value)) }.unwrap();
+            let params = CuKernelNodeParams {
+                func,
+                grid_dim: (1, 1, 1),
+                block_dim: (32, 1, 1),
+                shared_mem_bytes: 0,
+                kernel_params: &[],
+            };
+            // The mock never runs kernels, so any non-null handle will do.
+            let kernel = unsafe { graph.add_kernel_node(&[memset.into()], &params).unwrap() };
+            (graph, memset, kernel)
+        };
+
+        let (graph, _, _) = build(func, 1);
+        let mut exec = graph.instantiate().unwrap();
+        exec.launch(&stream).unwrap();
+        assert_eq!(read_u32(&dst), [1; 4]);
+
+        let (graph, _, _) = build(func, 2);
+        exec.update(&graph).unwrap();
+        exec.launch(&stream).unwrap();
+        assert_eq!(read_u32(&dst), [2; 4]);
+
+        let (graph, _, kernel) = build(other_func, 3);
+        match exec.update(&graph) {
+            Err(CuGraphExecUpdateError::Failed { reason, node, .. }) => {
+                assert_eq!(reason, CuGraphExecUpdateFailure::FunctionChanged);
+                assert_eq!(node, Some(kernel.node()));
+            }
+            res => panic!("unexpected update result: {:?}", res),
+        }
+
+        let (mut graph, memset, _) = build(func, 4);
+        graph.add_empty_node(&[memset.into()]).unwrap();
+        let err = exec.update(&graph).unwrap_err();
+        assert!(matches!(
+            err,
+            CuGraphExecUpdateError::Failed { reason: CuGraphExecUpdateFailure::TopologyChanged, .. }
+        ));
+        assert_eq!(CuError::from(err), CuError::GraphExecUpdateFailure);
+        assert_eq!(CuCallError::last().unwrap().function(), Some(EXEC_UPDATE));
+
+        // Failed updates leave the exec alone.
+        exec.launch(&stream).unwrap();
+        assert_eq!(read_u32(&dst), [2; 4]);
+    }
+
+    #[test]
+    fn inspect_captured() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let src = DeviceMemory::new(16, &stream).unwrap();
+        let mut dst = DeviceMemory::new(16, &stream).unwrap();
+        let flag = DeviceMemory::new(4, &stream).unwrap();
+
+        let capture = stream.capture(CuStreamCaptureMode::ThreadLocal).unwrap();
+        src.copy_to(&mut dst, Some(&stream)).unwrap();
+        let target = unsafe { CuMemOpTarget::device(&flag, 0) }.unwrap();
+        stream.write_value_32(&target, 1, CuWriteValueFlags::default()).unwrap();
+        let mut graph = capture.end().unwrap();
+        let captured = graph.nodes().unwrap();
+        graph.add_host_node(&captured[1..], || {}).unwrap();
+
+        let nodes = graph.nodes().unwrap();
+        let types: Vec<_> = nodes.iter().map(|node| graph.node_type(*node).unwrap()).collect();
+        assert_eq!(types, [CuGraphNodeType::Memcpy, CuGraphNodeType::BatchMemOp, CuGraphNodeType::Host]);
+        assert_eq!(graph.edges().unwrap(), [(nodes[0], nodes[1]), (nodes[1], nodes[2])]);
+        assert_eq!(graph.node_dependencies(nodes[0]).unwrap(), []);
+        assert_eq!(graph.node_dependencies(nodes[2]).unwrap(), [nodes[1]]);
+
+        match graph.node_params(nodes[0]).unwrap() {
+            CuGraphNodeParams::Memcpy(p) => {
+                assert_eq!((p.src_type, p.dst_type), (CuMemoryType::Device, CuMemoryType::Device));
+                assert_eq!((p.src, p.dst), unsafe { (src.get_raw(), dst.get_raw()) });
+                assert_eq!((p.width_in_bytes, p.height, p.depth), (16, 1, 1));
+            }
+            params => panic!("unexpected params: {:?}", params),
+        }
+        assert!(matches!(
+            graph.node_params(nodes[1]).unwrap(),
+            CuGraphNodeParams::Other(CuGraphNodeType::BatchMemOp)
+        ));
+        assert!(matches!(graph.node_params(nodes[2]).unwrap(), CuGraphNodeParams::Host));
+
+        // Nodes of other graphs are rejected instead of passed to the
+        // driver.
+        let mut other = CuGraph::new().unwrap();
+        let node = other.add_empty_node(&[]).unwrap().node();
+        assert_eq!(graph.node_type(node).unwrap_err(), CuError::InvalidValue);
+        assert_eq!(graph.node_params(node).unwrap_err(), CuError::InvalidValue);
+        drop(other);
+        assert_eq!(graph.node_dependencies(node).unwrap_err(), CuError::InvalidValue);
+    }
+
+    #[test]
+    fn to_dot() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let dst = DeviceMemory::new(16, &stream).unwrap();
+        let func = 0x10 as ffi::CUfunction;
+
+        let mut child = CuGraph::new().unwrap();
+        child.add_empty_node(&[]).unwrap();
+
+        let mut graph = CuGraph::new().unwrap();
+        let memset = unsafe { graph.add_memset_node(&[], &dst, CuMemsetValue::U32(7)) }.unwrap();
+        let child = graph.add_child_graph_node(&[], &child).unwrap();
+        let params = CuKernelNodeParams {
+            func,
+            grid_dim: (2, 1, 1),
+            block_dim: (32, 1, 1),
+            shared_mem_bytes: 64,
+            kernel_params: &[],
+        };
+        let kernel = unsafe { graph.add_kernel_node(&[memset.into(), child.into()], &params).unwrap() };
+        let alloc = graph.add_mem_alloc_node(&[kernel.into()], 8).unwrap();
+
+        match graph.node_params(kernel.into()).unwrap() {
+            CuGraphNodeParams::Kernel { func: f, grid_dim, block_dim, shared_mem_bytes } => {
+                assert_eq!(f, func);
+                assert_eq!((grid_dim, block_dim, shared_mem_bytes), ((2, 1, 1), (32, 1, 1), 64));
+            }
+            params => panic!("unexpected params: {:?}", params),
+        }
+        match graph.node_params(memset.into()).unwrap() {
+            CuGraphNodeParams::Memset(p) => {
+                assert_eq!(p.dst, unsafe { dst.get_raw() });
+                assert_eq!((p.value, p.width, p.height), (CuMemsetValue::U32(7), 4, 1));
+            }
+            params => panic!("unexpected params: {:?}", params),
+        }
+
+        let expected = format!(
+            concat!(
+                "digraph {{\n",
+                "    node [shape=box];\n",
+                "    n0 [label=\"memset\\n{:#x}\\n4 x 1 of U32(7)\"];\n",
+                "    n1 [label=\"graph\\nnodes=1\"];\n",
+                "    n2 [label=\"kernel\\nfunc=0x10\\ngrid=(2, 1, 1), block=(32, 1, 1), shared_mem=64\"];\n",
+                "    n3 [label=\"mem alloc\\n8 bytes at {:#x}\"];\n",
+                "    n0 -> n2;\n",
+                "    n1 -> n2;\n",
+                "    n2 -> n3;\n",
+                "}}\n",
+            ),
+            unsafe { dst.get_raw() },
+            alloc.ptr(),
+        );
+        assert_eq!(graph.to_dot().unwrap(), expected);
+    }
+}
@@ -1,5 +1,4 @@
-#[macro_use]
-extern crate enum_primitive;
+#![allow(clippy::missing_safety_doc)]
 
 extern crate cuda_rs_sys as ffi;
 
@@ -10,10 +9,36 @@ pub mod context;
 pub mod device;
 pub mod error;
 pub mod event;
+pub mod future;
+pub mod graph;
+pub mod mem_op;
 pub mod memory;
 pub mod stream;
+pub mod timer;
 
-pub fn init() -> Result<(), error::CuError> {
+pub fn init() -> error::CuResult<()> {
     let res = unsafe { ffi::cuInit(0) };
-    wrap!((), res)
+    wrap!((), res, "cuInit")
+}
+
+/// Number of simulated devices in tests that need more than one. Tests run
+/// concurrently against the same mock, so they must all agree on it.
+#[cfg(test)]
+pub(crate) const TEST_DEVICE_COUNT: i32 = 4;
+
+#[cfg(test)]
+pub(crate) fn assert_send<T: Send>() {}
+
+#[cfg(test)]
+pub(crate) fn assert_send_sync<T: Send + Sync>() {}
+
+#[cfg(test)]
+pub(crate) fn test_context() -> context::CuContextGuard {
+    init().unwrap();
+    device::CuDevice::new(0)
+        .unwrap()
+        .retain_primary_context()
+        .unwrap()
+        .guard()
+        .unwrap()
 }
@@ -1,10 +1,18 @@
 macro_rules! wrap {
-    ($val:expr, $res:ident) => (
+    ($val:expr, $res:ident, $function:literal) => (
         if $res == crate::ffi::cudaError_enum_CUDA_SUCCESS {
             Ok($val)
         } else {
-            use crate::error::CuError;
-            Err(CuError::from($res))
+            use crate::error::{CuCallError, CuError};
+            Err(CuCallError::new(CuError::from($res), $function).record())
         }
-    )
+    );
+    ($val:expr, $res:ident, $function:literal, $($args:tt)+) => (
+        if $res == crate::ffi::cudaError_enum_CUDA_SUCCESS {
+            Ok($val)
+        } else {
+            use crate::error::{CuCallError, CuError};
+            Err(CuCallError::new(CuError::from($res), $function).with_args(format!($($args)+)).record())
+        }
+    );
 }
@@ -0,0 +1,240 @@
+//! Stream memory operations: waiting on and writing 32 or 64 bit words of
+//! memory in stream order, for signaling between streams, the host and
+//! other devices without going through the host.
+//!
+//! See [`CuStream::wait_value_32`](crate::stream::CuStream::wait_value_32)
+//! and friends.
+
+use crate::{
+    error::{raise, CuError, CuResult},
+    ffi,
+    memory::{DeviceMemory, HostMemory},
+};
+use std::marker::PhantomData;
+
+/// A word of device or page-locked host memory that stream memory
+/// operations act on.
+///
+/// The borrow only covers enqueueing: the operations run later, so the
+/// constructors are unsafe and the caller keeps the memory alive until they
+/// are done.
+#[derive(Debug, Clone, Copy)]
+pub struct CuMemOpTarget<'a> {
+    ptr: ffi::CUdeviceptr,
+    /// Bytes from `ptr` to the end of the allocation.
+    len: usize,
+    _memory: PhantomData<&'a ()>,
+}
+
+impl<'a> CuMemOpTarget<'a> {
+    /// The word at `offset` bytes into `memory`.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must not be freed while operations on the target are
+    /// pending, including in graphs captured from them. Dropping
+    /// `DeviceMemory` only orders the free after the work of its own stream.
+    pub unsafe fn device(memory: &'a DeviceMemory, offset: usize) -> CuResult<Self> {
+        if offset >= memory.size {
+            return Err(raise(CuError::InvalidValue));
+        }
+
+        Ok(CuMemOpTarget {
+            ptr: memory.get_raw() + offset as ffi::CUdeviceptr,
+            len: memory.size - offset,
+            _memory: PhantomData,
+        })
+    }
+
+    /// The word at `offset` bytes into page-locked host memory, as seen from
+    /// the current context.
+    ///
+    /// # Safety
+    ///
+    /// `memory` must not be freed while operations on the target are
+    /// pending, including in graphs captured from them.
+    pub unsafe fn host(memory: &'a HostMemory, offset: usize) -> CuResult<Self> {
+        if offset >= memory.size {
+            return Err(raise(CuError::InvalidValue));
+        }
+
+        let mut ptr = 0;
+        let res = ffi::cuMemHostGetDevicePointer_v2(&mut ptr, memory.get_raw(), 0);
+        let target = CuMemOpTarget {
+            ptr: ptr + offset as ffi::CUdeviceptr,
+            len: memory.size - offset,
+            _memory: PhantomData,
+        };
+
+        wrap!(target, res, "cuMemHostGetDevicePointer_v2", "p={:?}", memory.get_raw())
+    }
+
+    /// A word at a raw device address, e.g. mapped memory of another device.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must stay valid for `len` bytes while operations on it are
+    /// pending.
+    pub unsafe fn from_raw(ptr: ffi::CUdeviceptr, len: usize) -> Self {
+        CuMemOpTarget { ptr, len, _memory: PhantomData }
+    }
+
+    /// The address of a `size` byte word, which must be in bounds and
+    /// aligned to its size.
+    pub(crate) fn address(&self, size: usize) -> CuResult<ffi::CUdeviceptr> {
+        if self.len < size || !self.ptr.is_multiple_of(size as ffi::CUdeviceptr) {
+            return Err(raise(CuError::InvalidValue));
+        }
+
+        Ok(self.ptr)
+    }
+}
+
+/// The condition a value wait blocks on, with the operand `T` it compares
+/// the word in memory against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuWaitCondition<T> {
+    /// `(word - value)` as a signed integer is `>= 0`, so the comparison
+    /// keeps working when a counter wraps around.
+    Geq(T),
+    Eq(T),
+    /// `(word & value) != 0`.
+    And(T),
+    /// `!(word | value) != 0`. Needs
+    /// `CuDeviceAttribute::CanUseStreamWaitValueNor`.
+    Nor(T),
+}
+
+impl<T: Copy> CuWaitCondition<T> {
+    pub fn value(&self) -> T {
+        match *self {
+            CuWaitCondition::Geq(value)
+            | CuWaitCondition::Eq(value)
+            | CuWaitCondition::And(value)
+            | CuWaitCondition::Nor(value) => value,
+        }
+    }
+
+    fn bits(&self) -> u32 {
+        match self {
+            CuWaitCondition::Geq(_) => ffi::CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_GEQ,
+            CuWaitCondition::Eq(_) => ffi::CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_EQ,
+            CuWaitCondition::And(_) => ffi::CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_AND,
+            CuWaitCondition::Nor(_) => ffi::CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_NOR,
+        }
+    }
+}
+
+/// The `CU_STREAM_WAIT_VALUE_*` flags of a value wait, besides the
+/// condition.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuWaitValueFlags {
+    /// Flush remote writes that happened before the wait was satisfied, so
+    /// work after it sees them. Needs
+    /// `CuDeviceAttribute::CanFlushRemoteWrites`.
+    pub flush: bool,
+}
+
+impl CuWaitValueFlags {
+    pub(crate) fn bits<T: Copy>(&self, cond: &CuWaitCondition<T>) -> u32 {
+        let mut bits = cond.bits();
+        if self.flush {
+            bits |= ffi::CUstreamWaitValue_flags_enum_CU_STREAM_WAIT_VALUE_FLUSH;
+        }
+
+        bits
+    }
+}
+
+/// The `CU_STREAM_WRITE_VALUE_*` flags of a value write.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuWriteValueFlags {
+    /// Skip the memory barrier before the write, so earlier writes of the
+    /// stream may become visible after it.
+    pub no_memory_barrier: bool,
+}
+
+impl CuWriteValueFlags {
+    pub(crate) fn bits(&self) -> u32 {
+        if self.no_memory_barrier {
+            ffi::CUstreamWriteValue_flags_enum_CU_STREAM_WRITE_VALUE_NO_MEMORY_BARRIER
+        } else {
+            ffi::CUstreamWriteValue_flags_enum_CU_STREAM_WRITE_VALUE_DEFAULT
+        }
+    }
+}
+
+/// One operation of [`CuStream::batch_mem_op`](crate::stream::CuStream::batch_mem_op).
+#[derive(Debug, Clone, Copy)]
+pub enum CuMemOp<'a> {
+    WaitValue32 {
+        target: CuMemOpTarget<'a>,
+        cond: CuWaitCondition<u32>,
+        flags: CuWaitValueFlags,
+    },
+    WaitValue64 {
+        target: CuMemOpTarget<'a>,
+        cond: CuWaitCondition<u64>,
+        flags: CuWaitValueFlags,
+    },
+    WriteValue32 {
+        target: CuMemOpTarget<'a>,
+        value: u32,
+        flags: CuWriteValueFlags,
+    },
+    WriteValue64 {
+        target: CuMemOpTarget<'a>,
+        value: u64,
+        flags: CuWriteValueFlags,
+    },
+    /// Makes remote writes visible to later work of the stream.
+    FlushRemoteWrites,
+    /// Orders all memory accesses of earlier work before those of later
+    /// work.
+    Barrier,
+}
+
+impl CuMemOp<'_> {
+    pub(crate) fn to_raw(self) -> CuResult<ffi::CUstreamBatchMemOpParams> {
+        // All-zero is a valid value of the union and its structs.
+        let mut params: ffi::CUstreamBatchMemOpParams = unsafe { std::mem::zeroed() };
+        match self {
+            CuMemOp::WaitValue32 { target, cond, flags } => {
+                let wait = unsafe { &mut params.waitValue };
+                wait.operation = ffi::CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WAIT_VALUE_32;
+                wait.address = target.address(4)?;
+                wait.__bindgen_anon_1.value = cond.value();
+                wait.flags = flags.bits(&cond);
+            }
+            CuMemOp::WaitValue64 { target, cond, flags } => {
+                let wait = unsafe { &mut params.waitValue };
+                wait.operation = ffi::CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WAIT_VALUE_64;
+                wait.address = target.address(8)?;
+                wait.__bindgen_anon_1.value64 = cond.value();
+                wait.flags = flags.bits(&cond);
+            }
+            CuMemOp::WriteValue32 { target, value, flags } => {
+                let write = unsafe { &mut params.writeValue };
+                write.operation = ffi::CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WRITE_VALUE_32;
+                write.address = target.address(4)?;
+                write.__bindgen_anon_1.value = value;
+                write.flags = flags.bits();
+            }
+            CuMemOp::WriteValue64 { target, value, flags } => {
+                let write = unsafe { &mut params.writeValue };
+                write.operation = ffi::CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_WRITE_VALUE_64;
+                write.address = target.address(8)?;
+                write.__bindgen_anon_1.value64 = value;
+                write.flags = flags.bits();
+            }
+            CuMemOp::FlushRemoteWrites => {
+                params.operation = ffi::CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_FLUSH_REMOTE_WRITES;
+            }
+            CuMemOp::Barrier => {
+                params.operation = ffi::CUstreamBatchMemOpType_enum_CU_STREAM_MEM_OP_BARRIER;
+            }
+        }
+
+        Ok(params)
+    }
+}
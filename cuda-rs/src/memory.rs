@@ -1,11 +1,20 @@
-use crate::{ffi, stream::CuStream, error::{CuResult, CuError}};
+use crate::{ffi, stream::CuStream, error::{raise, CuResult, CuError}};
 use std::ffi::c_void;
 
+/// Page-locked host memory.
+///
+/// # Thread safety
+///
+/// `HostMemory` owns its buffer, like a `Box<[u8]>`, and is `Send` and
+/// `Sync`. Writes to it go through `&mut self`.
 pub struct HostMemory {
     ptr: *mut c_void,
     pub size: usize,
 }
 
+unsafe impl Send for HostMemory {}
+unsafe impl Sync for HostMemory {}
+
 impl HostMemory {
     pub fn new(size: usize) -> CuResult<Self> {
         let mut ptr = std::ptr::null_mut();
@@ -13,7 +22,7 @@ impl HostMemory {
             ffi::cuMemAllocHost_v2(&mut ptr, size)
         };
 
-        wrap!(Self { ptr, size }, res)
+        wrap!(Self { ptr, size }, res, "cuMemAllocHost_v2", "bytesize={}", size)
     }
 
     pub unsafe fn from_raw(ptr: *mut c_void, size: usize) -> Self {
@@ -39,15 +48,19 @@ impl HostMemory {
             ffi::cuMemcpy(dst as _, self.ptr as _, size)
         };
 
-        wrap!((), res)
+        wrap!((), res, "cuMemcpy", "dst={:?}, src={:?}, bytes={}", dst, self.ptr, size)
     }
 
-    pub fn copy_from_raw(&self, src: *const c_void, size: usize) -> CuResult<()> {
+    /// Copies `size` bytes from `src` into this buffer.
+    ///
+    /// Takes `&mut self` because `HostMemory` is `Sync`: a write through a
+    /// shared reference could race with a slice from [`as_slice`](Self::as_slice).
+    pub fn copy_from_raw(&mut self, src: *const c_void, size: usize) -> CuResult<()> {
         let res = unsafe {
             ffi::cuMemcpy(self.ptr as _, src as _, size)
         };
 
-        wrap!((), res)
+        wrap!((), res, "cuMemcpy", "dst={:?}, src={:?}, bytes={}", self.ptr, src, size)
     }
 
     pub fn copy_to(&self, dst: &mut Self) -> CuResult<()> {
@@ -68,22 +81,38 @@ impl Drop for HostMemory {
     }
 }
 
+/// Device memory, freed on its stream when dropped.
+///
+/// # Thread safety
+///
+/// `DeviceMemory` is `Send` and `Sync`. The host never dereferences the
+/// pointer; every access is a driver call ordered on a stream, so sharing
+/// the handle between threads is as safe as sharing the stream. Copies that
+/// write the memory from several threads still need their streams ordered,
+/// just like on a single thread.
 pub struct DeviceMemory {
     ptr: ffi::CUdeviceptr,
     pub size: usize,
     pub stream: CuStream,
 }
 
+unsafe impl Send for DeviceMemory {}
+unsafe impl Sync for DeviceMemory {}
+
 impl DeviceMemory {
     pub fn new(size: usize, stream: &CuStream) -> CuResult<Self> {
         let mut ptr: ffi::CUdeviceptr = 0;
+        let raw_stream = unsafe { stream.get_raw() };
         let res = unsafe {
             ffi::cuMemAllocAsync(
-                &mut ptr, size, stream.get_raw()
+                &mut ptr, size, raw_stream
             )
         };
 
-        wrap!(Self { ptr, size, stream: stream.clone() }, res)
+        wrap!(
+            Self { ptr, size, stream: stream.clone() }, res,
+            "cuMemAllocAsync", "bytesize={}, stream={:?}", size, raw_stream
+        )
     }
 
     pub unsafe fn from_raw(ptr: ffi::CUdeviceptr, size: usize, stream: &CuStream) -> Self {
@@ -100,9 +129,10 @@ impl DeviceMemory {
         size: usize,
         stream: Option<&CuStream>,
     ) -> CuResult<()> {
+        let stream = unsafe {
+            stream.map_or(self.stream.get_raw(), |s| s.get_raw())
+        };
         let res = unsafe {
-            let stream = stream
-                .map_or(self.stream.get_raw(), |s| s.get_raw());
             ffi::cuMemcpyAsync(
                 dst,
                 self.ptr,
@@ -111,7 +141,10 @@ impl DeviceMemory {
             )
         };
 
-        wrap!((), res)
+        wrap!(
+            (), res,
+            "cuMemcpyAsync", "dst={:#x}, src={:#x}, bytes={}, stream={:?}", dst, self.ptr, size, stream
+        )
     }
 
     pub fn copy_from_raw(
@@ -120,9 +153,10 @@ impl DeviceMemory {
         size: usize,
         stream: Option<&CuStream>,
     ) -> CuResult<()> {
+        let stream = unsafe {
+            stream.map_or(self.stream.get_raw(), |s| s.get_raw())
+        };
         let res = unsafe {
-            let stream = stream
-                .map_or(self.stream.get_raw(), |s| s.get_raw());
             ffi::cuMemcpyAsync(
                 self.ptr,
                 src,
@@ -131,13 +165,47 @@ impl DeviceMemory {
             )
         };
 
-        wrap!((), res)
+        wrap!(
+            (), res,
+            "cuMemcpyAsync", "dst={:#x}, src={:#x}, bytes={}, stream={:?}", self.ptr, src, size, stream
+        )
     }
 
     pub fn copy_to(&self, dst: &mut Self, stream: Option<&CuStream>) -> CuResult<()> {
         self.copy_to_raw(dst.ptr, dst.size, stream)
     }
 
+    /// Copies into `dst` when the two buffers belong to contexts on
+    /// different devices. The contexts are those of the buffers' streams.
+    /// Peer access does not need to be enabled, but the copy is faster when
+    /// it is.
+    pub fn copy_to_peer(&self, dst: &mut Self, stream: Option<&CuStream>) -> CuResult<()> {
+        if dst.size > self.size {
+            return Err(raise(CuError::InvalidValue));
+        }
+
+        let src_ctx = self.stream.get_context()?;
+        let dst_ctx = dst.stream.get_context()?;
+        let stream = unsafe {
+            stream.map_or(self.stream.get_raw(), |s| s.get_raw())
+        };
+        let res = unsafe {
+            ffi::cuMemcpyPeerAsync(
+                dst.ptr,
+                dst_ctx.get_raw(),
+                self.ptr,
+                src_ctx.get_raw(),
+                dst.size,
+                stream,
+            )
+        };
+
+        wrap!(
+            (), res,
+            "cuMemcpyPeerAsync", "dst={:#x}, src={:#x}, bytes={}, stream={:?}", dst.ptr, self.ptr, dst.size, stream
+        )
+    }
+
     pub fn copy_from(&mut self, src: &Self, stream: Option<&CuStream>) -> CuResult<()> {
         src.copy_to(self, stream)
     }
@@ -151,16 +219,20 @@ impl DeviceMemory {
 
     pub fn to_host(&self) -> CuResult<HostMemory> {
         let host_mem = HostMemory::new(self.size)?;
+        let stream = unsafe { self.stream.get_raw() };
         let res = unsafe {
             ffi::cuMemcpyAsync(
                 host_mem.get_raw() as _,
                 self.ptr,
                 self.size,
-                self.stream.get_raw(),
+                stream,
             )
         };
 
-        wrap!(host_mem, res)
+        wrap!(
+            host_mem, res,
+            "cuMemcpyAsync", "dst={:?}, src={:#x}, bytes={}, stream={:?}", host_mem.ptr, self.ptr, self.size, stream
+        )
     }
 }
 
@@ -209,12 +281,13 @@ impl PitchedDeviceMemory {
         stream: Option<&CuStream>,
     ) -> CuResult<()> {
         if width != self.width || height != self.height {
-            return Err(CuError::InvalidValue);
+            return Err(raise(CuError::InvalidValue));
         }
 
+        let stream = unsafe {
+            stream.map_or(self.memory.stream.get_raw(), |s| s.get_raw())
+        };
         let res = unsafe {
-            let stream = stream
-                .map_or(self.memory.stream.get_raw(), |s| s.get_raw());
             let mut params: ffi::CUDA_MEMCPY2D = std::mem::zeroed();
             params.srcMemoryType = ffi::CUmemorytype_enum_CU_MEMORYTYPE_DEVICE;
             params.srcDevice = self.memory.get_raw();
@@ -232,7 +305,12 @@ impl PitchedDeviceMemory {
             ffi::cuMemcpy2DAsync_v2(&params, stream)
         };
 
-        wrap!((), res)
+        wrap!(
+            (), res,
+            "cuMemcpy2DAsync_v2",
+            "srcPitch={}, dstPitch={}, width={}, height={}, stream={:?}",
+            self.pitch, pitch, width, height, stream
+        )
     }
 
     pub fn copy_from_raw(
@@ -245,12 +323,13 @@ impl PitchedDeviceMemory {
         stream: Option<&CuStream>,
     ) -> CuResult<()> {
         if width != self.width || height != self.height {
-            return Err(CuError::InvalidValue);
+            return Err(raise(CuError::InvalidValue));
         }
 
+        let stream = unsafe {
+            stream.map_or(self.memory.stream.get_raw(), |s| s.get_raw())
+        };
         let res = unsafe {
-            let stream = stream
-                .map_or(self.memory.stream.get_raw(), |s| s.get_raw());
             let mut params: ffi::CUDA_MEMCPY2D = std::mem::zeroed();
             if is_src_host {
                 params.srcMemoryType = ffi::CUmemorytype_enum_CU_MEMORYTYPE_HOST;
@@ -269,7 +348,12 @@ impl PitchedDeviceMemory {
             ffi::cuMemcpy2DAsync_v2(&params, stream)
         };
 
-        wrap!((), res)
+        wrap!(
+            (), res,
+            "cuMemcpy2DAsync_v2",
+            "srcPitch={}, dstPitch={}, width={}, height={}, stream={:?}",
+            pitch, self.pitch, width, height, stream
+        )
     }
 
     pub fn copy_to(&self, dst: &mut Self, stream: Option<&CuStream>) -> CuResult<()> {
@@ -330,3 +414,115 @@ fn align_up(x: usize, align: usize) -> usize {
         panic!("attempt to add with overflow")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::CuError;
+
+    #[test]
+    fn thread_safety() {
+        crate::assert_send_sync::<HostMemory>();
+        crate::assert_send_sync::<DeviceMemory>();
+        crate::assert_send_sync::<PitchedDeviceMemory>();
+
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+
+        let dst = std::thread::spawn(move || {
+            let ctx = mem.stream.get_context().unwrap();
+            let dst = ctx.scoped(|| mem.to_host()).unwrap().unwrap();
+            mem.stream.synchronize().unwrap();
+            dst
+        }).join().unwrap();
+        assert_eq!(dst.size, 16);
+    }
+
+    #[test]
+    fn device_memory_round_trip() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+
+        let mut src = HostMemory::new(16).unwrap();
+        src.as_mut_slice::<u8>().copy_from_slice(&[7; 16]);
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        unsafe { mem.copy_from_raw(src.get_raw() as _, 16, None) }.unwrap();
+
+        let copy = mem.try_clone().unwrap();
+        let dst = copy.to_host().unwrap();
+        stream.synchronize().unwrap();
+
+        assert_eq!(dst.as_slice::<u8>(), &[7; 16]);
+    }
+
+    #[test]
+    fn pitched_memory_round_trip() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+
+        let (width, height) = (3, 4);
+        let mut src = HostMemory::new(width * height).unwrap();
+        for (i, x) in src.as_mut_slice::<u8>().iter_mut().enumerate() {
+            *x = i as u8;
+        }
+        let mem = PitchedDeviceMemory::new(width, height, &stream).unwrap();
+        assert_eq!(mem.pitch, ALIGNMENT);
+        unsafe {
+            mem.copy_from_raw(src.get_raw() as _, width, width, height, true, None)
+        }.unwrap();
+
+        let dst = mem.to_host().unwrap();
+        stream.synchronize().unwrap();
+
+        assert_eq!(dst.as_slice::<u8>(), src.as_slice::<u8>());
+        assert_eq!(
+            mem.copy_to_raw(0, width, width + 1, height, true, None).unwrap_err(),
+            CuError::InvalidValue,
+        );
+    }
+
+    #[test]
+    fn copy_to_peer() {
+        use crate::{context::CuContext, device::CuDevice};
+
+        let _ctx = crate::test_context();
+        ffi::mock::set_device_count(crate::TEST_DEVICE_COUNT);
+        let stream = CuStream::new().unwrap();
+        let mut src = HostMemory::new(16).unwrap();
+        src.as_mut_slice::<u8>().copy_from_slice(&[3; 16]);
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        unsafe { mem.copy_from_raw(src.get_raw() as _, 16, None) }.unwrap();
+
+        let _peer_ctx = CuContext::new(&CuDevice::new(1).unwrap()).unwrap();
+        let peer_stream = CuStream::new().unwrap();
+        let mut peer = DeviceMemory::new(16, &peer_stream).unwrap();
+        CuContext::pop().unwrap();
+
+        mem.copy_to_peer(&mut peer, None).unwrap();
+        stream.synchronize().unwrap();
+        let dst = peer.to_host().unwrap();
+        peer_stream.synchronize().unwrap();
+        assert_eq!(dst.as_slice::<u8>(), &[3; 16]);
+
+        let mut large = DeviceMemory::new(32, &stream).unwrap();
+        assert_eq!(mem.copy_to_peer(&mut large, None).unwrap_err(), CuError::InvalidValue);
+    }
+
+    #[test]
+    fn allocation_failure() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+
+        ffi::mock::fail_next(
+            "cuMemAllocAsync",
+            ffi::cudaError_enum_CUDA_ERROR_OUT_OF_MEMORY,
+        );
+        let err = DeviceMemory::new(16, &stream).err().unwrap();
+        assert_eq!(err, CuError::OutOfMemory);
+        let call = crate::error::CuCallError::last().unwrap();
+        assert_eq!(call.function(), Some("cuMemAllocAsync"));
+        assert!(call.to_string().starts_with("cuMemAllocAsync(bytesize=16, "));
+        assert!(DeviceMemory::new(16, &stream).is_ok());
+    }
+}
@@ -1,10 +1,18 @@
 use crate::{
     context::CuContext,
-    error::CuResult,
+    error::{raise, CuError, CuResult},
     event::CuEvent,
     ffi,
+    future::CuCompletion,
+    graph::CuGraph,
+    mem_op::{CuMemOp, CuMemOpTarget, CuWaitCondition, CuWaitValueFlags, CuWriteValueFlags},
+    memory::DeviceMemory,
+};
+use std::{
+    ffi::c_void,
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
 };
-use std::sync::Arc;
 
 struct CUstream(ffi::CUstream);
 
@@ -14,32 +22,66 @@ impl Drop for CUstream {
     }
 }
 
+/// Raw handle of `CU_STREAM_LEGACY`, a macro that bindgen doesn't translate.
+const LEGACY_STREAM: usize = 0x1;
+/// Raw handle of `CU_STREAM_PER_THREAD`.
+const PER_THREAD_STREAM: usize = 0x2;
+
+// Streams may be used from any thread, and the driver serializes work
+// submitted to the same stream.
+unsafe impl Send for CUstream {}
+unsafe impl Sync for CUstream {}
+
 enum Inner {
     Owned(Arc<CUstream>),
     Borrowed(ffi::CUstream),
 }
 
+/// A CUDA stream.
+///
+/// # Thread safety
+///
+/// `CuStream` is `Send` and `Sync`. Stream calls don't depend on the current
+/// context, and work submitted from several threads is serialized by the
+/// driver, in the order the calls are made.
 pub struct CuStream(Inner);
 
+unsafe impl Send for CuStream {}
+unsafe impl Sync for CuStream {}
+
 impl CuStream {
+    /// Creates a blocking stream with the default priority in the current
+    /// context.
     pub fn new() -> CuResult<Self> {
-        let mut s = std::ptr::null_mut();
-        let res = unsafe {
-            ffi::cuStreamCreate(&mut s, ffi::CUstream_flags_enum_CU_STREAM_DEFAULT)
-        };
-        let s = CuStream(Inner::Owned(Arc::new(CUstream(s))));
+        CuStreamBuilder::new().build()
+    }
 
-        wrap!(s, res)
+    pub fn builder() -> CuStreamBuilder {
+        CuStreamBuilder::new()
     }
 
     pub unsafe fn from_raw(s: ffi::CUstream) -> Self {
         CuStream(Inner::Borrowed(s))
     }
 
+    /// The legacy default stream (`CU_STREAM_LEGACY`). Work on it waits for
+    /// all blocking streams of its context, and blocking streams wait for it.
+    pub fn legacy() -> Self {
+        CuStream(Inner::Borrowed(LEGACY_STREAM as ffi::CUstream))
+    }
+
+    /// The default stream of the calling thread (`CU_STREAM_PER_THREAD`).
+    /// It only synchronizes with the legacy default stream. The handle is
+    /// the same on every thread, but refers to the stream of the thread
+    /// using it.
+    pub fn per_thread() -> Self {
+        CuStream(Inner::Borrowed(PER_THREAD_STREAM as ffi::CUstream))
+    }
+
     pub fn synchronize(&self) -> CuResult<()> {
         let res = unsafe { ffi::cuStreamSynchronize(self.get_raw()) };
 
-        wrap!((), res)
+        wrap!((), res, "cuStreamSynchronize", "stream={:?}", unsafe { self.get_raw() })
     }
 
     pub fn get_context(&self) -> CuResult<CuContext> {
@@ -53,7 +95,7 @@ impl CuStream {
             (ctx, res)
         };
 
-        wrap!(ctx, res)
+        wrap!(ctx, res, "cuStreamGetCtx", "stream={:?}", unsafe { self.get_raw() })
     }
 
     pub fn query(&self) -> CuResult<bool> {
@@ -62,16 +104,314 @@ impl CuStream {
         if res == ffi::cudaError_enum_CUDA_SUCCESS || res == ffi::cudaError_enum_CUDA_ERROR_NOT_READY {
             Ok(res == ffi::cudaError_enum_CUDA_SUCCESS)
         } else {
-            wrap!(false, res)
+            wrap!(false, res, "cuStreamQuery", "stream={:?}", unsafe { self.get_raw() })
         }
     }
 
+    pub fn flags(&self) -> CuResult<CuStreamFlags> {
+        let mut flags = 0;
+        let res = unsafe { ffi::cuStreamGetFlags(self.get_raw(), &mut flags) };
+
+        wrap!(CuStreamFlags::from_bits(flags), res, "cuStreamGetFlags", "stream={:?}", unsafe { self.get_raw() })
+    }
+
+    /// The priority of the stream, clamped to the range of
+    /// [`CuContext::stream_priority_range`] when it was created.
+    pub fn priority(&self) -> CuResult<i32> {
+        let mut priority = 0;
+        let res = unsafe { ffi::cuStreamGetPriority(self.get_raw(), &mut priority) };
+
+        wrap!(priority, res, "cuStreamGetPriority", "stream={:?}", unsafe { self.get_raw() })
+    }
+
+    /// An identifier of the stream, unique within the process. Unlike the
+    /// handle, it is never reused for another stream.
+    #[cfg(not(cuda = "11080"))]
+    pub fn id(&self) -> CuResult<u64> {
+        let mut id = 0;
+        let res = unsafe { ffi::cuStreamGetId(self.get_raw(), &mut id) };
+
+        wrap!(id, res, "cuStreamGetId", "stream={:?}", unsafe { self.get_raw() })
+    }
+
+    pub fn get_attribute(&self, id: CuStreamAttributeId) -> CuResult<CuStreamAttribute> {
+        // All-zero is a valid value of the union.
+        let mut value: ffi::CUstreamAttrValue = unsafe { std::mem::zeroed() };
+        let res = unsafe {
+            ffi::cuStreamGetAttribute(self.get_raw(), id as ffi::CUstreamAttrID, &mut value)
+        };
+        wrap!((), res, "cuStreamGetAttribute", "stream={:?}, attr={:?}", unsafe { self.get_raw() }, id)?;
+
+        unsafe { CuStreamAttribute::from_raw(id, &value) }
+    }
+
+    pub fn set_attribute(&self, attr: CuStreamAttribute) -> CuResult<()> {
+        let (id, value) = attr.to_raw();
+        let res = unsafe {
+            ffi::cuStreamSetAttribute(self.get_raw(), id as ffi::CUstreamAttrID, &value)
+        };
+
+        wrap!((), res, "cuStreamSetAttribute", "stream={:?}, attr={:?}", unsafe { self.get_raw() }, attr)
+    }
+
+    /// Makes accesses of later work to `len` bytes at `offset` into `memory`
+    /// persist in the L2 cache, for a fraction `hit_ratio` of them; the rest
+    /// are streaming. The persisting part of the cache is sized with
+    /// [`CuLimit::PersistingL2CacheSize`](crate::context::CuLimit::PersistingL2CacheSize).
+    ///
+    /// Use [`CuStream::set_attribute`] for other cache properties, and an
+    /// empty window to turn it off.
+    pub fn set_access_policy_window(
+        &self,
+        memory: &DeviceMemory,
+        offset: usize,
+        len: usize,
+        hit_ratio: f32,
+    ) -> CuResult<()> {
+        let window = CuAccessPolicyWindow::new(memory, offset, len, hit_ratio)?;
+
+        self.set_attribute(CuStreamAttribute::AccessPolicyWindow(window))
+    }
+
+    /// Copies the attributes of `src` to this stream.
+    pub fn copy_attributes_from(&self, src: &CuStream) -> CuResult<()> {
+        let res = unsafe { ffi::cuStreamCopyAttributes(self.get_raw(), src.get_raw()) };
+
+        wrap!(
+            (), res,
+            "cuStreamCopyAttributes", "dst={:?}, src={:?}",
+            unsafe { self.get_raw() }, unsafe { src.get_raw() }
+        )
+    }
+
     pub fn wait_on_event(&self, event: &CuEvent) -> CuResult<()> {
         let res = unsafe {
             ffi::cuStreamWaitEvent(self.get_raw(), event.get_raw(), 0)
         };
 
-        wrap!((), res)
+        wrap!((), res, "cuStreamWaitEvent", "stream={:?}", unsafe { self.get_raw() })
+    }
+
+    /// Runs `f` on a driver thread once all work enqueued on the stream
+    /// before it has completed. Work enqueued after it waits for `f` to
+    /// return.
+    ///
+    /// `f` must not call into CUDA. A panic in `f` is caught and does not
+    /// affect the stream.
+    ///
+    /// Fails with `StreamCaptureUnsupported` while the stream is capturing,
+    /// since a graph would run `f` once per launch. Add host nodes with
+    /// [`CuGraph::add_host_node`] instead.
+    pub fn launch_host_fn<F>(&self, f: F) -> CuResult<()>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        if self.capture_status()? != CuStreamCaptureStatus::None {
+            return Err(raise(CuError::StreamCaptureUnsupported));
+        }
+
+        let data = Box::into_raw(Box::new(f));
+        let res = unsafe {
+            ffi::cuLaunchHostFunc(self.get_raw(), Some(host_fn::<F>), data as *mut c_void)
+        };
+        if res != ffi::cudaError_enum_CUDA_SUCCESS {
+            // The driver never calls it, so the closure is still ours.
+            drop(unsafe { Box::from_raw(data) });
+        }
+
+        wrap!((), res, "cuLaunchHostFunc", "stream={:?}", unsafe { self.get_raw() })
+    }
+
+    /// Blocks work enqueued after this call until the 32 bit word at
+    /// `target` satisfies `cond`.
+    pub fn wait_value_32(
+        &self,
+        target: &CuMemOpTarget,
+        cond: CuWaitCondition<u32>,
+        flags: CuWaitValueFlags,
+    ) -> CuResult<()> {
+        let addr = target.address(4)?;
+        let flags = flags.bits(&cond);
+        let res = unsafe {
+            ffi::cuStreamWaitValue32_v2(self.get_raw(), addr, cond.value(), flags)
+        };
+
+        wrap!(
+            (), res,
+            "cuStreamWaitValue32_v2", "stream={:?}, addr={:#x}, value={}, flags={:#x}",
+            unsafe { self.get_raw() }, addr, cond.value(), flags
+        )
+    }
+
+    /// Like [`CuStream::wait_value_32`] for a 64 bit word. Needs
+    /// `CuDeviceAttribute::CanUse64BitStreamMemOps`.
+    pub fn wait_value_64(
+        &self,
+        target: &CuMemOpTarget,
+        cond: CuWaitCondition<u64>,
+        flags: CuWaitValueFlags,
+    ) -> CuResult<()> {
+        let addr = target.address(8)?;
+        let flags = flags.bits(&cond);
+        let res = unsafe {
+            ffi::cuStreamWaitValue64_v2(self.get_raw(), addr, cond.value(), flags)
+        };
+
+        wrap!(
+            (), res,
+            "cuStreamWaitValue64_v2", "stream={:?}, addr={:#x}, value={}, flags={:#x}",
+            unsafe { self.get_raw() }, addr, cond.value(), flags
+        )
+    }
+
+    /// Writes `value` to the 32 bit word at `target` once earlier work of
+    /// the stream has completed.
+    pub fn write_value_32(
+        &self,
+        target: &CuMemOpTarget,
+        value: u32,
+        flags: CuWriteValueFlags,
+    ) -> CuResult<()> {
+        let addr = target.address(4)?;
+        let res = unsafe {
+            ffi::cuStreamWriteValue32_v2(self.get_raw(), addr, value, flags.bits())
+        };
+
+        wrap!(
+            (), res,
+            "cuStreamWriteValue32_v2", "stream={:?}, addr={:#x}, value={}",
+            unsafe { self.get_raw() }, addr, value
+        )
+    }
+
+    /// Like [`CuStream::write_value_32`] for a 64 bit word. Needs
+    /// `CuDeviceAttribute::CanUse64BitStreamMemOps`.
+    pub fn write_value_64(
+        &self,
+        target: &CuMemOpTarget,
+        value: u64,
+        flags: CuWriteValueFlags,
+    ) -> CuResult<()> {
+        let addr = target.address(8)?;
+        let res = unsafe {
+            ffi::cuStreamWriteValue64_v2(self.get_raw(), addr, value, flags.bits())
+        };
+
+        wrap!(
+            (), res,
+            "cuStreamWriteValue64_v2", "stream={:?}, addr={:#x}, value={}",
+            unsafe { self.get_raw() }, addr, value
+        )
+    }
+
+    /// Enqueues several memory operations at once, in order. Nothing is
+    /// enqueued if one of them is invalid.
+    pub fn batch_mem_op(&self, ops: &[CuMemOp]) -> CuResult<()> {
+        let mut params = ops.iter().map(|op| op.to_raw()).collect::<CuResult<Vec<_>>>()?;
+        let res = unsafe {
+            ffi::cuStreamBatchMemOp_v2(self.get_raw(), params.len() as u32, params.as_mut_ptr(), 0)
+        };
+
+        wrap!((), res, "cuStreamBatchMemOp_v2", "stream={:?}, count={}", unsafe { self.get_raw() }, ops.len())
+    }
+
+    /// Starts capturing the work enqueued on the stream into a graph, instead
+    /// of running it. Other streams join the capture by waiting on an event
+    /// recorded in it, and must be joined back into this stream, the same
+    /// way, before [`CuStream::end_capture`].
+    ///
+    /// While a capture in [`CuStreamCaptureMode::Global`] or
+    /// [`CuStreamCaptureMode::ThreadLocal`] is in progress, calls that could
+    /// synchronize with captured work, such as
+    /// [`CuContext::synchronize`] or [`HostMemory::new`](crate::memory::HostMemory::new),
+    /// fail and invalidate it.
+    pub fn begin_capture(&self, mode: CuStreamCaptureMode) -> CuResult<()> {
+        let res = unsafe {
+            ffi::cuStreamBeginCapture_v2(self.get_raw(), mode as ffi::CUstreamCaptureMode)
+        };
+
+        wrap!((), res, "cuStreamBeginCapture_v2", "stream={:?}, mode={:?}", unsafe { self.get_raw() }, mode)
+    }
+
+    /// Ends the capture begun with [`CuStream::begin_capture`], returning
+    /// the captured graph. Fails with
+    /// [`CuError::StreamCaptureInvalidated`] if a call during the capture
+    /// broke it.
+    ///
+    /// Events recorded or waited on during the capture only order the
+    /// captured work, as in a fork and join across streams. They don't
+    /// become nodes, so the graph doesn't use them once the capture has
+    /// ended. Add event nodes with [`CuGraph::add_event_record_node`] and
+    /// [`CuGraph::add_event_wait_node`], which keep the events alive.
+    pub fn end_capture(&self) -> CuResult<CuGraph> {
+        let mut graph = std::ptr::null_mut();
+        let res = unsafe { ffi::cuStreamEndCapture(self.get_raw(), &mut graph) };
+        wrap!((), res, "cuStreamEndCapture", "stream={:?}", unsafe { self.get_raw() })?;
+
+        Ok(unsafe { CuGraph::from_owned(graph) })
+    }
+
+    /// Begins a capture that ends when the returned guard is ended or
+    /// dropped.
+    ///
+    /// ```no_run
+    /// # use cuda_rs::{memory::DeviceMemory, stream::{CuStream, CuStreamCaptureMode}};
+    /// # fn run(stream: &CuStream, src: &DeviceMemory, dst: &mut DeviceMemory) -> cuda_rs::error::CuResult<()> {
+    /// let capture = stream.capture(CuStreamCaptureMode::ThreadLocal)?;
+    /// src.copy_to(dst, Some(stream))?;
+    /// let graph = capture.end()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn capture(&self, mode: CuStreamCaptureMode) -> CuResult<CuStreamCapture<'_>> {
+        self.begin_capture(mode)?;
+
+        Ok(CuStreamCapture { stream: self, ended: false })
+    }
+
+    pub fn capture_status(&self) -> CuResult<CuStreamCaptureStatus> {
+        let mut status = 0;
+        let res = unsafe { ffi::cuStreamIsCapturing(self.get_raw(), &mut status) };
+        wrap!((), res, "cuStreamIsCapturing", "stream={:?}", unsafe { self.get_raw() })?;
+
+        CuStreamCaptureStatus::from_raw(status)
+    }
+
+    /// The status of the capture the stream takes part in, with its id and
+    /// how many graph nodes the next captured work will depend on.
+    pub fn capture_info(&self) -> CuResult<CuStreamCaptureInfo> {
+        let mut status = 0;
+        let mut id = 0;
+        let mut num_dependencies = 0;
+        let res = unsafe {
+            ffi::cuStreamGetCaptureInfo_v2(
+                self.get_raw(),
+                &mut status,
+                &mut id,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                &mut num_dependencies,
+            )
+        };
+        wrap!((), res, "cuStreamGetCaptureInfo_v2", "stream={:?}", unsafe { self.get_raw() })?;
+
+        let status = CuStreamCaptureStatus::from_raw(status)?;
+        Ok(match status {
+            CuStreamCaptureStatus::None => CuStreamCaptureInfo { status, id: 0, num_dependencies: 0 },
+            _ => CuStreamCaptureInfo { status, id, num_dependencies },
+        })
+    }
+
+    /// A future that resolves once the work enqueued so far has completed.
+    ///
+    /// ```no_run
+    /// # async fn run(stream: cuda_rs::stream::CuStream) -> cuda_rs::error::CuResult<()> {
+    /// stream.completion().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn completion(&self) -> CuCompletion {
+        CuCompletion::new(self)
     }
 
     pub unsafe fn get_raw(&self) -> ffi::CUstream {
@@ -90,3 +430,742 @@ impl Clone for CuStream {
         }
     }
 }
+
+/// Calls the closure passed to [`CuStream::launch_host_fn`]. Unwinding into
+/// the driver would abort, so panics stop here; the default panic hook has
+/// already reported them.
+unsafe extern "C" fn host_fn<F: FnOnce()>(data: *mut c_void) {
+    let f = Box::from_raw(data as *mut F);
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(f)) {
+        // Dropping the payload can panic too.
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(payload)));
+    }
+}
+
+/// The `CU_STREAM_*` flags of a stream.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuStreamFlags {
+    /// Don't synchronize with the legacy default stream. Work on a blocking
+    /// stream waits for, and is waited on by, work on the legacy stream.
+    pub non_blocking: bool,
+}
+
+impl CuStreamFlags {
+    pub fn bits(&self) -> u32 {
+        if self.non_blocking {
+            ffi::CUstream_flags_enum_CU_STREAM_NON_BLOCKING
+        } else {
+            ffi::CUstream_flags_enum_CU_STREAM_DEFAULT
+        }
+    }
+
+    pub fn from_bits(bits: u32) -> Self {
+        CuStreamFlags {
+            non_blocking: bits & ffi::CUstream_flags_enum_CU_STREAM_NON_BLOCKING != 0,
+        }
+    }
+}
+
+pub struct CuStreamBuilder {
+    flags: CuStreamFlags,
+    priority: i32,
+}
+
+impl CuStreamBuilder {
+    pub fn new() -> Self {
+        CuStreamBuilder { flags: CuStreamFlags::default(), priority: 0 }
+    }
+
+    pub fn flags(mut self, flags: CuStreamFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn non_blocking(mut self, non_blocking: bool) -> Self {
+        self.flags.non_blocking = non_blocking;
+        self
+    }
+
+    /// Sets the priority. Lower numbers are higher priorities; see
+    /// [`CuContext::stream_priority_range`]. Out of range values are clamped
+    /// by the driver.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Creates the stream in the current context.
+    pub fn build(self) -> CuResult<CuStream> {
+        let mut s = std::ptr::null_mut();
+        let flags = self.flags.bits();
+        let res = unsafe {
+            ffi::cuStreamCreateWithPriority(&mut s, flags, self.priority)
+        };
+        let s = CuStream(Inner::Owned(Arc::new(CUstream(s))));
+
+        wrap!(s, res, "cuStreamCreateWithPriority", "flags={:#x}, priority={}", flags, self.priority)
+    }
+}
+
+impl Default for CuStreamBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Which calls a capture restricts while it is in progress
+/// (`CUstreamCaptureMode`).
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuStreamCaptureMode {
+    /// Potentially unsafe calls fail on every thread.
+    #[default]
+    Global = 0,
+    /// Potentially unsafe calls fail on the thread that began the capture,
+    /// which must also end it.
+    ThreadLocal = 1,
+    /// Nothing is restricted. The capture may be ended on any thread.
+    Relaxed = 2,
+}
+
+/// The capture status of a stream (`CUstreamCaptureStatus`).
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuStreamCaptureStatus {
+    /// Not capturing.
+    None = 0,
+    Active = 1,
+    /// Capturing, but a call broke the capture, which must still be ended.
+    Invalidated = 2,
+}
+
+impl CuStreamCaptureStatus {
+    fn from_raw(status: ffi::CUstreamCaptureStatus) -> CuResult<Self> {
+        match status {
+            ffi::CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_NONE => Ok(CuStreamCaptureStatus::None),
+            ffi::CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_ACTIVE => Ok(CuStreamCaptureStatus::Active),
+            ffi::CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_INVALIDATED => {
+                Ok(CuStreamCaptureStatus::Invalidated)
+            }
+            _ => Err(raise(CuError::InvalidValue)),
+        }
+    }
+}
+
+/// Returned by [`CuStream::capture_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CuStreamCaptureInfo {
+    pub status: CuStreamCaptureStatus,
+    /// Unique within the process, shared by all streams in the capture.
+    /// Zero when not capturing.
+    pub id: u64,
+    /// Number of graph nodes the next work captured from the stream will
+    /// depend on.
+    pub num_dependencies: usize,
+}
+
+/// A capture in progress, begun with [`CuStream::capture`]. Dropping it
+/// ends the capture and discards the graph.
+#[must_use = "dropping the guard discards the capture"]
+pub struct CuStreamCapture<'a> {
+    stream: &'a CuStream,
+    ended: bool,
+}
+
+impl CuStreamCapture<'_> {
+    pub fn stream(&self) -> &CuStream {
+        self.stream
+    }
+
+    /// Ends the capture, returning the captured graph.
+    pub fn end(mut self) -> CuResult<CuGraph> {
+        self.ended = true;
+        self.stream.end_capture()
+    }
+}
+
+impl Drop for CuStreamCapture<'_> {
+    fn drop(&mut self) {
+        if !self.ended {
+            let _ = self.stream.end_capture();
+        }
+    }
+}
+
+/// The attributes of `CUstreamAttrID` that apply to streams.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuStreamAttributeId {
+    AccessPolicyWindow = 1,
+    SyncPolicy = 3,
+    Priority = 8,
+}
+
+/// The value of a stream attribute (`CUstreamAttrValue`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CuStreamAttribute {
+    AccessPolicyWindow(CuAccessPolicyWindow),
+    SyncPolicy(CuSyncPolicy),
+    /// Same as [`CuStream::priority`].
+    Priority(i32),
+}
+
+impl CuStreamAttribute {
+    pub fn id(&self) -> CuStreamAttributeId {
+        match self {
+            CuStreamAttribute::AccessPolicyWindow(_) => CuStreamAttributeId::AccessPolicyWindow,
+            CuStreamAttribute::SyncPolicy(_) => CuStreamAttributeId::SyncPolicy,
+            CuStreamAttribute::Priority(_) => CuStreamAttributeId::Priority,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `value` must hold the field of the union that belongs to `id`.
+    unsafe fn from_raw(id: CuStreamAttributeId, value: &ffi::CUstreamAttrValue) -> CuResult<Self> {
+        match id {
+            CuStreamAttributeId::AccessPolicyWindow => Ok(CuStreamAttribute::AccessPolicyWindow(
+                CuAccessPolicyWindow::from_raw(&value.accessPolicyWindow)?,
+            )),
+            CuStreamAttributeId::SyncPolicy => {
+                Ok(CuStreamAttribute::SyncPolicy(CuSyncPolicy::from_raw(value.syncPolicy)?))
+            }
+            CuStreamAttributeId::Priority => Ok(CuStreamAttribute::Priority(value.priority)),
+        }
+    }
+
+    fn to_raw(self) -> (CuStreamAttributeId, ffi::CUstreamAttrValue) {
+        let mut value: ffi::CUstreamAttrValue = unsafe { std::mem::zeroed() };
+        match self {
+            CuStreamAttribute::AccessPolicyWindow(window) => value.accessPolicyWindow = window.to_raw(),
+            CuStreamAttribute::SyncPolicy(policy) => value.syncPolicy = policy as ffi::CUsynchronizationPolicy,
+            CuStreamAttribute::Priority(priority) => value.priority = priority,
+        }
+
+        (self.id(), value)
+    }
+}
+
+/// How the L2 cache treats accesses (`CUaccessProperty`).
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuAccessProperty {
+    #[default]
+    Normal = 0,
+    /// Less likely to persist in the cache than normal accesses.
+    Streaming = 1,
+    /// More likely to persist in the cache than other accesses.
+    Persisting = 2,
+}
+
+impl CuAccessProperty {
+    fn from_raw(prop: ffi::CUaccessProperty) -> CuResult<Self> {
+        match prop {
+            ffi::CUaccessProperty_enum_CU_ACCESS_PROPERTY_NORMAL => Ok(CuAccessProperty::Normal),
+            ffi::CUaccessProperty_enum_CU_ACCESS_PROPERTY_STREAMING => Ok(CuAccessProperty::Streaming),
+            ffi::CUaccessProperty_enum_CU_ACCESS_PROPERTY_PERSISTING => Ok(CuAccessProperty::Persisting),
+            _ => Err(raise(CuError::InvalidValue)),
+        }
+    }
+}
+
+/// A region of device memory whose accesses get L2 cache properties
+/// (`CUaccessPolicyWindow`). A fraction `hit_ratio` of the accesses, chosen
+/// at random, get `hit_prop` and the others `miss_prop`.
+///
+/// The default window is empty, which turns the policy off.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CuAccessPolicyWindow {
+    pub base: ffi::CUdeviceptr,
+    /// At most `CuDeviceAttribute::MaxAccessPolicyWindowSize` bytes.
+    pub num_bytes: usize,
+    pub hit_ratio: f32,
+    pub hit_prop: CuAccessProperty,
+    pub miss_prop: CuAccessProperty,
+}
+
+impl CuAccessPolicyWindow {
+    /// A window over `len` bytes at `offset` into `memory`, with persisting
+    /// hits and streaming misses.
+    pub fn new(memory: &DeviceMemory, offset: usize, len: usize, hit_ratio: f32) -> CuResult<Self> {
+        let in_bounds = offset.checked_add(len).is_some_and(|end| end <= memory.size);
+        if !in_bounds || !(0.0..=1.0).contains(&hit_ratio) {
+            return Err(raise(CuError::InvalidValue));
+        }
+
+        Ok(CuAccessPolicyWindow {
+            base: unsafe { memory.get_raw() } + offset as ffi::CUdeviceptr,
+            num_bytes: len,
+            hit_ratio,
+            hit_prop: CuAccessProperty::Persisting,
+            miss_prop: CuAccessProperty::Streaming,
+        })
+    }
+
+    pub fn hit_prop(mut self, prop: CuAccessProperty) -> Self {
+        self.hit_prop = prop;
+        self
+    }
+
+    pub fn miss_prop(mut self, prop: CuAccessProperty) -> Self {
+        self.miss_prop = prop;
+        self
+    }
+
+    fn from_raw(window: &ffi::CUaccessPolicyWindow) -> CuResult<Self> {
+        Ok(CuAccessPolicyWindow {
+            base: window.base_ptr as ffi::CUdeviceptr,
+            num_bytes: window.num_bytes,
+            hit_ratio: window.hitRatio,
+            hit_prop: CuAccessProperty::from_raw(window.hitProp)?,
+            miss_prop: CuAccessProperty::from_raw(window.missProp)?,
+        })
+    }
+
+    fn to_raw(self) -> ffi::CUaccessPolicyWindow {
+        ffi::CUaccessPolicyWindow {
+            base_ptr: self.base as *mut c_void,
+            num_bytes: self.num_bytes,
+            hitRatio: self.hit_ratio,
+            hitProp: self.hit_prop as ffi::CUaccessProperty,
+            missProp: self.miss_prop as ffi::CUaccessProperty,
+        }
+    }
+}
+
+/// How a thread waiting for a stream waits (`CUsynchronizationPolicy`).
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuSyncPolicy {
+    /// Spin or yield depending on the number of active contexts and
+    /// processors.
+    #[default]
+    Auto = 1,
+    Spin = 2,
+    Yield = 3,
+    /// Block the thread on a synchronization primitive.
+    BlockingSync = 4,
+}
+
+impl CuSyncPolicy {
+    fn from_raw(policy: ffi::CUsynchronizationPolicy) -> CuResult<Self> {
+        match policy {
+            ffi::CUsynchronizationPolicy_enum_CU_SYNC_POLICY_AUTO => Ok(CuSyncPolicy::Auto),
+            ffi::CUsynchronizationPolicy_enum_CU_SYNC_POLICY_SPIN => Ok(CuSyncPolicy::Spin),
+            ffi::CUsynchronizationPolicy_enum_CU_SYNC_POLICY_YIELD => Ok(CuSyncPolicy::Yield),
+            ffi::CUsynchronizationPolicy_enum_CU_SYNC_POLICY_BLOCKING_SYNC => Ok(CuSyncPolicy::BlockingSync),
+            _ => Err(raise(CuError::InvalidValue)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::DeviceMemory;
+
+    #[test]
+    fn thread_safety() {
+        crate::assert_send_sync::<CuStream>();
+
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        std::thread::scope(|s| {
+            for _ in 0..2 {
+                s.spawn(|| {
+                    stream.get_context().unwrap().scoped(|| {
+                        let mem = DeviceMemory::new(16, &stream).unwrap();
+                        mem.try_clone().unwrap();
+                    }).unwrap();
+                });
+            }
+        });
+        stream.synchronize().unwrap();
+    }
+
+    #[test]
+    fn builder() {
+        let ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        assert_eq!(stream.flags().unwrap(), CuStreamFlags::default());
+        assert_eq!(stream.priority().unwrap(), 0);
+
+        let (_, greatest) = ctx.stream_priority_range().unwrap();
+        let stream = CuStream::builder()
+            .non_blocking(true)
+            .priority(greatest)
+            .build()
+            .unwrap();
+        assert!(stream.flags().unwrap().non_blocking);
+        assert_eq!(stream.priority().unwrap(), greatest);
+
+        let clamped = CuStream::builder().priority(greatest - 10).build().unwrap();
+        assert_eq!(clamped.priority().unwrap(), greatest);
+
+        #[cfg(not(cuda = "11080"))]
+        assert_ne!(stream.id().unwrap(), clamped.id().unwrap());
+    }
+
+    #[test]
+    fn default_streams() {
+        let ctx = crate::test_context();
+        for stream in [CuStream::legacy(), CuStream::per_thread()] {
+            let mem = DeviceMemory::new(16, &stream).unwrap();
+            let _copy = mem.try_clone().unwrap();
+            stream.synchronize().unwrap();
+            assert!(stream.query().unwrap());
+            assert_eq!(
+                unsafe { stream.get_context().unwrap().get_raw() },
+                unsafe { ctx.get_raw() },
+            );
+        }
+    }
+
+    #[test]
+    fn null_stream() {
+        let _ctx = crate::test_context();
+        let blocking = CuStream::new().unwrap();
+        let mem = DeviceMemory::new(16, &blocking).unwrap();
+        let _copy = mem.try_clone().unwrap();
+        unsafe { ffi::mock::set_stream_held(blocking.get_raw(), true) }.unwrap();
+
+        // Only the legacy stream waits for blocking streams.
+        let null = unsafe { CuStream::from_raw(std::ptr::null_mut()) };
+        let other = DeviceMemory::new(16, &null).unwrap();
+        let _copy = other.try_clone().unwrap();
+        assert_eq!(blocking.query().unwrap(), cfg!(not(feature = "per-thread-default-stream")));
+        blocking.synchronize().unwrap();
+    }
+
+    #[test]
+    fn launch_host_fn() {
+        use std::sync::mpsc;
+
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let (tx, rx) = mpsc::channel();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        let _copy = mem.try_clone().unwrap();
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), true) }.unwrap();
+
+        let first = tx.clone();
+        stream.launch_host_fn(move || first.send(1).unwrap()).unwrap();
+        stream.launch_host_fn(|| panic!("in host fn")).unwrap();
+        stream.launch_host_fn(move || tx.send(2).unwrap()).unwrap();
+        assert!(rx.try_recv().is_err());
+
+        stream.synchronize().unwrap();
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn launch_host_fn_failure() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let captured = Arc::new(());
+        let inner = captured.clone();
+
+        ffi::mock::fail_next("cuLaunchHostFunc", ffi::cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        let err = stream.launch_host_fn(move || drop(inner)).unwrap_err();
+        assert_eq!(err, crate::error::CuError::InvalidValue);
+        assert_eq!(crate::error::CuCallError::last().unwrap().function(), Some("cuLaunchHostFunc"));
+        assert_eq!(Arc::strong_count(&captured), 1);
+    }
+
+    #[test]
+    fn launch_host_fn_captured() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        // The closure runs at most once, so it can't become a graph node.
+        let capture = stream.capture(CuStreamCaptureMode::ThreadLocal).unwrap();
+        let inner = calls.clone();
+        let err = stream.launch_host_fn(move || drop(inner)).unwrap_err();
+        assert_eq!(err, CuError::StreamCaptureUnsupported);
+        assert_eq!(Arc::strong_count(&calls), 1);
+
+        let mut graph = capture.end().unwrap();
+        let inner = calls.clone();
+        graph.add_host_node(&[], move || { inner.fetch_add(1, Ordering::SeqCst); }).unwrap();
+        let exec = graph.instantiate().unwrap();
+        exec.launch(&stream).unwrap();
+        exec.launch(&stream).unwrap();
+        stream.synchronize().unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn wait_and_write_value() {
+        use crate::memory::HostMemory;
+
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let mut flag = HostMemory::new(8).unwrap();
+        flag.as_mut_slice::<u32>().fill(0);
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+
+        let host = unsafe { CuMemOpTarget::host(&flag, 0) }.unwrap();
+        let device = unsafe { CuMemOpTarget::device(&mem, 8) }.unwrap();
+        stream.wait_value_32(&host, CuWaitCondition::Geq(2), CuWaitValueFlags::default()).unwrap();
+        stream.write_value_64(&device, u64::MAX, CuWriteValueFlags::default()).unwrap();
+        stream.write_value_32(&host, 7, CuWriteValueFlags::default()).unwrap();
+        assert!(!stream.query().unwrap());
+
+        // The host side of the handshake, normally another thread.
+        unsafe { (flag.get_raw() as *mut u32).write_volatile(2) };
+        stream.synchronize().unwrap();
+        assert_eq!(flag.as_slice::<u32>()[0], 7);
+        let dst = mem.to_host().unwrap();
+        stream.synchronize().unwrap();
+        assert_eq!(dst.as_slice::<u64>()[1], u64::MAX);
+
+        let misaligned = unsafe { CuMemOpTarget::device(&mem, 4) }.unwrap();
+        let err = stream.write_value_64(&misaligned, 1, CuWriteValueFlags::default()).unwrap_err();
+        assert_eq!(err, crate::error::CuError::InvalidValue);
+    }
+
+    #[test]
+    fn wait_value_from_another_thread() {
+        let guard = crate::test_context();
+        let ctx = guard.context();
+        let stream = CuStream::new().unwrap();
+        let mem = DeviceMemory::new(8, &stream).unwrap();
+        let target = unsafe { CuMemOpTarget::device(&mem, 0) }.unwrap();
+        let signal = CuStream::builder().non_blocking(true).build().unwrap();
+        signal.write_value_64(&target, 0, CuWriteValueFlags::default()).unwrap();
+        signal.synchronize().unwrap();
+
+        stream.wait_value_64(&target, CuWaitCondition::Eq(42), CuWaitValueFlags { flush: true }).unwrap();
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                ctx.scoped(|| {
+                    signal.write_value_64(&target, 42, CuWriteValueFlags::default()).unwrap();
+                    signal.synchronize().unwrap();
+                }).unwrap();
+            });
+            stream.synchronize().unwrap();
+        });
+    }
+
+    #[test]
+    fn batch_mem_op() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        let word = unsafe { CuMemOpTarget::device(&mem, 0) }.unwrap();
+        let counter = unsafe { CuMemOpTarget::device(&mem, 8) }.unwrap();
+
+        let write = CuWriteValueFlags::default();
+        stream.batch_mem_op(&[
+            CuMemOp::WriteValue32 { target: word, value: 0b0110, flags: write },
+            CuMemOp::WriteValue64 { target: counter, value: 1, flags: write },
+            CuMemOp::Barrier,
+            CuMemOp::WaitValue32 { target: word, cond: CuWaitCondition::And(0b0100), flags: Default::default() },
+            CuMemOp::WaitValue64 { target: counter, cond: CuWaitCondition::Nor(!0b11), flags: Default::default() },
+            CuMemOp::FlushRemoteWrites,
+        ]).unwrap();
+        stream.synchronize().unwrap();
+        let dst = mem.to_host().unwrap();
+        stream.synchronize().unwrap();
+        assert_eq!(dst.as_slice::<u32>()[0], 0b0110);
+
+        // An invalid operation rejects the whole batch.
+        let misaligned = unsafe { CuMemOpTarget::device(&mem, 12) }.unwrap();
+        let err = stream.batch_mem_op(&[
+            CuMemOp::WriteValue32 { target: word, value: 1, flags: write },
+            CuMemOp::WriteValue64 { target: misaligned, value: 1, flags: write },
+        ]).unwrap_err();
+        assert_eq!(err, crate::error::CuError::InvalidValue);
+        assert!(unsafe { CuMemOpTarget::device(&mem, 16) }.is_err());
+    }
+
+    #[test]
+    fn attributes() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let mem = DeviceMemory::new(1 << 20, &stream).unwrap();
+        let get = |stream: &CuStream, id| stream.get_attribute(id).unwrap();
+
+        assert_eq!(
+            get(&stream, CuStreamAttributeId::AccessPolicyWindow),
+            CuStreamAttribute::AccessPolicyWindow(CuAccessPolicyWindow::default()),
+        );
+        assert_eq!(get(&stream, CuStreamAttributeId::SyncPolicy), CuStreamAttribute::SyncPolicy(CuSyncPolicy::Auto));
+
+        stream.set_access_policy_window(&mem, 4096, 8192, 0.75).unwrap();
+        let CuStreamAttribute::AccessPolicyWindow(window) = get(&stream, CuStreamAttributeId::AccessPolicyWindow) else {
+            panic!("wrong attribute");
+        };
+        assert_eq!(window.base, unsafe { mem.get_raw() } + 4096);
+        assert_eq!(window.num_bytes, 8192);
+        assert_eq!(window.hit_ratio, 0.75);
+        assert_eq!(window.hit_prop, CuAccessProperty::Persisting);
+        assert_eq!(window.miss_prop, CuAccessProperty::Streaming);
+
+        stream.set_attribute(CuStreamAttribute::SyncPolicy(CuSyncPolicy::BlockingSync)).unwrap();
+        stream.set_attribute(CuStreamAttribute::Priority(-1)).unwrap();
+        assert_eq!(stream.priority().unwrap(), -1);
+
+        let other = CuStream::new().unwrap();
+        other.copy_attributes_from(&stream).unwrap();
+        for id in [
+            CuStreamAttributeId::AccessPolicyWindow,
+            CuStreamAttributeId::SyncPolicy,
+            CuStreamAttributeId::Priority,
+        ] {
+            assert_eq!(get(&other, id), get(&stream, id));
+        }
+
+        let err = stream.set_access_policy_window(&mem, 4096, 1 << 20, 0.5).unwrap_err();
+        assert_eq!(err, crate::error::CuError::InvalidValue);
+        assert!(CuAccessPolicyWindow::new(&mem, 0, 16, 1.5).is_err());
+        let mut window = CuAccessPolicyWindow::new(&mem, 0, 16, 1.0).unwrap();
+        window.num_bytes = 1 << 30;
+        let err = stream.set_attribute(CuStreamAttribute::AccessPolicyWindow(window)).unwrap_err();
+        assert_eq!(err, crate::error::CuError::InvalidValue);
+        assert_eq!(crate::error::CuCallError::last().unwrap().function(), Some("cuStreamSetAttribute"));
+    }
+
+    #[test]
+    fn capture() {
+        use crate::memory::PitchedDeviceMemory;
+
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let src = DeviceMemory::new(16, &stream).unwrap();
+        let mut dst = DeviceMemory::new(16, &stream).unwrap();
+        let pitched = PitchedDeviceMemory::new(4, 2, &stream).unwrap();
+        let word = unsafe { CuMemOpTarget::device(&src, 0) }.unwrap();
+        stream.write_value_32(&word, 7, CuWriteValueFlags::default()).unwrap();
+        assert_eq!(stream.capture_status().unwrap(), CuStreamCaptureStatus::None);
+
+        stream.begin_capture(CuStreamCaptureMode::ThreadLocal).unwrap();
+        assert_eq!(stream.capture_status().unwrap(), CuStreamCaptureStatus::Active);
+        src.copy_to(&mut dst, Some(&stream)).unwrap();
+        pitched.copy_from_raw(unsafe { src.get_raw() }, 4, 4, 2, false, Some(&stream)).unwrap();
+        let info = stream.capture_info().unwrap();
+        assert_eq!(info.status, CuStreamCaptureStatus::Active);
+        assert_eq!(info.num_dependencies, 1);
+        let graph = stream.end_capture().unwrap();
+        assert_eq!(graph.node_count().unwrap(), 2);
+        assert_eq!(stream.capture_info().unwrap().id, 0);
+
+        // Nothing ran.
+        let host = dst.to_host().unwrap();
+        stream.synchronize().unwrap();
+        assert_eq!(host.as_slice::<u32>()[0], 0);
+    }
+
+    #[test]
+    fn capture_fork_join() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let side = CuStream::builder().non_blocking(true).build().unwrap();
+        let (fork, join) = (CuEvent::new().unwrap(), CuEvent::new().unwrap());
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        let mut a = DeviceMemory::new(16, &stream).unwrap();
+        let mut b = DeviceMemory::new(16, &stream).unwrap();
+
+        let capture = stream.capture(CuStreamCaptureMode::ThreadLocal).unwrap();
+        fork.record(&stream).unwrap();
+        side.wait_on_event(&fork).unwrap();
+        assert_eq!(side.capture_info().unwrap().id, stream.capture_info().unwrap().id);
+        mem.copy_to(&mut a, Some(&stream)).unwrap();
+        mem.copy_to(&mut b, Some(&side)).unwrap();
+        join.record(&side).unwrap();
+        stream.wait_on_event(&join).unwrap();
+        assert_eq!(stream.capture_info().unwrap().num_dependencies, 2);
+        let err = join.query().unwrap_err();
+        assert_eq!(err, crate::error::CuError::CapturedEvent);
+
+        let graph = capture.end().unwrap();
+        assert_eq!(graph.node_count().unwrap(), 2);
+        assert_eq!(side.capture_status().unwrap(), CuStreamCaptureStatus::None);
+        let exec = graph.instantiate().unwrap();
+
+        // Work left on a forked stream that isn't joined back fails the
+        // capture.
+        let capture = stream.capture(CuStreamCaptureMode::ThreadLocal).unwrap();
+        fork.record(&stream).unwrap();
+        side.wait_on_event(&fork).unwrap();
+        mem.copy_to(&mut b, Some(&side)).unwrap();
+        let Err(err) = capture.end() else {
+            panic!("capture should have failed");
+        };
+        assert_eq!(err, crate::error::CuError::StreamCaptureUnjoined);
+        assert_eq!(side.capture_status().unwrap(), CuStreamCaptureStatus::None);
+
+        // The graph has no event nodes, so it doesn't need the events.
+        drop((fork, join));
+        exec.launch(&stream).unwrap();
+        stream.synchronize().unwrap();
+    }
+
+    #[test]
+    fn capture_invalidated() {
+        let guard = crate::test_context();
+        let ctx = guard.context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+
+        let capture = stream.capture(CuStreamCaptureMode::ThreadLocal).unwrap();
+        let err = capture.stream().synchronize().unwrap_err();
+        assert_eq!(err, crate::error::CuError::StreamCaptureUnsupported);
+        assert_eq!(stream.capture_status().unwrap(), CuStreamCaptureStatus::Invalidated);
+        let Err(err) = capture.end() else {
+            panic!("capture should have failed");
+        };
+        assert_eq!(err, crate::error::CuError::StreamCaptureInvalidated);
+
+        // Unsafe calls only fail on the capturing thread in thread-local
+        // mode, and never in relaxed mode.
+        let capture = stream.capture(CuStreamCaptureMode::ThreadLocal).unwrap();
+        std::thread::scope(|s| {
+            s.spawn(|| ctx.scoped(|| crate::memory::HostMemory::new(16).unwrap()).unwrap());
+        });
+        assert!(crate::memory::HostMemory::new(16).is_err());
+        drop(capture);
+        assert_eq!(stream.capture_status().unwrap(), CuStreamCaptureStatus::None);
+
+        let capture = stream.capture(CuStreamCaptureMode::Relaxed).unwrap();
+        let host = crate::memory::HostMemory::new(16).unwrap();
+        capture.end().unwrap();
+        drop(host);
+    }
+
+    #[test]
+    fn query_and_synchronize() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::new().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        let _copy = mem.try_clone().unwrap();
+
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), true) }.unwrap();
+        assert!(!stream.query().unwrap());
+
+        stream.synchronize().unwrap();
+        assert!(stream.query().unwrap());
+    }
+
+    #[test]
+    fn wait_on_event() {
+        let _ctx = crate::test_context();
+        let producer = CuStream::new().unwrap();
+        let consumer = CuStream::new().unwrap();
+        let event = CuEvent::new().unwrap();
+
+        let mem = DeviceMemory::new(16, &producer).unwrap();
+        let _copy = mem.try_clone().unwrap();
+        event.record(&producer).unwrap();
+        consumer.wait_on_event(&event).unwrap();
+
+        unsafe { ffi::mock::set_stream_held(producer.get_raw(), true) }.unwrap();
+        assert!(!consumer.query().unwrap());
+        assert!(!event.query().unwrap());
+
+        consumer.synchronize().unwrap();
+        assert!(event.query().unwrap());
+    }
+}
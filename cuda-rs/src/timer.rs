@@ -0,0 +1,427 @@
+//! Timing GPU work with pairs of events.
+//!
+//! [`CuTimer`] records a start event on a stream and [`CuTimer::stop`] an
+//! end event, giving a [`CuTiming`] whose duration can be read once the work
+//! in between has completed. [`CuTimingAggregator`] collects named regions
+//! across frames and summarizes them, reading durations as they become
+//! available instead of synchronizing every frame.
+
+use crate::{error::CuResult, event::CuEvent, stream::CuStream};
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+
+/// A timed region in progress on a stream. Dropping it without calling
+/// [`CuTimer::stop`] discards the region.
+pub struct CuTimer<'a> {
+    stream: &'a CuStream,
+    start: CuEvent,
+}
+
+impl<'a> CuTimer<'a> {
+    /// Starts timing the work enqueued on `stream` from now on. The events
+    /// are created in the current context, which must be the one of
+    /// `stream`.
+    pub fn start(stream: &'a CuStream) -> CuResult<Self> {
+        let start = CuEvent::new()?;
+        start.record(stream)?;
+
+        Ok(CuTimer { stream, start })
+    }
+
+    /// Ends the region after the work enqueued on the stream so far.
+    pub fn stop(self) -> CuResult<CuTiming> {
+        let end = CuEvent::new()?;
+        end.record(self.stream)?;
+
+        Ok(CuTiming { start: self.start, end })
+    }
+}
+
+/// The duration of a region of GPU work, known once the work completes.
+#[derive(Clone)]
+pub struct CuTiming {
+    start: CuEvent,
+    end: CuEvent,
+}
+
+impl CuTiming {
+    /// Whether the work of the region has completed.
+    pub fn is_ready(&self) -> CuResult<bool> {
+        self.end.query()
+    }
+
+    /// The duration in milliseconds, or `None` if the work of the region
+    /// hasn't completed yet. Never blocks.
+    pub fn try_elapsed_ms(&self) -> CuResult<Option<f32>> {
+        if !self.end.query()? {
+            return Ok(None);
+        }
+
+        self.end.elapsed_time(&self.start).map(Some)
+    }
+
+    /// Waits for the work of the region to complete and returns its
+    /// duration in milliseconds.
+    pub fn elapsed_ms(&self) -> CuResult<f32> {
+        self.end.synchronize()?;
+        self.end.elapsed_time(&self.start)
+    }
+}
+
+/// Summary of the durations of a named region, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CuTimingStats {
+    /// Number of completed regions summarized, at most the capacity of the
+    /// aggregator.
+    pub count: usize,
+    pub min_ms: f32,
+    pub mean_ms: f32,
+    /// The 99th percentile, by nearest rank.
+    pub p99_ms: f32,
+    pub max_ms: f32,
+}
+
+impl CuTimingStats {
+    fn from_samples(samples: &[f32]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f32::total_cmp);
+        let rank = (sorted.len() * 99).div_ceil(100);
+        let sum: f64 = sorted.iter().map(|&ms| ms as f64).sum();
+
+        Some(CuTimingStats {
+            count: sorted.len(),
+            min_ms: sorted[0],
+            mean_ms: (sum / sorted.len() as f64) as f32,
+            p99_ms: sorted[rank - 1],
+            max_ms: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+struct Regions {
+    /// Regions whose work may not have completed, in the order they ended.
+    pending: Vec<(String, CuTiming)>,
+    /// Durations of the latest completed regions, by name, oldest first.
+    samples: BTreeMap<String, VecDeque<f32>>,
+    /// The most durations kept per name.
+    capacity: usize,
+}
+
+impl Regions {
+    /// Moves the durations of completed regions to `samples`.
+    fn collect(&mut self) -> CuResult<()> {
+        let mut res = Ok(());
+        let (samples, capacity) = (&mut self.samples, self.capacity);
+        self.pending.retain(|(name, timing)| match timing.try_elapsed_ms() {
+            Ok(Some(ms)) => {
+                let samples = samples.entry(name.clone()).or_default();
+                if samples.len() == capacity {
+                    samples.pop_front();
+                }
+                samples.push_back(ms);
+                false
+            }
+            Ok(None) => true,
+            Err(err) => {
+                // A region that can't be timed would never leave the list.
+                res = Err(err);
+                false
+            }
+        });
+
+        res
+    }
+}
+
+/// Collects the durations of named regions, e.g. once per frame, and
+/// reports statistics over them.
+///
+/// Durations are read as regions complete: ending a region, or calling
+/// [`CuTimingAggregator::collect`], picks up the regions that are done
+/// without waiting for the others. Only [`CuTimingAggregator::flush`]
+/// synchronizes.
+///
+/// Statistics cover the latest durations of each region, up to the
+/// capacity of the aggregator, so memory use and the cost of
+/// [`CuTimingAggregator::stats`] don't grow with the number of frames.
+///
+/// # Thread safety
+///
+/// `CuTimingAggregator` is `Send` and `Sync`. Regions can be timed from
+/// several threads and streams at once, and may be nested.
+pub struct CuTimingAggregator {
+    regions: Mutex<Regions>,
+}
+
+impl Default for CuTimingAggregator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CuTimingAggregator {
+    /// The number of durations kept per region by
+    /// [`CuTimingAggregator::new`].
+    pub const DEFAULT_CAPACITY: usize = 1000;
+
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// An aggregator that keeps the latest `capacity` durations of each
+    /// region, and at least one.
+    pub fn with_capacity(capacity: usize) -> Self {
+        CuTimingAggregator {
+            regions: Mutex::new(Regions {
+                pending: Vec::new(),
+                samples: BTreeMap::new(),
+                capacity: capacity.max(1),
+            }),
+        }
+    }
+
+    /// Starts timing the region `name` on `stream`. The region ends when
+    /// the returned guard is dropped or [`CuTimingRegion::end`] is called.
+    pub fn region<'a>(&'a self, name: &str, stream: &'a CuStream) -> CuResult<CuTimingRegion<'a>> {
+        Ok(CuTimingRegion {
+            aggregator: self,
+            name: name.to_owned(),
+            timer: Some(CuTimer::start(stream)?),
+        })
+    }
+
+    /// Adds a region timed by hand.
+    pub fn add(&self, name: &str, timing: CuTiming) {
+        self.lock().pending.push((name.to_owned(), timing));
+    }
+
+    /// Reads the durations of the regions that have completed, without
+    /// blocking. A region whose duration can't be read is dropped, and the
+    /// error returned.
+    pub fn collect(&self) -> CuResult<()> {
+        self.lock().collect()
+    }
+
+    /// Waits for every pending region to complete, then collects them. A
+    /// region that can't be waited for is dropped like in
+    /// [`CuTimingAggregator::collect`], and the first error returned once
+    /// the others are collected.
+    pub fn flush(&self) -> CuResult<()> {
+        // Regions can still end on other threads while this one waits.
+        let pending: Vec<_> = self.lock().pending.iter().map(|(_, timing)| timing.clone()).collect();
+        let mut res = Ok(());
+        let mut failed = Vec::new();
+        for timing in pending {
+            if let Err(err) = timing.end.synchronize() {
+                failed.push(unsafe { timing.end.get_raw() });
+                res = res.and(Err(err));
+            }
+        }
+
+        let mut regions = self.lock();
+        regions.pending.retain(|(_, timing)| !failed.contains(&unsafe { timing.end.get_raw() }));
+        res.and(regions.collect())
+    }
+
+    /// Number of regions whose durations haven't been read yet.
+    pub fn pending(&self) -> usize {
+        self.lock().pending.len()
+    }
+
+    /// Statistics over the latest completed regions named `name`, or
+    /// `None` if there are none yet.
+    pub fn stats(&self, name: &str) -> Option<CuTimingStats> {
+        CuTimingStats::from_samples(self.lock().samples.get_mut(name)?.make_contiguous())
+    }
+
+    /// Statistics over the latest completed regions, by name.
+    pub fn report(&self) -> BTreeMap<String, CuTimingStats> {
+        self.lock()
+            .samples
+            .iter_mut()
+            .filter_map(|(name, samples)| {
+                Some((name.clone(), CuTimingStats::from_samples(samples.make_contiguous())?))
+            })
+            .collect()
+    }
+
+    /// Forgets the durations collected so far. Pending regions are kept.
+    pub fn reset(&self) {
+        self.lock().samples.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Regions> {
+        // The lock is never held across a panic that could leave the
+        // regions inconsistent.
+        self.regions.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// A region being timed by a [`CuTimingAggregator`], ended on drop.
+pub struct CuTimingRegion<'a> {
+    aggregator: &'a CuTimingAggregator,
+    name: String,
+    timer: Option<CuTimer<'a>>,
+}
+
+impl CuTimingRegion<'_> {
+    /// Ends the region like dropping it does, but returns the errors that
+    /// dropping ignores.
+    pub fn end(mut self) -> CuResult<()> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> CuResult<()> {
+        let Some(timer) = self.timer.take() else {
+            return Ok(());
+        };
+        let timing = timer.stop()?;
+
+        let mut regions = self.aggregator.lock();
+        regions.pending.push((std::mem::take(&mut self.name), timing));
+        regions.collect()
+    }
+}
+
+impl Drop for CuTimingRegion<'_> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ffi, memory::DeviceMemory};
+
+    #[test]
+    fn thread_safety() {
+        crate::assert_send_sync::<CuTiming>();
+        crate::assert_send_sync::<CuTimingAggregator>();
+    }
+
+    #[test]
+    fn stats() {
+        let samples: Vec<f32> = (1..=100).rev().map(|ms| ms as f32).collect();
+        let stats = CuTimingStats::from_samples(&samples).unwrap();
+        assert_eq!(stats, CuTimingStats { count: 100, min_ms: 1.0, mean_ms: 50.5, p99_ms: 99.0, max_ms: 100.0 });
+
+        let stats = CuTimingStats::from_samples(&[2.0]).unwrap();
+        assert_eq!((stats.min_ms, stats.p99_ms, stats.max_ms), (2.0, 2.0, 2.0));
+        assert_eq!(CuTimingStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn timer() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), true) }.unwrap();
+        let timer = CuTimer::start(&stream).unwrap();
+        let _copies = (0..3).map(|_| mem.try_clone().unwrap()).collect::<Vec<_>>();
+        let timing = timer.stop().unwrap();
+        assert!(!timing.is_ready().unwrap());
+        assert_eq!(timing.try_elapsed_ms().unwrap(), None);
+
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), false) }.unwrap();
+        let ms = timing.elapsed_ms().unwrap();
+        assert!(ms > 0.0);
+        assert_eq!(timing.try_elapsed_ms().unwrap(), Some(ms));
+    }
+
+    #[test]
+    fn aggregator() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        let timings = CuTimingAggregator::new();
+
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), true) }.unwrap();
+        let mut copies = Vec::new();
+        for _ in 0..10 {
+            let _frame = timings.region("frame", &stream).unwrap();
+            let copy = timings.region("copy", &stream).unwrap();
+            copies.push(mem.try_clone().unwrap());
+            copy.end().unwrap();
+            copies.push(mem.try_clone().unwrap());
+        }
+
+        // Nothing has completed, and nothing waited for it.
+        assert_eq!(timings.pending(), 20);
+        timings.collect().unwrap();
+        assert_eq!(timings.stats("frame"), None);
+        assert!(!stream.query().unwrap());
+
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), false) }.unwrap();
+        timings.flush().unwrap();
+        assert_eq!(timings.pending(), 0);
+
+        let report = timings.report();
+        assert_eq!(report.keys().collect::<Vec<_>>(), ["copy", "frame"]);
+        for stats in report.values() {
+            assert_eq!(stats.count, 10);
+            assert!(0.0 < stats.min_ms && stats.min_ms <= stats.mean_ms);
+            assert!(stats.mean_ms <= stats.p99_ms && stats.p99_ms <= stats.max_ms);
+        }
+        // Frames contain the copies.
+        assert!(report["frame"].mean_ms > report["copy"].mean_ms);
+
+        timings.reset();
+        assert!(timings.report().is_empty());
+    }
+
+    #[test]
+    fn flush_error() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        let timings = CuTimingAggregator::new();
+
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), true) }.unwrap();
+        let mut copies = Vec::new();
+        for _ in 0..3 {
+            let _copy = timings.region("copy", &stream).unwrap();
+            copies.push(mem.try_clone().unwrap());
+        }
+        unsafe { ffi::mock::set_stream_held(stream.get_raw(), false) }.unwrap();
+        assert_eq!(timings.pending(), 3);
+
+        // The region that failed is dropped, and the others still collected.
+        ffi::mock::fail_next("cuEventSynchronize", ffi::cudaError_enum_CUDA_ERROR_INVALID_VALUE);
+        let err = timings.flush().unwrap_err();
+        assert_eq!(err, crate::error::CuError::InvalidValue);
+        assert_eq!(crate::error::CuCallError::last().unwrap().function(), Some("cuEventSynchronize"));
+        assert_eq!(timings.pending(), 0);
+        assert_eq!(timings.stats("copy").unwrap().count, 2);
+    }
+
+    #[test]
+    fn capacity() {
+        let _ctx = crate::test_context();
+        let stream = CuStream::builder().non_blocking(true).build().unwrap();
+        let mem = DeviceMemory::new(16, &stream).unwrap();
+        let latest = CuTimingAggregator::with_capacity(4);
+        let all = CuTimingAggregator::with_capacity(6);
+
+        // Each region takes longer than the one before.
+        for copies in 1..=6 {
+            let timer = CuTimer::start(&stream).unwrap();
+            let _copies = (0..copies).map(|_| mem.try_clone().unwrap()).collect::<Vec<_>>();
+            let timing = timer.stop().unwrap();
+            latest.add("copies", timing.clone());
+            all.add("copies", timing);
+        }
+        latest.flush().unwrap();
+        all.flush().unwrap();
+
+        let (latest, all) = (latest.stats("copies").unwrap(), all.stats("copies").unwrap());
+        assert_eq!((latest.count, all.count), (4, 6));
+        assert!(latest.min_ms > all.min_ms);
+        assert_eq!(latest.max_ms, all.max_ms);
+    }
+}